@@ -0,0 +1,255 @@
+//! Describe how values change over time.
+use crate::time::Duration;
+use crate::{Background, Color, Point, Size, Vector};
+
+/// A curve that maps the linear progress of an animation to the progress of
+/// the animated value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Easing {
+    /// Constant speed.
+    #[default]
+    Linear,
+
+    /// Starts slowly and accelerates.
+    EaseIn,
+
+    /// Starts quickly and decelerates.
+    EaseOut,
+
+    /// Accelerates until halfway, then decelerates.
+    EaseInOut,
+}
+
+impl Easing {
+    /// Applies the [`Easing`] to the given linear progress, between `0.0`
+    /// and `1.0`.
+    pub fn apply(self, progress: f32) -> f32 {
+        let t = progress.clamp(0.0, 1.0);
+
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t * t,
+            Easing::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+        }
+    }
+}
+
+/// The duration and [`Easing`] of the change between two values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Transition {
+    /// The duration of the [`Transition`].
+    pub duration: Duration,
+
+    /// The [`Easing`] of the [`Transition`].
+    pub easing: Easing,
+}
+
+impl Transition {
+    /// A [`Transition`] that changes values instantly.
+    pub const NONE: Self = Self {
+        duration: Duration::ZERO,
+        easing: Easing::Linear,
+    };
+
+    /// Creates a new [`Transition`] with the given duration, easing in and
+    /// out.
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            easing: Easing::EaseInOut,
+        }
+    }
+
+    /// Sets the [`Easing`] of the [`Transition`].
+    pub fn easing(self, easing: Easing) -> Self {
+        Self { easing, ..self }
+    }
+
+    /// Returns the linear progress of the [`Transition`] after the given
+    /// elapsed time, between `0.0` and `1.0`.
+    pub fn progress(&self, elapsed: Duration) -> f32 {
+        if elapsed >= self.duration {
+            1.0
+        } else {
+            elapsed.as_secs_f32() / self.duration.as_secs_f32()
+        }
+    }
+}
+
+/// A value that can be interpolated.
+pub trait Interpolate: Sized {
+    /// Interpolates between `self` and `other` by the given factor, where
+    /// `0.0` produces `self` and `1.0` produces `other`.
+    fn interpolate(&self, other: &Self, factor: f32) -> Self;
+}
+
+impl Interpolate for f32 {
+    fn interpolate(&self, other: &Self, factor: f32) -> Self {
+        self + (other - self) * factor
+    }
+}
+
+impl Interpolate for Color {
+    fn interpolate(&self, other: &Self, factor: f32) -> Self {
+        Color {
+            r: self.r.interpolate(&other.r, factor),
+            g: self.g.interpolate(&other.g, factor),
+            b: self.b.interpolate(&other.b, factor),
+            a: self.a.interpolate(&other.a, factor),
+        }
+    }
+}
+
+/// A missing [`Color`] fades from or into the transparent version of the
+/// other one.
+impl Interpolate for Option<Color> {
+    fn interpolate(&self, other: &Self, factor: f32) -> Self {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a.interpolate(b, factor)),
+            (Some(a), None) => Some(a.interpolate(&transparent(*a), factor)),
+            (None, Some(b)) => Some(transparent(*b).interpolate(b, factor)),
+            (None, None) => None,
+        }
+    }
+}
+
+impl Interpolate for Background {
+    fn interpolate(&self, other: &Self, factor: f32) -> Self {
+        match (self, other) {
+            (Background::Color(a), Background::Color(b)) => {
+                Background::Color(a.interpolate(b, factor))
+            }
+        }
+    }
+}
+
+/// A missing [`Background`] fades from or into the transparent version of
+/// the other one.
+impl Interpolate for Option<Background> {
+    fn interpolate(&self, other: &Self, factor: f32) -> Self {
+        let color = |background: &Option<Background>| {
+            background.map(|background| match background {
+                Background::Color(color) => color,
+            })
+        };
+
+        color(self)
+            .interpolate(&color(other), factor)
+            .map(Background::Color)
+    }
+}
+
+impl Interpolate for Vector {
+    fn interpolate(&self, other: &Self, factor: f32) -> Self {
+        Vector::new(
+            self.x.interpolate(&other.x, factor),
+            self.y.interpolate(&other.y, factor),
+        )
+    }
+}
+
+impl Interpolate for Point {
+    fn interpolate(&self, other: &Self, factor: f32) -> Self {
+        Point::new(
+            self.x.interpolate(&other.x, factor),
+            self.y.interpolate(&other.y, factor),
+        )
+    }
+}
+
+impl Interpolate for Size {
+    fn interpolate(&self, other: &Self, factor: f32) -> Self {
+        Size::new(
+            self.width.interpolate(&other.width, factor),
+            self.height.interpolate(&other.height, factor),
+        )
+    }
+}
+
+fn transparent(color: Color) -> Color {
+    Color { a: 0.0, ..color }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EASINGS: [Easing; 4] = [
+        Easing::Linear,
+        Easing::EaseIn,
+        Easing::EaseOut,
+        Easing::EaseInOut,
+    ];
+
+    #[test]
+    fn easings_start_at_zero_and_end_at_one() {
+        for easing in EASINGS {
+            assert_eq!(easing.apply(0.0), 0.0, "{:?}", easing);
+            assert_eq!(easing.apply(1.0), 1.0, "{:?}", easing);
+
+            // Progress out of bounds is clamped
+            assert_eq!(easing.apply(-1.0), 0.0, "{:?}", easing);
+            assert_eq!(easing.apply(2.0), 1.0, "{:?}", easing);
+        }
+
+        assert_eq!(Easing::EaseInOut.apply(0.5), 0.5);
+        assert!(Easing::EaseIn.apply(0.5) < 0.5);
+        assert!(Easing::EaseOut.apply(0.5) > 0.5);
+    }
+
+    #[test]
+    fn transition_progress_is_linear_until_its_duration() {
+        let transition = Transition::new(Duration::from_millis(200));
+
+        assert_eq!(transition.progress(Duration::ZERO), 0.0);
+        assert_eq!(transition.progress(Duration::from_millis(50)), 0.25);
+        assert_eq!(transition.progress(Duration::from_millis(200)), 1.0);
+        assert_eq!(transition.progress(Duration::from_secs(1)), 1.0);
+
+        assert_eq!(Transition::NONE.progress(Duration::ZERO), 1.0);
+    }
+
+    #[test]
+    fn interpolation_produces_the_endpoints() {
+        let from = Color::from_rgb(1.0, 0.0, 0.0);
+        let to = Color::from_rgba(0.0, 0.0, 1.0, 0.5);
+
+        assert_eq!(from.interpolate(&to, 0.0), from);
+        assert_eq!(from.interpolate(&to, 1.0), to);
+        assert_eq!(
+            from.interpolate(&to, 0.5),
+            Color::from_rgba(0.5, 0.0, 0.5, 0.75)
+        );
+
+        let from = Point::new(-10.0, 10.0);
+        let to = Point::new(30.0, 20.0);
+
+        assert_eq!(from.interpolate(&to, 0.0), from);
+        assert_eq!(from.interpolate(&to, 1.0), to);
+        assert_eq!(from.interpolate(&to, 0.25), Point::new(0.0, 12.5));
+    }
+
+    #[test]
+    fn missing_colors_fade_through_transparency() {
+        let color = Some(Color::from_rgb(0.0, 1.0, 0.0));
+
+        assert_eq!(color.interpolate(&None, 0.0), color);
+        assert_eq!(
+            color.interpolate(&None, 1.0),
+            Some(Color::from_rgba(0.0, 1.0, 0.0, 0.0))
+        );
+        assert_eq!(
+            None.interpolate(&color, 0.0),
+            Some(Color::from_rgba(0.0, 1.0, 0.0, 0.0))
+        );
+        assert_eq!(None.interpolate(&color, 1.0), color);
+        assert_eq!(None::<Color>.interpolate(&None, 0.5), None);
+    }
+}
@@ -1,12 +1,27 @@
-/// The symbolic name of a keyboard key.
-///
-/// This is mostly the `KeyCode` type found in [`winit`].
-///
-/// [`winit`]: https://docs.rs/winit/0.20.0-alpha3/winit/
-#[derive(Debug, Hash, Ord, PartialOrd, PartialEq, Eq, Clone, Copy)]
-#[repr(u32)]
-#[allow(missing_docs)]
-pub enum KeyCode {
+/// Declares the [`KeyCode`] enum along with the list of all of its variants,
+/// so the list cannot fall out of sync with the enum.
+macro_rules! key_codes {
+    ($($(#[$meta:meta])* $variant:ident,)*) => {
+        /// The symbolic name of a keyboard key.
+        ///
+        /// This is mostly the `KeyCode` type found in [`winit`].
+        ///
+        /// [`winit`]: https://docs.rs/winit/0.20.0-alpha3/winit/
+        #[derive(Debug, Hash, Ord, PartialOrd, PartialEq, Eq, Clone, Copy)]
+        #[repr(u32)]
+        #[allow(missing_docs)]
+        pub enum KeyCode {
+            $($(#[$meta])* $variant,)*
+        }
+
+        impl KeyCode {
+            /// All the [`KeyCode`] variants, in declaration order.
+            pub const ALL: &'static [KeyCode] = &[$(KeyCode::$variant,)*];
+        }
+    };
+}
+
+key_codes! {
     /// The '1' key over the letters.
     Key1,
     /// The '2' key over the letters.
@@ -201,3 +216,17 @@ pub enum KeyCode {
     Paste,
     Cut,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_lists_every_key_code_in_declaration_order() {
+        for (index, key_code) in KeyCode::ALL.iter().enumerate() {
+            assert_eq!(*key_code as usize, index);
+        }
+
+        assert_eq!(KeyCode::ALL.last(), Some(&KeyCode::Cut));
+    }
+}
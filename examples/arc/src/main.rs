@@ -64,8 +64,7 @@ impl Application for Arc {
     }
 
     fn subscription(&self) -> Subscription<Message> {
-        iced::time::every(std::time::Duration::from_millis(10))
-            .map(|_| Message::Tick)
+        iced::window::frames().map(|_| Message::Tick)
     }
 }
 
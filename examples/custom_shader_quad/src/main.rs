@@ -251,7 +251,7 @@ fn fs_main(
 
 use iced::widget::{column, container, text};
 use iced::{
-    executor, mouse, time, Alignment, Application, Command, Element, Length,
+    executor, mouse, window, Alignment, Application, Command, Element, Length,
     Settings, Subscription, Theme, Vector,
 };
 use iced_native::{shader::ShaderContent, subscription};
@@ -424,9 +424,8 @@ impl Application for Example {
                 _ => None,
             });
 
-        let time_sub =
-            time::every(Duration::from_millis(10)).map(Message::Tick);
+        let frames_sub = window::frames().map(Message::Tick);
 
-        Subscription::batch(vec![mouse_sub, time_sub])
+        Subscription::batch(vec![mouse_sub, frames_sub])
     }
 }
@@ -0,0 +1,134 @@
+use iced::dnd;
+use iced::widget::{column, container, draggable, drop_zone, row, text};
+use iced::{Element, Length, Point, Sandbox, Settings};
+
+pub fn main() -> iced::Result {
+    DragAndDrop::run(Settings::default())
+}
+
+struct DragAndDrop {
+    columns: [Vec<String>; 2],
+    hovered: Option<usize>,
+    files: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Card {
+    column: usize,
+    index: usize,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Hovered(usize),
+    Left,
+    Dropped(usize, dnd::Payload),
+}
+
+impl Sandbox for DragAndDrop {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            columns: [
+                vec![
+                    String::from("Write the docs"),
+                    String::from("Fix the tests"),
+                    String::from("Release"),
+                ],
+                vec![String::from("Implement drag and drop")],
+            ],
+            hovered: None,
+            files: Vec::new(),
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Drag and drop - Iced")
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Hovered(column) => {
+                self.hovered = Some(column);
+            }
+            Message::Left => {
+                self.hovered = None;
+            }
+            Message::Dropped(column, payload) => {
+                self.hovered = None;
+
+                if let Some(files) = payload.files() {
+                    self.files = files
+                        .iter()
+                        .map(|path| path.display().to_string())
+                        .collect();
+                } else if let Some(card) = payload.downcast_ref::<Card>() {
+                    let title = self.columns[card.column].remove(card.index);
+
+                    self.columns[column].push(title);
+                }
+            }
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        let columns = row(self
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(i, cards)| self.column(i, cards))
+            .collect())
+        .spacing(20);
+
+        let files = if self.files.is_empty() {
+            column![text("Drop some files from your system on a column!")]
+        } else {
+            column(self.files.iter().map(|file| text(file).into()).collect())
+        };
+
+        container(column![columns, files].spacing(20))
+            .padding(20)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .into()
+    }
+}
+
+impl DragAndDrop {
+    fn column<'a>(
+        &self,
+        i: usize,
+        cards: &'a [String],
+    ) -> Element<'a, Message> {
+        let title = if self.hovered == Some(i) {
+            text(format!("Column {} (drop here!)", i + 1))
+        } else {
+            text(format!("Column {}", i + 1))
+        };
+
+        let cards = cards.iter().enumerate().map(|(index, card)| {
+            draggable(
+                container(text(card)).padding(10),
+                dnd::Payload::new(Card { column: i, index }),
+            )
+            .preview(container(text(card)).padding(10))
+            .into()
+        });
+
+        drop_zone(
+            column(
+                std::iter::once(title.size(24).into())
+                    .chain(cards)
+                    .collect(),
+            )
+            .spacing(10)
+            .width(Length::Units(250))
+            .height(Length::Fill),
+        )
+        .on_hover(move |_payload, _position: Point| Message::Hovered(i))
+        .on_drop(move |payload, _position| Message::Dropped(i, payload))
+        .on_leave(Message::Left)
+        .into()
+    }
+}
@@ -107,6 +107,7 @@ pub fn main() {
 
                 // Map window event to iced event
                 if let Some(event) = iced_winit::conversion::window_event(
+                    iced_winit::window::Id::MAIN,
                     &event,
                     windowed_context.window().scale_factor(),
                     modifiers,
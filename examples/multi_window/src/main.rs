@@ -0,0 +1,81 @@
+use iced::executor;
+use iced::multi_window::Application;
+use iced::widget::{button, column, container, text};
+use iced::window;
+use iced::{Alignment, Command, Element, Length, Settings, Theme};
+
+pub fn main() -> iced::Result {
+    MultiWindow::run(Settings::default())
+}
+
+#[derive(Default)]
+struct MultiWindow {
+    count: i32,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Message {
+    Increment,
+    OpenWindow,
+    CloseWindow(window::Id),
+}
+
+impl Application for MultiWindow {
+    type Executor = executor::Default;
+    type Message = Message;
+    type Theme = Theme;
+    type Flags = ();
+
+    fn new(_flags: ()) -> (Self, Command<Message>) {
+        (Self::default(), Command::none())
+    }
+
+    fn title(&self, window: window::Id) -> String {
+        if window == window::Id::MAIN {
+            String::from("Multi window - Iced")
+        } else {
+            format!("Window {:?} - Iced", window)
+        }
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
+        match message {
+            Message::Increment => {
+                self.count += 1;
+
+                Command::none()
+            }
+            Message::OpenWindow => window::spawn(
+                window::Id::unique(),
+                window::Settings {
+                    size: (400, 300),
+                    ..window::Settings::default()
+                },
+            ),
+            Message::CloseWindow(id) => window::close(id),
+        }
+    }
+
+    fn view(&self, window: window::Id) -> Element<Message> {
+        let controls = if window == window::Id::MAIN {
+            button("Open a new window").on_press(Message::OpenWindow)
+        } else {
+            button("Close this window").on_press(Message::CloseWindow(window))
+        };
+
+        let content = column![
+            text(format!("Shared count: {}", self.count)).size(30),
+            button("Increment").on_press(Message::Increment),
+            controls,
+        ]
+        .spacing(20)
+        .align_items(Alignment::Center);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .center_y()
+            .into()
+    }
+}
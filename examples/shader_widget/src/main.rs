@@ -1,6 +1,6 @@
 use iced::widget::{column, container, text};
 use iced::{
-    executor, time, Alignment, Application, Command, Settings, Subscription,
+    executor, window, Alignment, Application, Command, Settings, Subscription,
     Theme,
 };
 
@@ -142,9 +142,7 @@ impl Application for Example {
     fn subscription(&self) -> Subscription<Message> {
         match self.state {
             State::Idle => Subscription::none(),
-            State::Ticking { .. } => {
-                time::every(Duration::from_millis(10)).map(Message::Tick)
-            }
+            State::Ticking { .. } => window::frames().map(Message::Tick),
         }
     }
 }
@@ -1,6 +1,6 @@
 use iced::theme;
 use iced::widget::tooltip::Position;
-use iced::widget::{button, container, tooltip};
+use iced::widget::{button, container, text, tooltip};
 use iced::{Element, Length, Sandbox, Settings};
 
 pub fn main() -> iced::Result {
@@ -49,7 +49,7 @@ impl Sandbox for Example {
         let tooltip = tooltip(
             button("Press to change position")
                 .on_press(Message::ChangePosition),
-            position_to_text(self.position),
+            text(position_to_text(self.position)),
             self.position,
         )
         .gap(10)
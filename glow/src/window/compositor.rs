@@ -108,4 +108,39 @@ impl<Theme> iced_graphics::window::GLCompositor for Compositor<Theme> {
             backend.present(gl, primitive, viewport, overlay);
         });
     }
+
+    fn screenshot<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+    ) -> Vec<u8> {
+        self.present(renderer, viewport, background_color, overlay);
+
+        let Size { width, height } = viewport.physical_size();
+        let row_length = width as usize * 4;
+        let mut pixels = vec![0; row_length * height as usize];
+
+        unsafe {
+            self.gl.pixel_store_i32(glow::PACK_ALIGNMENT, 1);
+            self.gl.read_pixels(
+                0,
+                0,
+                width as i32,
+                height as i32,
+                glow::RGBA,
+                glow::UNSIGNED_BYTE,
+                glow::PixelPackData::Slice(&mut pixels),
+            );
+        }
+
+        // OpenGL stores the rows of the framebuffer from the bottom up.
+        pixels
+            .chunks_exact(row_length)
+            .rev()
+            .flatten()
+            .copied()
+            .collect()
+    }
 }
@@ -10,7 +10,11 @@ use iced_winit::application;
 use iced_winit::conversion;
 use iced_winit::futures;
 use iced_winit::futures::channel::mpsc;
+use iced_winit::geometry::Geometry;
 use iced_winit::renderer;
+use iced_winit::screenshot;
+use iced_winit::session::Session;
+use iced_winit::trace::Trace;
 use iced_winit::user_interface;
 use iced_winit::{Clipboard, Command, Debug, Proxy, Settings};
 
@@ -35,6 +39,8 @@ where
     use glutin::platform::run_return::EventLoopExtRunReturn;
     use glutin::ContextBuilder;
 
+    let mut trace = Trace::start(&settings);
+
     let mut debug = Debug::new();
     debug.startup_started();
 
@@ -48,14 +54,28 @@ where
         Runtime::new(executor, proxy)
     };
 
+    let geometry_id = application::persisted_geometry_id(&settings);
+    let session = Session::open(&settings).map_err(Error::SessionOpenFailed)?;
+
     let (application, init_command) = {
         let flags = settings.flags;
 
         runtime.enter(|| A::new(flags))
     };
 
+    let mut window_settings = settings.window;
+
+    let restored_geometry = geometry_id
+        .as_deref()
+        .and_then(Geometry::load)
+        .map(|geometry| geometry.clamp(event_loop.available_monitors()));
+
+    if let Some(geometry) = &restored_geometry {
+        geometry.apply(&mut window_settings);
+    }
+
     let context = {
-        let builder = settings.window.into_builder(
+        let builder = window_settings.into_builder(
             &application.title(),
             event_loop.primary_monitor(),
             settings.id,
@@ -115,6 +135,10 @@ where
         }
     };
 
+    if restored_geometry.map_or(false, |geometry| geometry.maximized) {
+        context.window().set_maximized(true);
+    }
+
     #[allow(unsafe_code)]
     let (compositor, renderer) = unsafe {
         C::new(compositor_settings, |address| {
@@ -123,6 +147,7 @@ where
     };
 
     let (mut sender, receiver) = mpsc::unbounded();
+    let (control_sender, mut control_receiver) = mpsc::unbounded();
 
     let mut instance = Box::pin(run_instance::<A, E, C>(
         application,
@@ -132,9 +157,13 @@ where
         proxy,
         debug,
         receiver,
+        control_sender,
         context,
         init_command,
         settings.exit_on_close_request,
+        settings.focus_traversal,
+        geometry_id,
+        session,
     ));
 
     let mut context = task::Context::from_waker(task::noop_waker_ref());
@@ -166,10 +195,22 @@ where
 
             let poll = instance.as_mut().poll(&mut context);
 
-            *control_flow = match poll {
-                task::Poll::Pending => ControlFlow::Wait,
-                task::Poll::Ready(_) => ControlFlow::Exit,
-            };
+            match poll {
+                task::Poll::Pending => {
+                    if let Ok(Some(flow)) = control_receiver.try_next() {
+                        *control_flow = flow;
+                    } else if !matches!(control_flow, ControlFlow::WaitUntil(_))
+                    {
+                        *control_flow = ControlFlow::Wait;
+                    }
+                }
+                task::Poll::Ready(_) => {
+                    // Flush the performance trace, if any, before exiting
+                    drop(trace.take());
+
+                    *control_flow = ControlFlow::Exit;
+                }
+            }
         }
     });
 
@@ -184,9 +225,13 @@ async fn run_instance<A, E, C>(
     mut proxy: glutin::event_loop::EventLoopProxy<A::Message>,
     mut debug: Debug,
     mut receiver: mpsc::UnboundedReceiver<glutin::event::Event<'_, A::Message>>,
+    mut control_sender: mpsc::UnboundedSender<glutin::event_loop::ControlFlow>,
     mut context: glutin::ContextWrapper<glutin::PossiblyCurrent, Window>,
     init_command: Command<A::Message>,
     exit_on_close_request: bool,
+    focus_traversal: bool,
+    geometry_id: Option<String>,
+    mut session: Session,
 ) where
     A: Application + 'static,
     E: Executor + 'static,
@@ -198,13 +243,22 @@ async fn run_instance<A, E, C>(
 
     let mut clipboard = Clipboard::connect(context.window());
     let mut cache = user_interface::Cache::default();
-    let mut state = application::State::new(&application, context.window());
+    let mut state = application::State::new(
+        context.window(),
+        application.title(),
+        application.scale_factor(),
+        application.theme(),
+        &application.style(),
+    );
     let mut viewport_version = state.viewport_version();
+    let mut screenshots = screenshot::Queue::new();
+    let mut geometry =
+        geometry_id.as_ref().map(|_| Geometry::of(context.window()));
 
     application::run_command(
         &application,
         &mut cache,
-        &state,
+        &mut state,
         &mut renderer,
         init_command,
         &mut runtime,
@@ -212,6 +266,7 @@ async fn run_instance<A, E, C>(
         &mut proxy,
         &mut debug,
         context.window(),
+        &mut screenshots,
         || compositor.fetch_information(),
     );
     runtime.track(application.subscription());
@@ -233,31 +288,90 @@ async fn run_instance<A, E, C>(
 
     while let Some(event) = receiver.next().await {
         match event {
+            event::Event::NewEvents(event::StartCause::ResumeTimeReached {
+                ..
+            }) => {
+                if !session.is_replaying() {
+                    events.push(iced_native::Event::Window(
+                        iced_native::window::Id::MAIN,
+                        iced_native::window::Event::RedrawRequested(
+                            iced_native::time::Instant::now(),
+                        ),
+                    ));
+                }
+            }
             event::Event::MainEventsCleared => {
+                let mut cursor_position = state.cursor_position();
+
+                if let Some(step) = session.next_step(context.window()) {
+                    events = step.events;
+                    cursor_position = step.cursor_position;
+                }
+
                 if events.is_empty() && messages.is_empty() {
+                    if let Some(at) = session.next_wake_up(None) {
+                        let _ = control_sender.start_send(
+                            glutin::event_loop::ControlFlow::WaitUntil(at),
+                        );
+                    }
+
                     continue;
                 }
 
+                session.record_update(
+                    state.viewport(),
+                    state.viewport_version(),
+                    &events,
+                    cursor_position,
+                );
+
                 debug.event_processing_started();
 
-                let (interface_state, statuses) = user_interface.update(
+                let (interface_state, mut statuses) = user_interface.update(
                     &events,
-                    state.cursor_position(),
+                    cursor_position,
                     &mut renderer,
                     &mut clipboard,
                     &mut messages,
                 );
 
+                if focus_traversal {
+                    user_interface.traverse_focus(
+                        &events,
+                        &mut statuses,
+                        &renderer,
+                    );
+                }
+
                 debug.event_processing_finished();
 
-                for event in events.drain(..).zip(statuses.into_iter()) {
-                    runtime.broadcast(event);
+                session.record_messages(&messages);
+
+                for (event, status) in
+                    events.drain(..).zip(statuses.into_iter())
+                {
+                    // Subscriptions are notified of redraws once the frame
+                    // is presented
+                    if !application::is_redraw_request(&event) {
+                        runtime.broadcast((event, status));
+                    }
                 }
 
+                let redraw_request = interface_state.redraw_request();
+
+                let _ = control_sender.start_send(
+                    match session.next_wake_up(redraw_request) {
+                        Some(at) => {
+                            glutin::event_loop::ControlFlow::WaitUntil(at)
+                        }
+                        None => glutin::event_loop::ControlFlow::Wait,
+                    },
+                );
+
                 if !messages.is_empty()
                     || matches!(
                         interface_state,
-                        user_interface::State::Outdated
+                        user_interface::State::Outdated { .. }
                     )
                 {
                     let mut cache =
@@ -267,7 +381,7 @@ async fn run_instance<A, E, C>(
                     application::update(
                         &mut application,
                         &mut cache,
-                        &state,
+                        &mut state,
                         &mut renderer,
                         &mut runtime,
                         &mut clipboard,
@@ -275,11 +389,18 @@ async fn run_instance<A, E, C>(
                         &mut debug,
                         &mut messages,
                         context.window(),
+                        &mut screenshots,
                         || compositor.fetch_information(),
                     );
 
                     // Update window
-                    state.synchronize(&application, context.window());
+                    state.synchronize(
+                        context.window(),
+                        application.title(),
+                        application.scale_factor(),
+                        application.theme(),
+                        &application.style(),
+                    );
 
                     let should_exit = application.should_exit();
 
@@ -306,8 +427,16 @@ async fn run_instance<A, E, C>(
                     },
                     state.cursor_position(),
                 );
+                debug.inspect(
+                    &user_interface,
+                    &mut renderer,
+                    state.cursor_position(),
+                );
                 debug.draw_finished();
 
+                let new_mouse_interaction =
+                    state.cursor_icon().unwrap_or(new_mouse_interaction);
+
                 if new_mouse_interaction != mouse_interaction {
                     context.window().set_cursor_icon(
                         conversion::mouse_interaction(new_mouse_interaction),
@@ -322,11 +451,14 @@ async fn run_instance<A, E, C>(
                 event::MacOS::ReceivedUrl(url),
             )) => {
                 use iced_native::event;
-                events.push(iced_native::Event::PlatformSpecific(
-                    event::PlatformSpecific::MacOS(event::MacOS::ReceivedUrl(
-                        url,
-                    )),
-                ));
+
+                if !session.is_replaying() {
+                    events.push(iced_native::Event::PlatformSpecific(
+                        event::PlatformSpecific::MacOS(
+                            event::MacOS::ReceivedUrl(url),
+                        ),
+                    ));
+                }
             }
             event::Event::UserEvent(message) => {
                 messages.push(message);
@@ -365,8 +497,16 @@ async fn run_instance<A, E, C>(
                         },
                         state.cursor_position(),
                     );
+                    debug.inspect(
+                        &user_interface,
+                        &mut renderer,
+                        state.cursor_position(),
+                    );
                     debug.draw_finished();
 
+                    let new_mouse_interaction =
+                        state.cursor_icon().unwrap_or(new_mouse_interaction);
+
                     if new_mouse_interaction != mouse_interaction {
                         context.window().set_cursor_icon(
                             conversion::mouse_interaction(
@@ -396,15 +536,53 @@ async fn run_instance<A, E, C>(
 
                 context.swap_buffers().expect("Swap buffers");
 
+                if !screenshots.is_empty() {
+                    let bytes = compositor.screenshot(
+                        &mut renderer,
+                        state.viewport(),
+                        state.background_color(),
+                        &debug.overlay(),
+                    );
+
+                    for message in screenshots.fulfill(
+                        bytes,
+                        state.physical_size(),
+                        state.scale_factor(),
+                    ) {
+                        proxy
+                            .send_event(message)
+                            .expect("Send message to event loop");
+                    }
+                }
+
                 debug.render_finished();
 
-                // TODO: Handle animations!
-                // Maybe we can use `ControlFlow::WaitUntil` for this.
+                // Notify subscriptions of the presented frame
+                runtime.broadcast((
+                    iced_native::Event::Window(
+                        iced_native::window::Id::MAIN,
+                        iced_native::window::Event::RedrawRequested(
+                            iced_native::time::Instant::now(),
+                        ),
+                    ),
+                    iced_native::event::Status::Ignored,
+                ));
             }
             event::Event::WindowEvent {
                 event: window_event,
                 ..
             } => {
+                // The application may be terminated right after a close
+                // request, so its geometry is stored right away.
+                if let (
+                    winit::event::WindowEvent::CloseRequested,
+                    Some(id),
+                    Some(geometry),
+                ) = (&window_event, &geometry_id, geometry)
+                {
+                    application::save_geometry(id, geometry);
+                }
+
                 if application::requests_exit(&window_event, state.modifiers())
                     && exit_on_close_request
                 {
@@ -413,7 +591,18 @@ async fn run_instance<A, E, C>(
 
                 state.update(context.window(), &window_event, &mut debug);
 
+                if let Some(geometry) = &mut geometry {
+                    geometry.track(context.window(), &window_event);
+                }
+
+                // When replaying a session, the recorded events are used
+                // instead.
+                if session.is_replaying() {
+                    continue;
+                }
+
                 if let Some(event) = conversion::window_event(
+                    iced_native::window::Id::MAIN,
                     &window_event,
                     state.scale_factor(),
                     state.modifiers(),
@@ -425,6 +614,10 @@ async fn run_instance<A, E, C>(
         }
     }
 
+    if let (Some(id), Some(geometry)) = (geometry_id, geometry) {
+        application::save_geometry(&id, geometry);
+    }
+
     // Manually drop the user interface
     drop(ManuallyDrop::into_inner(user_interface));
 }
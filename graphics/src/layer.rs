@@ -106,6 +106,19 @@ impl<'a> Layer<'a> {
             );
         }
 
+        #[cfg(feature = "trace")]
+        for (i, layer) in layers.iter().enumerate() {
+            tracing::debug!(
+                layer = i,
+                custom_shader_quads = layer.custom_shader_quads.len(),
+                quads = layer.quads.len(),
+                meshes = layer.meshes.len(),
+                text = layer.text.len(),
+                images = layer.images.len(),
+                "layer primitives"
+            );
+        }
+
         layers
     }
 
@@ -55,6 +55,25 @@ pub trait Compositor: Sized {
         background_color: Color,
         overlay: &[T],
     ) -> Result<(), SurfaceError>;
+
+    /// Renders the [`Renderer`] primitives again into an offscreen buffer
+    /// with the size of the given [`Viewport`] and returns its contents as
+    /// RGBA bytes, row by row.
+    ///
+    /// This is meant to be called right after [`present`], so the returned
+    /// bytes match the last presented frame of the [`Surface`].
+    ///
+    /// [`Renderer`]: Self::Renderer
+    /// [`Surface`]: Self::Surface
+    /// [`present`]: Self::present
+    fn screenshot<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        surface: &mut Self::Surface,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+    ) -> Vec<u8>;
 }
 
 /// Result of an unsuccessful call to [`Compositor::present`].
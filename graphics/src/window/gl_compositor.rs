@@ -68,4 +68,21 @@ pub trait GLCompositor: Sized {
         background_color: Color,
         overlay: &[T],
     );
+
+    /// Reads back the contents of the framebuffer of the [`GLCompositor`]
+    /// as RGBA bytes, row by row from the top, after drawing the primitives
+    /// of the [`Renderer`] again.
+    ///
+    /// This is meant to be called right after [`present`] and swapping the
+    /// buffers, so the returned bytes match the last presented frame.
+    ///
+    /// [`Renderer`]: crate::Renderer
+    /// [`present`]: Self::present
+    fn screenshot<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+    ) -> Vec<u8>;
 }
@@ -197,6 +197,14 @@ where
 
         local_shell.revalidate_layout(|| shell.invalidate_layout());
 
+        if local_shell.is_overlay_invalid() {
+            shell.invalidate_overlay();
+        }
+
+        if let Some(at) = local_shell.redraw_request() {
+            shell.request_redraw(at);
+        }
+
         if !local_messages.is_empty() {
             let mut heads = self.state.take().unwrap().into_heads();
 
@@ -230,6 +238,16 @@ where
         event_status
     }
 
+    fn inspect(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+    ) -> widget::inspect::Node {
+        self.with_element(|element| {
+            element.as_widget().inspect(&tree.children[0], layout)
+        })
+    }
+
     fn operate(
         &self,
         tree: &mut Tree,
@@ -244,11 +262,38 @@ where
             fn container(
                 &mut self,
                 id: Option<&widget::Id>,
+                bounds: Rectangle,
                 operate_on_children: &mut dyn FnMut(
                     &mut dyn widget::Operation<T>,
                 ),
             ) {
-                self.operation.container(id, &mut |operation| {
+                self.operation.container(id, bounds, &mut |operation| {
+                    operate_on_children(&mut MapOperation { operation });
+                });
+            }
+
+            fn focus_scope(
+                &mut self,
+                id: Option<&widget::Id>,
+                bounds: Rectangle,
+                operate_on_children: &mut dyn FnMut(
+                    &mut dyn widget::Operation<T>,
+                ),
+            ) {
+                self.operation.focus_scope(id, bounds, &mut |operation| {
+                    operate_on_children(&mut MapOperation { operation });
+                });
+            }
+
+            fn focus_trap(
+                &mut self,
+                id: Option<&widget::Id>,
+                bounds: Rectangle,
+                operate_on_children: &mut dyn FnMut(
+                    &mut dyn widget::Operation<T>,
+                ),
+            ) {
+                self.operation.focus_trap(id, bounds, &mut |operation| {
                     operate_on_children(&mut MapOperation { operation });
                 });
             }
@@ -261,6 +306,16 @@ where
                 self.operation.focusable(state, id);
             }
 
+            fn scrollable(
+                &mut self,
+                state: &mut dyn widget::operation::Scrollable,
+                id: Option<&widget::Id>,
+                bounds: Rectangle,
+                content_bounds: Rectangle,
+            ) {
+                self.operation.scrollable(state, id, bounds, content_bounds);
+            }
+
             fn text_input(
                 &mut self,
                 state: &mut dyn widget::operation::TextInput,
@@ -268,6 +323,15 @@ where
             ) {
                 self.operation.text_input(state, id);
             }
+
+            fn text(
+                &mut self,
+                id: Option<&widget::Id>,
+                bounds: Rectangle,
+                text: &str,
+            ) {
+                self.operation.text(id, bounds, text);
+            }
         }
 
         self.with_element(|element| {
@@ -479,6 +543,14 @@ where
 
         local_shell.revalidate_layout(|| shell.invalidate_layout());
 
+        if local_shell.is_overlay_invalid() {
+            shell.invalidate_overlay();
+        }
+
+        if let Some(at) = local_shell.redraw_request() {
+            shell.request_redraw(at);
+        }
+
         if !local_messages.is_empty() {
             let overlay = self.overlay.take().unwrap().into_heads();
             let mut heads = overlay.instance.state.take().unwrap().into_heads();
@@ -126,6 +126,16 @@ where
         })
     }
 
+    fn inspect(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+    ) -> widget::inspect::Node {
+        self.with_element(|element| {
+            element.as_widget().inspect(&tree.children[0], layout)
+        })
+    }
+
     fn operate(
         &self,
         tree: &mut Tree,
@@ -3,8 +3,8 @@ use iced_native::layout::{self, Layout};
 use iced_native::mouse;
 use iced_native::overlay;
 use iced_native::renderer;
-use iced_native::widget::horizontal_space;
 use iced_native::widget::tree::{self, Tree};
+use iced_native::widget::{self, horizontal_space};
 use iced_native::{
     Clipboard, Element, Length, Point, Rectangle, Shell, Size, Widget,
 };
@@ -142,6 +142,32 @@ where
         layout::Node::new(limits.max())
     }
 
+    fn inspect(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+    ) -> widget::inspect::Node {
+        let state = tree.state.downcast_ref::<State>();
+        let content = self.content.borrow();
+
+        // The content was resolved during the last draw, which is always
+        // performed before inspecting.
+        let content_layout = Layout::with_offset(
+            layout.position() - Point::ORIGIN,
+            &content.layout,
+        );
+
+        widget::inspect::Node::new::<Self>(
+            layout.bounds(),
+            self.width(),
+            self.height(),
+        )
+        .children([content
+            .element
+            .as_widget()
+            .inspect(&state.tree.borrow(), content_layout)])
+    }
+
     fn on_event(
         &mut self,
         tree: &mut Tree,
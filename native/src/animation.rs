@@ -0,0 +1,422 @@
+//! Animate values over time.
+//!
+//! Widgets can keep an [`Animation`], a [`Spring`] or a [`State`] in their
+//! [`Tree`] state and advance them when they receive a
+//! [`window::Event::RedrawRequested`], requesting new redraws through the
+//! [`Shell`] while they are in motion.
+//!
+//! [`Tree`]: crate::widget::Tree
+pub use iced_core::animation::{Easing, Interpolate, Transition};
+
+use crate::time::{Duration, Instant};
+use crate::window;
+use crate::{Event, Shell};
+
+use std::cell::Cell;
+
+/// A value that tweens towards a target following a [`Transition`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Animation<T> {
+    from: T,
+    to: T,
+    started_at: Option<Instant>,
+    transition: Transition,
+}
+
+impl<T> Animation<T>
+where
+    T: Interpolate + Clone,
+{
+    /// Creates a new [`Animation`] resting at the given value.
+    pub fn new(value: T) -> Self {
+        Self {
+            from: value.clone(),
+            to: value,
+            started_at: None,
+            transition: Transition::NONE,
+        }
+    }
+
+    /// Sets the [`Transition`] of the [`Animation`].
+    pub fn transition(mut self, transition: Transition) -> Self {
+        self.transition = transition;
+        self
+    }
+
+    /// Starts animating towards the given target at the given [`Instant`],
+    /// from the value the [`Animation`] has at that moment.
+    pub fn go_to(&mut self, target: T, now: Instant) {
+        self.from = self.value(now);
+        self.to = target;
+        self.started_at = Some(now);
+    }
+
+    /// Returns the target of the [`Animation`].
+    pub fn target(&self) -> &T {
+        &self.to
+    }
+
+    /// Returns the value of the [`Animation`] at the given [`Instant`].
+    pub fn value(&self, now: Instant) -> T {
+        let progress = self.progress(now);
+
+        if progress >= 1.0 {
+            self.to.clone()
+        } else {
+            self.from
+                .interpolate(&self.to, self.transition.easing.apply(progress))
+        }
+    }
+
+    /// Returns true if the [`Animation`] is still in motion at the given
+    /// [`Instant`].
+    pub fn is_animating(&self, now: Instant) -> bool {
+        self.progress(now) < 1.0
+    }
+
+    fn progress(&self, now: Instant) -> f32 {
+        match self.started_at {
+            Some(started_at) => {
+                self.transition.progress(since(started_at, now))
+            }
+            None => 1.0,
+        }
+    }
+}
+
+/// A value that follows a target with spring physics.
+///
+/// Unlike an [`Animation`], a [`Spring`] keeps its velocity when its target
+/// changes, which makes it a good fit for values that are retargeted often,
+/// like positions following the cursor.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spring {
+    value: f32,
+    velocity: f32,
+    target: f32,
+    stiffness: f32,
+    damping: f32,
+    last_tick: Option<Instant>,
+}
+
+impl Spring {
+    /// The step of the simulation of a [`Spring`].
+    const STEP: Duration = Duration::from_millis(4);
+
+    /// The distance and speed under which a [`Spring`] is considered at rest.
+    const REST_THRESHOLD: f32 = 0.001;
+
+    /// Creates a new [`Spring`] resting at the given value.
+    pub fn new(value: f32) -> Self {
+        Self {
+            value,
+            velocity: 0.0,
+            target: value,
+            stiffness: 170.0,
+            damping: 26.0,
+            last_tick: None,
+        }
+    }
+
+    /// Sets the stiffness of the [`Spring`]. Stiffer springs move faster.
+    pub fn stiffness(self, stiffness: f32) -> Self {
+        Self { stiffness, ..self }
+    }
+
+    /// Sets the damping of the [`Spring`]. Less damped springs oscillate
+    /// more before coming to rest.
+    pub fn damping(self, damping: f32) -> Self {
+        Self { damping, ..self }
+    }
+
+    /// Sets the target of the [`Spring`].
+    pub fn set_target(&mut self, target: f32) {
+        self.target = target;
+    }
+
+    /// Returns the target of the [`Spring`].
+    pub fn target(&self) -> f32 {
+        self.target
+    }
+
+    /// Returns the current value of the [`Spring`].
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+
+    /// Returns true if the [`Spring`] has come to rest at its target.
+    pub fn is_at_rest(&self) -> bool {
+        (self.target - self.value).abs() < Self::REST_THRESHOLD
+            && self.velocity.abs() < Self::REST_THRESHOLD
+    }
+
+    /// Advances the simulation of the [`Spring`] until the given [`Instant`].
+    ///
+    /// Returns true if the [`Spring`] is still in motion.
+    pub fn tick(&mut self, now: Instant) -> bool {
+        let elapsed = match self.last_tick {
+            // Long pauses are not simulated, to avoid large jumps
+            Some(last_tick) => {
+                since(last_tick, now).min(Duration::from_millis(100))
+            }
+            None => Duration::ZERO,
+        };
+
+        self.last_tick = Some(now);
+
+        let mut remaining = elapsed.as_secs_f32();
+        let step = Self::STEP.as_secs_f32();
+
+        while remaining > 0.0 {
+            let dt = remaining.min(step);
+            let force = self.stiffness * (self.target - self.value)
+                - self.damping * self.velocity;
+
+            self.velocity += force * dt;
+            self.value += self.velocity * dt;
+
+            remaining -= dt;
+        }
+
+        if self.is_at_rest() {
+            self.value = self.target;
+            self.velocity = 0.0;
+            self.last_tick = None;
+
+            false
+        } else {
+            true
+        }
+    }
+}
+
+/// The status of a widget, along with the [`Transition`] from its previous
+/// status.
+///
+/// Widgets can keep a [`State`] to interpolate between the appearances of
+/// their statuses when drawing, instead of switching instantly.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct State<S> {
+    current: S,
+    previous: S,
+    changed_at: Option<Instant>,
+    now: Option<Instant>,
+    transition: Cell<Transition>,
+}
+
+impl<S> State<S>
+where
+    S: Copy + PartialEq,
+{
+    /// Creates a new [`State`] with the given status.
+    pub fn new(status: S) -> Self {
+        Self {
+            current: status,
+            previous: status,
+            changed_at: None,
+            now: None,
+            transition: Cell::new(Transition::NONE),
+        }
+    }
+
+    /// Returns the current status of the [`State`].
+    pub fn status(&self) -> S {
+        self.current
+    }
+
+    /// Updates the [`State`] with the status of the widget after it has
+    /// processed the given [`Event`].
+    ///
+    /// A change of status starts a new transition, and redraws are
+    /// requested through the [`Shell`] until it finishes.
+    pub fn update<Message>(
+        &mut self,
+        status: S,
+        event: &Event,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let now = match event {
+            Event::Window(_, window::Event::RedrawRequested(now)) => *now,
+            _ => Instant::now(),
+        };
+
+        self.now = Some(now);
+
+        if status != self.current {
+            let duration = self.transition.get().duration;
+
+            // Reverting an unfinished transition starts from where it is
+            let elapsed = if status == self.previous {
+                duration.saturating_sub(self.elapsed())
+            } else {
+                Duration::ZERO
+            };
+
+            self.previous = self.current;
+            self.current = status;
+            self.changed_at = Some(now.checked_sub(elapsed).unwrap_or(now));
+        }
+
+        if self.is_animating() {
+            shell.request_redraw(now);
+        }
+    }
+
+    /// Returns true if the [`State`] is transitioning between statuses.
+    pub fn is_animating(&self) -> bool {
+        self.progress() < 1.0
+    }
+
+    /// Interpolates between the values of the previous and the current
+    /// status of the [`State`], following the given [`Transition`].
+    ///
+    /// The [`Transition`] is remembered to know when to stop requesting
+    /// redraws.
+    pub fn interpolate<T: Interpolate>(
+        &self,
+        transition: Transition,
+        value: impl Fn(S) -> T,
+    ) -> T {
+        self.transition.set(transition);
+
+        let progress = self.progress();
+
+        if progress >= 1.0 {
+            value(self.current)
+        } else {
+            value(self.previous).interpolate(
+                &value(self.current),
+                transition.easing.apply(progress),
+            )
+        }
+    }
+
+    fn elapsed(&self) -> Duration {
+        match (self.changed_at, self.now) {
+            (Some(changed_at), Some(now)) => since(changed_at, now),
+            _ => Duration::MAX,
+        }
+    }
+
+    fn progress(&self) -> f32 {
+        self.transition.get().progress(self.elapsed())
+    }
+}
+
+/// Returns the time elapsed between two instants, or zero if they are not in
+/// order.
+fn since(earlier: Instant, now: Instant) -> Duration {
+    if now > earlier {
+        now - earlier
+    } else {
+        Duration::ZERO
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn redraw(now: Instant) -> Event {
+        Event::Window(window::Id::MAIN, window::Event::RedrawRequested(now))
+    }
+
+    fn opacity(state: &State<bool>, transition: Transition) -> f32 {
+        state.interpolate(
+            transition,
+            |is_visible| {
+                if is_visible {
+                    1.0
+                } else {
+                    0.0
+                }
+            },
+        )
+    }
+
+    #[test]
+    fn animation_interpolates_towards_its_target() {
+        let start = Instant::now();
+        let transition =
+            Transition::new(Duration::from_millis(100)).easing(Easing::Linear);
+
+        let mut animation = Animation::new(0.0).transition(transition);
+        animation.go_to(10.0, start);
+
+        assert_eq!(animation.value(start), 0.0);
+        assert_eq!(animation.value(start + Duration::from_millis(50)), 5.0);
+        assert_eq!(animation.value(start + Duration::from_millis(100)), 10.0);
+        assert!(!animation.is_animating(start + Duration::from_millis(100)));
+    }
+
+    #[test]
+    fn reversing_a_state_mid_way_continues_from_its_value() {
+        let start = Instant::now();
+        let transition =
+            Transition::new(Duration::from_millis(100)).easing(Easing::Linear);
+
+        let mut messages = Vec::<()>::new();
+        let mut shell = Shell::new(&mut messages);
+        let mut state = State::new(false);
+
+        assert_eq!(opacity(&state, transition), 0.0);
+
+        state.update(true, &redraw(start), &mut shell);
+
+        assert_eq!(opacity(&state, transition), 0.0);
+        assert!(state.is_animating());
+
+        let halfway = start + Duration::from_millis(50);
+        state.update(true, &redraw(halfway), &mut shell);
+
+        assert_eq!(opacity(&state, transition), 0.5);
+
+        state.update(false, &redraw(halfway), &mut shell);
+
+        assert_eq!(opacity(&state, transition), 0.5);
+
+        state.update(
+            false,
+            &redraw(halfway + Duration::from_millis(25)),
+            &mut shell,
+        );
+
+        assert_eq!(opacity(&state, transition), 0.25);
+
+        state.update(
+            false,
+            &redraw(start + Duration::from_secs(1)),
+            &mut shell,
+        );
+
+        assert_eq!(opacity(&state, transition), 0.0);
+        assert!(!state.is_animating());
+    }
+
+    #[test]
+    fn spring_settles_at_its_target() {
+        let start = Instant::now();
+        let mut spring = Spring::new(0.0);
+
+        spring.set_target(100.0);
+
+        assert!(spring.tick(start));
+        assert!(!spring.is_at_rest());
+
+        let frame = Duration::from_millis(16);
+        let mut frames = 1;
+
+        while spring.tick(start + frame * frames) {
+            assert!(frames < 200, "spring did not settle");
+
+            frames += 1;
+        }
+
+        assert!(spring.is_at_rest());
+        assert_eq!(spring.value(), 100.0);
+
+        // A settled spring does not move until retargeted
+        assert!(!spring.tick(start + frame * (frames + 1)));
+        assert_eq!(spring.value(), 100.0);
+    }
+}
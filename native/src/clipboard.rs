@@ -2,6 +2,7 @@
 use iced_futures::MaybeSend;
 
 use std::fmt;
+use std::path::PathBuf;
 
 /// A buffer for short-term storage and transfer within and between
 /// applications.
@@ -11,6 +12,116 @@ pub trait Clipboard {
 
     /// Writes the given text contents to the [`Clipboard`].
     fn write(&mut self, contents: String);
+
+    /// Reads the current content of the primary selection as text.
+    ///
+    /// The primary selection is only available on X11 and Wayland. By
+    /// default, it returns `None`.
+    fn read_primary(&self) -> Option<String> {
+        None
+    }
+
+    /// Writes the given text contents to the primary selection.
+    ///
+    /// The primary selection is only available on X11 and Wayland. By
+    /// default, it does nothing.
+    fn write_primary(&mut self, _contents: String) {}
+
+    /// Reads the current content of the [`Clipboard`] in the given
+    /// [`Format`].
+    ///
+    /// By default, only [`Format::Text`] is supported.
+    fn read_content(&self, format: Format) -> Option<Content> {
+        match format {
+            Format::Text => self.read().map(Content::Text),
+            _ => None,
+        }
+    }
+
+    /// Writes the given [`Content`] to the [`Clipboard`].
+    ///
+    /// By default, only [`Content::Text`] is supported.
+    fn write_content(&mut self, content: Content) {
+        if let Content::Text(contents) = content {
+            self.write(contents);
+        }
+    }
+}
+
+/// The format of some clipboard [`Content`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Format {
+    /// Plain text.
+    Text,
+
+    /// An HTML fragment.
+    Html,
+
+    /// An image.
+    Image,
+
+    /// A list of files.
+    Files,
+}
+
+impl Format {
+    /// Returns the MIME type of the [`Format`].
+    pub fn mime_type(self) -> &'static str {
+        match self {
+            Format::Text => "text/plain;charset=utf-8",
+            Format::Html => "text/html",
+            Format::Image => "image/png",
+            Format::Files => "text/uri-list",
+        }
+    }
+}
+
+/// Some content stored in a [`Clipboard`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Content {
+    /// Plain text.
+    Text(String),
+
+    /// An HTML fragment.
+    Html {
+        /// The HTML markup.
+        html: String,
+
+        /// The plain text alternative for applications that cannot handle
+        /// HTML, if any.
+        alt_text: Option<String>,
+    },
+
+    /// An image.
+    ///
+    /// The pixels can be used directly to create an image [`Handle`].
+    ///
+    /// [`Handle`]: crate::image::Handle::from_pixels
+    Image {
+        /// The width of the image.
+        width: u32,
+
+        /// The height of the image.
+        height: u32,
+
+        /// The pixels of the image, as 32bpp RGBA.
+        pixels: Vec<u8>,
+    },
+
+    /// A list of files.
+    Files(Vec<PathBuf>),
+}
+
+impl Content {
+    /// Returns the [`Format`] of the [`Content`].
+    pub fn format(&self) -> Format {
+        match self {
+            Content::Text(_) => Format::Text,
+            Content::Html { .. } => Format::Html,
+            Content::Image { .. } => Format::Image,
+            Content::Files(_) => Format::Files,
+        }
+    }
 }
 
 /// A null implementation of the [`Clipboard`] trait.
@@ -34,6 +145,19 @@ pub enum Action<T> {
 
     /// Write the given contents to the clipboard.
     Write(String),
+
+    /// Read the primary selection and produce `T` with the result.
+    ReadPrimary(Box<dyn Fn(Option<String>) -> T>),
+
+    /// Write the given contents to the primary selection.
+    WritePrimary(String),
+
+    /// Read the clipboard in the given [`Format`] and produce `T` with the
+    /// result.
+    ReadContent(Format, Box<dyn Fn(Option<Content>) -> T>),
+
+    /// Write the given [`Content`] to the clipboard.
+    WriteContent(Content),
 }
 
 impl<T> Action<T> {
@@ -48,6 +172,14 @@ impl<T> Action<T> {
         match self {
             Self::Read(o) => Action::Read(Box::new(move |s| f(o(s)))),
             Self::Write(content) => Action::Write(content),
+            Self::ReadPrimary(o) => {
+                Action::ReadPrimary(Box::new(move |s| f(o(s))))
+            }
+            Self::WritePrimary(content) => Action::WritePrimary(content),
+            Self::ReadContent(format, o) => {
+                Action::ReadContent(format, Box::new(move |c| f(o(c))))
+            }
+            Self::WriteContent(content) => Action::WriteContent(content),
         }
     }
 }
@@ -57,6 +189,14 @@ impl<T> fmt::Debug for Action<T> {
         match self {
             Self::Read(_) => write!(f, "Action::Read"),
             Self::Write(_) => write!(f, "Action::Write"),
+            Self::ReadPrimary(_) => write!(f, "Action::ReadPrimary"),
+            Self::WritePrimary(_) => write!(f, "Action::WritePrimary"),
+            Self::ReadContent(format, _) => {
+                write!(f, "Action::ReadContent({:?})", format)
+            }
+            Self::WriteContent(content) => {
+                write!(f, "Action::WriteContent({:?})", content.format())
+            }
         }
     }
 }
@@ -19,8 +19,8 @@ pub enum Action<T> {
     /// Run a clipboard action.
     Clipboard(clipboard::Action<T>),
 
-    /// Run a window action.
-    Window(window::Action<T>),
+    /// Run a window action on the window with the given [`window::Id`].
+    Window(window::Id, window::Action<T>),
 
     /// Run a system action.
     System(system::Action<T>),
@@ -46,7 +46,7 @@ impl<T> Action<T> {
         match self {
             Self::Future(future) => Action::Future(Box::pin(future.map(f))),
             Self::Clipboard(action) => Action::Clipboard(action.map(f)),
-            Self::Window(window) => Action::Window(window.map(f)),
+            Self::Window(id, window) => Action::Window(id, window.map(f)),
             Self::System(system) => Action::System(system.map(f)),
             Self::Widget(widget) => Action::Widget(widget.map(f)),
         }
@@ -60,7 +60,9 @@ impl<T> fmt::Debug for Action<T> {
             Self::Clipboard(action) => {
                 write!(f, "Action::Clipboard({:?})", action)
             }
-            Self::Window(action) => write!(f, "Action::Window({:?})", action),
+            Self::Window(id, action) => {
+                write!(f, "Action::Window({:?}, {:?})", id, action)
+            }
             Self::System(action) => write!(f, "Action::System({:?})", action),
             Self::Widget(_action) => write!(f, "Action::Widget"),
         }
@@ -1,5 +1,8 @@
 #![allow(missing_docs)]
+use crate::application;
 use crate::time;
+use crate::widget;
+use crate::{Point, UserInterface};
 
 use std::collections::VecDeque;
 
@@ -31,6 +34,11 @@ pub struct Debug {
 
     message_count: usize,
     last_messages: VecDeque<String>,
+
+    inspector_enabled: bool,
+    inspector_lines: Vec<String>,
+
+    spans: Spans,
 }
 
 impl Debug {
@@ -63,6 +71,11 @@ impl Debug {
 
             message_count: 0,
             last_messages: VecDeque::new(),
+
+            inspector_enabled: false,
+            inspector_lines: Vec::new(),
+
+            spans: Spans::default(),
         }
     }
 
@@ -70,70 +83,94 @@ impl Debug {
         self.is_enabled = !self.is_enabled;
     }
 
+    pub fn toggle_inspector(&mut self) {
+        self.inspector_enabled = !self.inspector_enabled;
+        self.inspector_lines.clear();
+    }
+
     pub fn startup_started(&mut self) {
+        self.spans.enter(Stage::Startup);
         self.startup_start = time::Instant::now();
     }
 
     pub fn startup_finished(&mut self) {
         self.startup_duration = time::Instant::now() - self.startup_start;
+        self.spans.exit(Stage::Startup);
     }
 
     pub fn update_started(&mut self) {
+        self.spans.enter(Stage::Update);
         self.update_start = time::Instant::now();
     }
 
     pub fn update_finished(&mut self) {
         self.update_durations
             .push(time::Instant::now() - self.update_start);
+        self.spans.exit(Stage::Update);
     }
 
     pub fn view_started(&mut self) {
+        self.spans.enter(Stage::View);
         self.view_start = time::Instant::now();
     }
 
     pub fn view_finished(&mut self) {
         self.view_durations
             .push(time::Instant::now() - self.view_start);
+        self.spans.exit(Stage::View);
     }
 
     pub fn layout_started(&mut self) {
+        self.spans.enter(Stage::Layout);
         self.layout_start = time::Instant::now();
     }
 
     pub fn layout_finished(&mut self) {
         self.layout_durations
             .push(time::Instant::now() - self.layout_start);
+        self.spans.exit(Stage::Layout);
     }
 
     pub fn event_processing_started(&mut self) {
+        self.spans.enter(Stage::EventProcessing);
         self.event_start = time::Instant::now();
     }
 
     pub fn event_processing_finished(&mut self) {
         self.event_durations
             .push(time::Instant::now() - self.event_start);
+        self.spans.exit(Stage::EventProcessing);
     }
 
     pub fn draw_started(&mut self) {
+        self.spans.enter(Stage::Draw);
         self.draw_start = time::Instant::now();
     }
 
     pub fn draw_finished(&mut self) {
         self.draw_durations
             .push(time::Instant::now() - self.draw_start);
+        self.spans.exit(Stage::Draw);
     }
 
     pub fn render_started(&mut self) {
+        self.spans.enter(Stage::Render);
         self.render_start = time::Instant::now();
     }
 
     pub fn render_finished(&mut self) {
         self.render_durations
             .push(time::Instant::now() - self.render_start);
+        self.spans.exit(Stage::Render);
     }
 
     pub fn log_message<Message: std::fmt::Debug>(&mut self, message: &Message) {
-        self.last_messages.push_back(format!("{:?}", message));
+        let message = format!("{:?}", message);
+
+        #[cfg(feature = "trace")]
+        tracing::debug!(%message, "message");
+
+        self.last_messages.push_back(message);
 
         if self.last_messages.len() > 10 {
             let _ = self.last_messages.pop_front();
@@ -142,9 +179,40 @@ impl Debug {
         self.message_count += 1;
     }
 
+    /// Highlights the widget under the cursor and describes the widget tree
+    /// of the [`UserInterface`], if the inspector is enabled.
+    ///
+    /// This must be called right after drawing the [`UserInterface`].
+    pub fn inspect<Message, Renderer>(
+        &mut self,
+        user_interface: &UserInterface<'_, Message, Renderer>,
+        renderer: &mut Renderer,
+        cursor_position: Point,
+    ) where
+        Renderer: crate::Renderer,
+        Renderer::Theme: application::StyleSheet,
+    {
+        if !self.inspector_enabled {
+            return;
+        }
+
+        let root = user_interface.inspect();
+        let hovered = root.find(cursor_position);
+
+        if let Some(node) = hovered {
+            widget::inspect::highlight(renderer, node);
+        }
+
+        self.inspector_lines = root.lines(hovered);
+    }
+
     pub fn overlay(&self) -> Vec<String> {
         if !self.is_enabled {
-            return Vec::new();
+            return if self.inspector_enabled {
+                self.inspector_overlay()
+            } else {
+                Vec::new()
+            };
         }
 
         let mut lines = Vec::new();
@@ -182,6 +250,18 @@ impl Debug {
             }
         }));
 
+        if self.inspector_enabled {
+            lines.extend(self.inspector_overlay());
+        }
+
+        lines
+    }
+
+    fn inspector_overlay(&self) -> Vec<String> {
+        let mut lines = vec![String::from("Inspector:")];
+
+        lines.extend(self.inspector_lines.iter().cloned());
+
         lines
     }
 }
@@ -192,6 +272,66 @@ impl Default for Debug {
     }
 }
 
+/// A stage of the lifecycle of an application.
+#[derive(Debug, Clone, Copy)]
+enum Stage {
+    Startup,
+    Update,
+    View,
+    Layout,
+    EventProcessing,
+    Draw,
+    Render,
+}
+
+/// The `tracing` spans of the stages in progress.
+///
+/// Spans are only emitted with the `trace` feature; otherwise, this does
+/// nothing.
+#[derive(Debug, Default)]
+struct Spans {
+    #[cfg(feature = "trace")]
+    entered: [Option<tracing::span::EnteredSpan>; 7],
+}
+
+impl Spans {
+    fn enter(&mut self, stage: Stage) {
+        #[cfg(feature = "trace")]
+        {
+            // A stage that was never finished is closed first, so spans
+            // are always exited in the reverse order they were entered.
+            self.entered[stage as usize] = None;
+
+            let span = match stage {
+                Stage::Startup => tracing::info_span!("startup"),
+                Stage::Update => tracing::info_span!("update"),
+                Stage::View => tracing::info_span!("view"),
+                Stage::Layout => tracing::info_span!("layout"),
+                Stage::EventProcessing => {
+                    tracing::info_span!("event_processing")
+                }
+                Stage::Draw => tracing::info_span!("draw"),
+                Stage::Render => tracing::info_span!("render"),
+            };
+
+            self.entered[stage as usize] = Some(span.entered());
+        }
+
+        #[cfg(not(feature = "trace"))]
+        let _ = stage;
+    }
+
+    fn exit(&mut self, stage: Stage) {
+        #[cfg(feature = "trace")]
+        {
+            self.entered[stage as usize] = None;
+        }
+
+        #[cfg(not(feature = "trace"))]
+        let _ = stage;
+    }
+}
+
 #[derive(Debug)]
 struct TimeBuffer {
     head: usize,
@@ -1,4 +1,7 @@
 #![allow(missing_docs)]
+use crate::application;
+use crate::{Point, UserInterface};
+
 #[derive(Debug, Default)]
 pub struct Debug;
 
@@ -41,6 +44,17 @@ impl Debug {
     ) {
     }
 
+    pub fn inspect<Message, Renderer>(
+        &mut self,
+        _user_interface: &UserInterface<'_, Message, Renderer>,
+        _renderer: &mut Renderer,
+        _cursor_position: Point,
+    ) where
+        Renderer: crate::Renderer,
+        Renderer::Theme: application::StyleSheet,
+    {
+    }
+
     pub fn overlay(&self) -> Vec<String> {
         Vec::new()
     }
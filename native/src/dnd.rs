@@ -0,0 +1,232 @@
+//! Drag and drop content between widgets and from the operating system.
+use crate::event;
+use crate::keyboard;
+use crate::mouse;
+use crate::touch;
+use crate::window;
+use crate::Point;
+
+use std::any::Any;
+use std::fmt;
+use std::ops::Range;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// The content being dragged.
+#[derive(Clone)]
+pub enum Payload {
+    /// A list of files dragged from the operating system.
+    Files(Vec<PathBuf>),
+
+    /// Some data dragged from a widget of the application.
+    Data(Arc<dyn Any + Send + Sync>),
+}
+
+impl Payload {
+    /// Creates a new [`Payload`] containing the given data.
+    pub fn new<T>(data: T) -> Self
+    where
+        T: Any + Send + Sync,
+    {
+        Self::Data(Arc::new(data))
+    }
+
+    /// Returns the dragged files, if the [`Payload`] contains files.
+    pub fn files(&self) -> Option<&[PathBuf]> {
+        match self {
+            Self::Files(files) => Some(files),
+            Self::Data(_) => None,
+        }
+    }
+
+    /// Returns a reference to the data of the [`Payload`], if it contains
+    /// data of type `T`.
+    pub fn downcast_ref<T>(&self) -> Option<&T>
+    where
+        T: Any,
+    {
+        match self {
+            Self::Files(_) => None,
+            Self::Data(data) => data.downcast_ref(),
+        }
+    }
+}
+
+impl fmt::Debug for Payload {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Files(files) => f.debug_tuple("Files").field(files).finish(),
+            Self::Data(_) => write!(f, "Data(..)"),
+        }
+    }
+}
+
+impl PartialEq for Payload {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Files(a), Self::Files(b)) => a == b,
+            (Self::Data(a), Self::Data(b)) => Arc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+/// A drag and drop event.
+///
+/// The position of the events is given in window coordinates. Drop targets
+/// should only react to the events happening inside their bounds.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    /// A [`Payload`] is being dragged over the given position.
+    Moved {
+        /// The dragged [`Payload`].
+        payload: Payload,
+
+        /// The position of the cursor.
+        position: Point,
+    },
+
+    /// A [`Payload`] has been dropped at the given position.
+    Dropped {
+        /// The dropped [`Payload`].
+        payload: Payload,
+
+        /// The position of the cursor.
+        position: Point,
+    },
+
+    /// The current drag has left the window or has been cancelled.
+    Left,
+}
+
+/// The drag and drop state of a user interface.
+///
+/// It turns the mouse, touch, keyboard and file events of the window into
+/// drag and drop [`Event`]s.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Tracker {
+    drag: Option<Drag>,
+}
+
+#[derive(Debug, Clone)]
+struct Drag {
+    payload: Payload,
+    is_external: bool,
+}
+
+impl Tracker {
+    /// Starts an internal drag with the given [`Payload`].
+    pub fn start(&mut self, payload: Payload) {
+        self.drag = Some(Drag {
+            payload,
+            is_external: false,
+        });
+    }
+
+    /// Returns whether a drag is in progress.
+    pub fn is_active(&self) -> bool {
+        self.drag.is_some()
+    }
+
+    /// Produces the drag and drop [`Event`]s caused by the given events,
+    /// along with the range of events that caused each of them.
+    ///
+    /// Consecutive file drops are grouped into a single [`Event::Dropped`].
+    pub fn translate(
+        &mut self,
+        events: &[event::Event],
+        cursor_position: Point,
+    ) -> Vec<(Range<usize>, Event)> {
+        use event::Event as E;
+
+        let mut translated = Vec::new();
+        let mut i = 0;
+
+        while i < events.len() {
+            let start = i;
+            i += 1;
+
+            let event = match &events[start] {
+                E::Mouse(mouse::Event::CursorMoved { position })
+                | E::Touch(touch::Event::FingerMoved { position, .. }) => {
+                    self.internal().map(|drag| Event::Moved {
+                        payload: drag.payload.clone(),
+                        position: *position,
+                    })
+                }
+                E::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                | E::Touch(touch::Event::FingerLifted { .. }) => {
+                    match self.drag.take() {
+                        Some(drag) if !drag.is_external => {
+                            Some(Event::Dropped {
+                                payload: drag.payload,
+                                position: cursor_position,
+                            })
+                        }
+                        drag => {
+                            self.drag = drag;
+
+                            None
+                        }
+                    }
+                }
+                E::Keyboard(keyboard::Event::KeyPressed {
+                    key_code: keyboard::KeyCode::Escape,
+                    ..
+                }) => self.drag.take().map(|_| Event::Left),
+                E::Window(_, window::Event::FileHovered(path)) => {
+                    match &mut self.drag {
+                        Some(Drag {
+                            payload: Payload::Files(files),
+                            is_external: true,
+                        }) => files.push(path.clone()),
+                        _ => {
+                            self.drag = Some(Drag {
+                                payload: Payload::Files(vec![path.clone()]),
+                                is_external: true,
+                            });
+                        }
+                    }
+
+                    self.drag.as_ref().map(|drag| Event::Moved {
+                        payload: drag.payload.clone(),
+                        position: cursor_position,
+                    })
+                }
+                E::Window(_, window::Event::FilesHoveredLeft) => {
+                    self.drag.take().map(|_| Event::Left)
+                }
+                E::Window(_, window::Event::FileDropped(path)) => {
+                    let mut files = vec![path.clone()];
+
+                    while let Some(E::Window(
+                        _,
+                        window::Event::FileDropped(path),
+                    )) = events.get(i)
+                    {
+                        files.push(path.clone());
+                        i += 1;
+                    }
+
+                    self.drag = None;
+
+                    Some(Event::Dropped {
+                        payload: Payload::Files(files),
+                        position: cursor_position,
+                    })
+                }
+                _ => None,
+            };
+
+            if let Some(event) = event {
+                translated.push((start..i, event));
+            }
+        }
+
+        translated
+    }
+
+    fn internal(&self) -> Option<&Drag> {
+        self.drag.as_ref().filter(|drag| !drag.is_external)
+    }
+}
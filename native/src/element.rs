@@ -286,6 +286,14 @@ where
         self.widget.layout(renderer, limits)
     }
 
+    fn inspect(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+    ) -> widget::inspect::Node {
+        self.widget.inspect(tree, layout)
+    }
+
     fn operate(
         &self,
         tree: &mut Tree,
@@ -300,11 +308,38 @@ where
             fn container(
                 &mut self,
                 id: Option<&widget::Id>,
+                bounds: Rectangle,
+                operate_on_children: &mut dyn FnMut(
+                    &mut dyn widget::Operation<T>,
+                ),
+            ) {
+                self.operation.container(id, bounds, &mut |operation| {
+                    operate_on_children(&mut MapOperation { operation });
+                });
+            }
+
+            fn focus_scope(
+                &mut self,
+                id: Option<&widget::Id>,
+                bounds: Rectangle,
                 operate_on_children: &mut dyn FnMut(
                     &mut dyn widget::Operation<T>,
                 ),
             ) {
-                self.operation.container(id, &mut |operation| {
+                self.operation.focus_scope(id, bounds, &mut |operation| {
+                    operate_on_children(&mut MapOperation { operation });
+                });
+            }
+
+            fn focus_trap(
+                &mut self,
+                id: Option<&widget::Id>,
+                bounds: Rectangle,
+                operate_on_children: &mut dyn FnMut(
+                    &mut dyn widget::Operation<T>,
+                ),
+            ) {
+                self.operation.focus_trap(id, bounds, &mut |operation| {
                     operate_on_children(&mut MapOperation { operation });
                 });
             }
@@ -321,8 +356,10 @@ where
                 &mut self,
                 state: &mut dyn widget::operation::Scrollable,
                 id: Option<&widget::Id>,
+                bounds: Rectangle,
+                content_bounds: Rectangle,
             ) {
-                self.operation.scrollable(state, id);
+                self.operation.scrollable(state, id, bounds, content_bounds);
             }
 
             fn text_input(
@@ -332,6 +369,15 @@ where
             ) {
                 self.operation.text_input(state, id);
             }
+
+            fn text(
+                &mut self,
+                id: Option<&widget::Id>,
+                bounds: Rectangle,
+                text: &str,
+            ) {
+                self.operation.text(id, bounds, text);
+            }
         }
 
         self.widget
@@ -478,6 +524,14 @@ where
         self.element.widget.operate(state, layout, operation)
     }
 
+    fn inspect(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+    ) -> widget::inspect::Node {
+        self.element.widget.inspect(state, layout)
+    }
+
     fn on_event(
         &mut self,
         state: &mut Tree,
@@ -1,4 +1,5 @@
 //! Handle events of a user interface.
+use crate::dnd;
 use crate::keyboard;
 use crate::mouse;
 use crate::touch;
@@ -18,12 +19,15 @@ pub enum Event {
     /// A mouse event
     Mouse(mouse::Event),
 
-    /// A window event
-    Window(window::Event),
+    /// A window event, along with the [`window::Id`] of the window
+    Window(window::Id, window::Event),
 
     /// A touch event
     Touch(touch::Event),
 
+    /// A drag and drop event
+    DragAndDrop(dnd::Event),
+
     /// A platform specific event
     PlatformSpecific(PlatformSpecific),
 }
@@ -44,8 +44,10 @@
 #![forbid(unsafe_code, rust_2018_idioms)]
 #![allow(clippy::inherent_to_string, clippy::type_complexity)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
+pub mod animation;
 pub mod clipboard;
 pub mod command;
+pub mod dnd;
 pub mod event;
 pub mod image;
 pub mod keyboard;
@@ -54,10 +56,12 @@ pub mod mouse;
 pub mod overlay;
 pub mod program;
 pub mod renderer;
+pub mod session;
 pub mod shader;
 pub mod subscription;
 pub mod svg;
 pub mod system;
+pub mod testing;
 pub mod text;
 pub mod touch;
 pub mod user_interface;
@@ -1,4 +1,9 @@
 //! Build and show dropdown menus.
+mod cascade;
+mod item;
+
+pub use item::Item;
+
 use crate::alignment;
 use crate::event::{self, Event};
 use crate::layout;
@@ -15,9 +20,18 @@ use crate::{
     Shell, Size, Vector, Widget,
 };
 
-pub use iced_style::menu::{Appearance, StyleSheet};
+pub use iced_style::menu::{
+    Appearance, BarAppearance, ItemAppearance, StyleSheet,
+};
 
 /// A list of selectable options.
+///
+/// A [`Menu`] can either display a flat list of options or a list of
+/// [`Item`]s with nested submenus, separators, and accelerators.
+///
+/// Each kind is shown by its own overlay: a flat list scrolls in a single
+/// panel, while a list of [`Item`]s opens a panel next to the previous one for
+/// every submenu.
 #[allow(missing_debug_implementations)]
 pub struct Menu<'a, T, Renderer>
 where
@@ -25,8 +39,7 @@ where
     Renderer::Theme: StyleSheet,
 {
     state: &'a mut State,
-    options: &'a [T],
-    hovered_option: &'a mut Option<usize>,
+    options: Options<'a, T>,
     last_selection: &'a mut Option<T>,
     width: u16,
     padding: Padding,
@@ -35,6 +48,15 @@ where
     style: <Renderer::Theme as StyleSheet>::Style,
 }
 
+enum Options<'a, T> {
+    List {
+        options: &'a [T],
+        hovered_option: &'a mut Option<usize>,
+        to_string: fn(&T) -> String,
+    },
+    Items(&'a [Item<T>]),
+}
+
 impl<'a, T, Renderer> Menu<'a, T, Renderer>
 where
     T: ToString + Clone,
@@ -49,11 +71,47 @@ where
         options: &'a [T],
         hovered_option: &'a mut Option<usize>,
         last_selection: &'a mut Option<T>,
+    ) -> Self {
+        Self::with_options(
+            state,
+            Options::List {
+                options,
+                hovered_option,
+                to_string: T::to_string,
+            },
+            last_selection,
+        )
+    }
+}
+
+impl<'a, T, Renderer> Menu<'a, T, Renderer>
+where
+    T: Clone,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme:
+        StyleSheet + container::StyleSheet + scrollable::StyleSheet,
+{
+    /// Creates a new [`Menu`] with the given [`State`] and [`Item`]s, which
+    /// may open nested submenus next to each other.
+    ///
+    /// The value of the selected [`Item`] is stored in `last_selection`, and
+    /// the [`State`] is closed.
+    pub fn with_items(
+        state: &'a mut State,
+        items: &'a [Item<T>],
+        last_selection: &'a mut Option<T>,
+    ) -> Self {
+        Self::with_options(state, Options::Items(items), last_selection)
+    }
+
+    fn with_options(
+        state: &'a mut State,
+        options: Options<'a, T>,
+        last_selection: &'a mut Option<T>,
     ) -> Self {
         Menu {
             state,
             options,
-            hovered_option,
             last_selection,
             width: 0,
             padding: Padding::ZERO,
@@ -64,6 +122,9 @@ where
     }
 
     /// Sets the width of the [`Menu`].
+    ///
+    /// A [`Menu`] with [`Item`]s uses it as the minimum width of every
+    /// submenu.
     pub fn width(mut self, width: u16) -> Self {
         self.width = width;
         self
@@ -107,10 +168,49 @@ where
         position: Point,
         target_height: f32,
     ) -> overlay::Element<'a, Message, Renderer> {
-        overlay::Element::new(
-            position,
-            Box::new(Overlay::new(self, target_height)),
-        )
+        match self.options {
+            Options::List {
+                options,
+                hovered_option,
+                to_string,
+            } => {
+                let list = List {
+                    options,
+                    hovered_option,
+                    last_selection: self.last_selection,
+                    to_string,
+                    font: self.font,
+                    text_size: self.text_size,
+                    padding: self.padding,
+                    style: self.style.clone(),
+                };
+
+                overlay::Element::new(
+                    position,
+                    Box::new(Overlay::new(
+                        self.state,
+                        list,
+                        self.width,
+                        target_height,
+                        self.style,
+                    )),
+                )
+            }
+            Options::Items(items) => overlay::Element::new(
+                position,
+                Box::new(cascade::Overlay {
+                    state: self.state,
+                    items,
+                    last_selection: self.last_selection,
+                    width: self.width,
+                    padding: self.padding,
+                    text_size: self.text_size,
+                    font: self.font,
+                    style: self.style,
+                    target_height,
+                }),
+            ),
+        }
     }
 }
 
@@ -118,6 +218,10 @@ where
 #[derive(Debug)]
 pub struct State {
     tree: Tree,
+    is_open: bool,
+    path: Vec<usize>,
+    hovered: Option<(usize, usize)>,
+    viewport: Option<(Rectangle, Rectangle)>,
 }
 
 impl State {
@@ -125,6 +229,56 @@ impl State {
     pub fn new() -> Self {
         Self {
             tree: Tree::empty(),
+            is_open: false,
+            path: Vec::new(),
+            hovered: None,
+            viewport: None,
+        }
+    }
+
+    /// Returns whether the [`Menu`] is open or not.
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+
+    /// Opens the [`Menu`], with all of its submenus closed.
+    pub fn open(&mut self) {
+        self.is_open = true;
+        self.path.clear();
+        self.hovered = None;
+    }
+
+    /// Closes the [`Menu`].
+    pub fn close(&mut self) {
+        self.is_open = false;
+        self.path.clear();
+        self.hovered = None;
+    }
+
+    /// Scrolls a [`Menu`] with a flat list of options so the option at the
+    /// given index is visible, given the height of every option.
+    ///
+    /// It does nothing until the [`Menu`] has processed an event, since its
+    /// visible bounds are only known after it has been laid out.
+    pub fn scroll_to_option(&mut self, index: usize, option_height: f32) {
+        let (bounds, content_bounds) = match self.viewport {
+            Some(viewport) => viewport,
+            None => return,
+        };
+
+        let scrollable = match self.tree.children.first_mut() {
+            Some(tree) => tree.state.downcast_mut::<scrollable::State>(),
+            None => return,
+        };
+
+        let offset = scrollable.offset(bounds, content_bounds) as f32;
+        let top = index as f32 * option_height;
+        let bottom = top + option_height;
+
+        if top < offset {
+            scrollable.scroll_to_absolute(top);
+        } else if bottom > offset + bounds.height {
+            scrollable.scroll_to_absolute(bottom - bounds.height);
         }
     }
 }
@@ -140,7 +294,7 @@ where
     Renderer: crate::Renderer,
     Renderer::Theme: StyleSheet + container::StyleSheet,
 {
-    state: &'a mut Tree,
+    state: &'a mut State,
     container: Container<'a, Message, Renderer>,
     width: u16,
     target_height: f32,
@@ -155,36 +309,22 @@ where
     Renderer::Theme:
         StyleSheet + container::StyleSheet + scrollable::StyleSheet,
 {
-    pub fn new<T>(menu: Menu<'a, T, Renderer>, target_height: f32) -> Self
+    pub fn new<T>(
+        state: &'a mut State,
+        list: List<'a, T, Renderer>,
+        width: u16,
+        target_height: f32,
+        style: <Renderer::Theme as StyleSheet>::Style,
+    ) -> Self
     where
-        T: Clone + ToString,
+        T: Clone,
     {
-        let Menu {
-            state,
-            options,
-            hovered_option,
-            last_selection,
-            width,
-            padding,
-            font,
-            text_size,
-            style,
-        } = menu;
-
-        let container = Container::new(Scrollable::new(List {
-            options,
-            hovered_option,
-            last_selection,
-            font,
-            text_size,
-            padding,
-            style: style.clone(),
-        }));
+        let container = Container::new(Scrollable::new(list));
 
         state.tree.diff(&container as &dyn Widget<_, _>);
 
         Self {
-            state: &mut state.tree,
+            state,
             container,
             width,
             target_height,
@@ -241,8 +381,15 @@ where
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
+        let scrollable = layout.children().next().unwrap();
+
+        self.state.viewport = Some((
+            scrollable.bounds(),
+            scrollable.children().next().unwrap().bounds(),
+        ));
+
         self.container.on_event(
-            self.state,
+            &mut self.state.tree,
             event,
             layout,
             cursor_position,
@@ -260,7 +407,7 @@ where
         renderer: &Renderer,
     ) -> mouse::Interaction {
         self.container.mouse_interaction(
-            self.state,
+            &self.state.tree,
             layout,
             cursor_position,
             viewport,
@@ -293,7 +440,7 @@ where
         );
 
         self.container.draw(
-            self.state,
+            &self.state.tree,
             renderer,
             theme,
             style,
@@ -312,6 +459,7 @@ where
     options: &'a [T],
     hovered_option: &'a mut Option<usize>,
     last_selection: &'a mut Option<T>,
+    to_string: fn(&T) -> String,
     padding: Padding,
     text_size: Option<u16>,
     font: Renderer::Font,
@@ -321,7 +469,7 @@ where
 impl<'a, T, Message, Renderer> Widget<Message, Renderer>
     for List<'a, T, Renderer>
 where
-    T: Clone + ToString,
+    T: Clone,
     Renderer: text::Renderer,
     Renderer::Theme: StyleSheet,
 {
@@ -486,7 +634,7 @@ where
             }
 
             renderer.fill_text(Text {
-                content: &option.to_string(),
+                content: &(self.to_string)(option),
                 bounds: Rectangle {
                     x: bounds.x + self.padding.left as f32,
                     y: bounds.center_y(),
@@ -510,7 +658,7 @@ where
 impl<'a, T, Message, Renderer> From<List<'a, T, Renderer>>
     for Element<'a, Message, Renderer>
 where
-    T: ToString + Clone,
+    T: Clone,
     Message: 'a,
     Renderer: 'a + text::Renderer,
     Renderer::Theme: StyleSheet,
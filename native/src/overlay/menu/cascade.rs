@@ -0,0 +1,629 @@
+//! Show menus of items with nested submenus.
+//!
+//! This overlay is kept apart from the one of a flat list of options. A list
+//! is a single [`Scrollable`] panel whose hovered option is owned by the
+//! widget opening it, like a [`PickList`]. A menu of items lays out a panel
+//! for every open submenu, and tracks the open path and the hovered item in
+//! its [`State`].
+//!
+//! [`Scrollable`]: crate::widget::Scrollable
+//! [`PickList`]: crate::widget::PickList
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::renderer;
+use crate::text::{self, Text};
+use crate::touch;
+use crate::{
+    Clipboard, Color, Layout, Padding, Point, Rectangle, Shell, Size, Vector,
+};
+
+use super::item::{Item, Kind};
+use super::{State, StyleSheet};
+
+/// The overlay of a [`Menu`] with [`Item`]s, which opens its submenus next to
+/// each other.
+///
+/// [`Menu`]: super::Menu
+pub(super) struct Overlay<'a, T, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    pub(super) state: &'a mut State,
+    pub(super) items: &'a [Item<T>],
+    pub(super) last_selection: &'a mut Option<T>,
+    pub(super) width: u16,
+    pub(super) padding: Padding,
+    pub(super) text_size: Option<u16>,
+    pub(super) font: Renderer::Font,
+    pub(super) style: <Renderer::Theme as StyleSheet>::Style,
+    pub(super) target_height: f32,
+}
+
+impl<'a, T, Renderer> Overlay<'a, T, Renderer>
+where
+    T: Clone,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Returns the items of every open menu, starting from the root.
+    fn levels(&self) -> Vec<&'a [Item<T>]> {
+        let mut levels = vec![self.items];
+
+        for &index in &self.state.path {
+            match levels
+                .last()
+                .and_then(|items| items.get(index))
+                .and_then(Item::submenu_items)
+            {
+                Some(items) => levels.push(items),
+                None => break,
+            }
+        }
+
+        levels
+    }
+
+    fn text_size(&self, renderer: &Renderer) -> f32 {
+        f32::from(self.text_size.unwrap_or_else(|| renderer.default_size()))
+    }
+
+    fn item_height(&self, item: &Item<T>, text_size: f32) -> f32 {
+        if item.is_separator() {
+            (text_size / 2.0).round()
+        } else {
+            text_size + f32::from(self.padding.vertical())
+        }
+    }
+
+    fn panel_width(
+        &self,
+        renderer: &Renderer,
+        items: &[Item<T>],
+        text_size: f32,
+    ) -> f32 {
+        let measure = |content: &str| {
+            renderer.measure_width(content, text_size as u16, self.font.clone())
+        };
+
+        let (label, accelerator) = items.iter().fold(
+            (0.0f32, 0.0f32),
+            |(label, accelerator), item| match &item.kind {
+                Kind::Entry {
+                    label: text,
+                    accelerator: shortcut,
+                    ..
+                } => (
+                    label.max(measure(text)),
+                    accelerator.max(shortcut.as_deref().map_or(0.0, measure)),
+                ),
+                Kind::Submenu { label: text, .. } => {
+                    (label.max(measure(text)), accelerator)
+                }
+                Kind::Separator => (label, accelerator),
+            },
+        );
+
+        let accelerator = if accelerator > 0.0 {
+            text_size + accelerator
+        } else {
+            0.0
+        };
+
+        // The check mark and the submenu arrow take one column each
+        (text_size * 2.0
+            + label
+            + accelerator
+            + f32::from(self.padding.horizontal()))
+        .max(f32::from(self.width))
+        .ceil()
+    }
+
+    /// Produces the [`Item`] under the cursor, if any, as a level and index.
+    fn hovered_item(
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Option<(usize, usize)> {
+        layout
+            .children()
+            .enumerate()
+            .collect::<Vec<_>>()
+            .into_iter()
+            .rev()
+            .find_map(|(level, panel)| {
+                if !panel.bounds().contains(cursor_position) {
+                    return None;
+                }
+
+                panel
+                    .children()
+                    .position(|item| item.bounds().contains(cursor_position))
+                    .map(|index| (level, index))
+            })
+    }
+
+    /// Activates the [`Item`] at the given level and index, either selecting
+    /// it or opening its submenu.
+    ///
+    /// When an [`Item`] is selected, the event is ignored so the owner of the
+    /// [`Menu`] can take the `last_selection`.
+    ///
+    /// [`Menu`]: super::Menu
+    fn activate<Message>(
+        &mut self,
+        level: usize,
+        index: usize,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let levels = self.levels();
+        let state = &mut *self.state;
+
+        let item = match levels.get(level).and_then(|items| items.get(index)) {
+            Some(item) if item.is_selectable() => item,
+            _ => return event::Status::Captured,
+        };
+
+        let status = match &item.kind {
+            Kind::Entry {
+                on_select: Some(value),
+                ..
+            } => {
+                *self.last_selection = Some(value.clone());
+                state.close();
+
+                event::Status::Ignored
+            }
+            Kind::Submenu { items, .. } => {
+                state.path.truncate(level);
+                state.path.push(index);
+                state.hovered = items
+                    .iter()
+                    .position(Item::is_selectable)
+                    .map(|first| (level + 1, first));
+
+                event::Status::Captured
+            }
+            _ => return event::Status::Captured,
+        };
+
+        shell.invalidate_layout();
+
+        status
+    }
+}
+
+impl<'a, T, Message, Renderer> crate::Overlay<Message, Renderer>
+    for Overlay<'a, T, Renderer>
+where
+    T: Clone,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let text_size = self.text_size(renderer);
+
+        let mut panels: Vec<layout::Node> = Vec::new();
+        let mut anchor: Option<Rectangle> = None;
+
+        for (level, items) in self.levels().into_iter().enumerate() {
+            let width = self.panel_width(renderer, items, text_size);
+
+            let mut height = 0.0;
+
+            let children = items
+                .iter()
+                .map(|item| {
+                    let item_height = self.item_height(item, text_size);
+
+                    let mut node =
+                        layout::Node::new(Size::new(width, item_height));
+                    node.move_to(Point::new(0.0, height));
+
+                    height += item_height;
+
+                    node
+                })
+                .collect();
+
+            let panel_position = match anchor {
+                None => {
+                    let space_below =
+                        bounds.height - (position.y + self.target_height);
+                    let space_above = position.y;
+
+                    let y =
+                        if space_below >= height || space_below > space_above {
+                            position.y + self.target_height
+                        } else {
+                            position.y - height
+                        };
+
+                    Point::new(
+                        position.x.min(bounds.width - width).max(0.0),
+                        y.max(0.0),
+                    )
+                }
+                Some(anchor) => {
+                    let x = if anchor.x + anchor.width + width <= bounds.width {
+                        anchor.x + anchor.width
+                    } else {
+                        (anchor.x - width).max(0.0)
+                    };
+
+                    Point::new(x, anchor.y.min(bounds.height - height).max(0.0))
+                }
+            };
+
+            let mut panel =
+                layout::Node::with_children(Size::new(width, height), children);
+            panel.move_to(panel_position);
+
+            anchor = self
+                .state
+                .path
+                .get(level)
+                .and_then(|&index| panel.children().get(index))
+                .map(|item| {
+                    item.bounds()
+                        + Vector::new(panel_position.x, panel_position.y)
+                });
+
+            panels.push(panel);
+        }
+
+        let (top_left, bottom_right) =
+            panels.iter().map(layout::Node::bounds).fold(
+                (
+                    Point::new(f32::INFINITY, f32::INFINITY),
+                    Point::new(f32::NEG_INFINITY, f32::NEG_INFINITY),
+                ),
+                |(top_left, bottom_right), bounds| {
+                    (
+                        Point::new(
+                            top_left.x.min(bounds.x),
+                            top_left.y.min(bounds.y),
+                        ),
+                        Point::new(
+                            bottom_right.x.max(bounds.x + bounds.width),
+                            bottom_right.y.max(bounds.y + bounds.height),
+                        ),
+                    )
+                },
+            );
+
+        let panels = panels
+            .into_iter()
+            .map(|panel| panel.translate(Vector::new(-top_left.x, -top_left.y)))
+            .collect();
+
+        let mut node = layout::Node::with_children(
+            Size::new(bottom_right.x - top_left.x, bottom_right.y - top_left.y),
+            panels,
+        );
+        node.move_to(top_left);
+
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                let levels = self.levels();
+                let state = &mut *self.state;
+
+                match Self::hovered_item(layout, cursor_position) {
+                    Some((level, index)) => {
+                        let item = &levels[level][index];
+
+                        state.hovered =
+                            item.is_selectable().then_some((level, index));
+
+                        if item.submenu_items().is_some() {
+                            if state.path.len() != level + 1
+                                || state.path[level] != index
+                            {
+                                state.path.truncate(level);
+                                state.path.push(index);
+
+                                shell.invalidate_layout();
+                            }
+                        } else if state.path.len() > level {
+                            state.path.truncate(level);
+
+                            shell.invalidate_layout();
+                        }
+                    }
+                    None => {
+                        state.hovered = None;
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(button)) => {
+                if let Some((level, index)) =
+                    Self::hovered_item(layout, cursor_position)
+                {
+                    if button == mouse::Button::Left {
+                        return self.activate(level, index, shell);
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some((level, index)) =
+                    Self::hovered_item(layout, cursor_position)
+                {
+                    return self.activate(level, index, shell);
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) => {
+                let levels = self.levels();
+                let state = &mut *self.state;
+
+                let level =
+                    state.hovered.map_or(state.path.len(), |(level, _)| level);
+
+                match key_code {
+                    keyboard::KeyCode::Escape => {
+                        match state.path.pop() {
+                            Some(index) => {
+                                state.hovered = Some((state.path.len(), index));
+                            }
+                            None => state.close(),
+                        }
+
+                        shell.invalidate_layout();
+                    }
+                    keyboard::KeyCode::Up | keyboard::KeyCode::Down => {
+                        let items = levels.get(level).copied().unwrap_or(&[]);
+                        let current = state.hovered.map(|(_, index)| index);
+
+                        let next = if key_code == keyboard::KeyCode::Down {
+                            let start = current.map_or(0, |index| index + 1);
+
+                            (start..items.len())
+                                .chain(0..start)
+                                .find(|&index| items[index].is_selectable())
+                        } else {
+                            let end = current.unwrap_or(items.len());
+
+                            (0..end)
+                                .rev()
+                                .chain((end..items.len()).rev())
+                                .find(|&index| items[index].is_selectable())
+                        };
+
+                        if let Some(index) = next {
+                            state.hovered = Some((level, index));
+                        }
+                    }
+                    keyboard::KeyCode::Right => {
+                        if let Some((level, index)) = state.hovered {
+                            if levels[level][index].submenu_items().is_some() {
+                                return self.activate(level, index, shell);
+                            }
+                        }
+                    }
+                    keyboard::KeyCode::Left => {
+                        if let Some(index) = state.path.pop() {
+                            state.hovered = Some((state.path.len(), index));
+
+                            shell.invalidate_layout();
+                        }
+                    }
+                    keyboard::KeyCode::Enter
+                    | keyboard::KeyCode::NumpadEnter
+                    | keyboard::KeyCode::Space => {
+                        if let Some((level, index)) = state.hovered {
+                            return self.activate(level, index, shell);
+                        }
+                    }
+                    _ => return event::Status::Ignored,
+                }
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let levels = self.levels();
+
+        match Self::hovered_item(layout, cursor_position) {
+            Some((level, index)) if levels[level][index].is_selectable() => {
+                mouse::Interaction::Pointer
+            }
+            _ => mouse::Interaction::default(),
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+    ) {
+        let appearance = theme.appearance(&self.style);
+        let item_appearance = theme.item(&self.style);
+        let text_size = self.text_size(renderer);
+        let padding = self.padding;
+        let state = &self.state;
+
+        for (level, (items, panel)) in
+            self.levels().into_iter().zip(layout.children()).enumerate()
+        {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: panel.bounds(),
+                    border_color: appearance.border_color,
+                    border_width: appearance.border_width,
+                    border_radius: appearance.border_radius.into(),
+                },
+                appearance.background,
+            );
+
+            for (index, (item, layout)) in
+                items.iter().zip(panel.children()).enumerate()
+            {
+                let bounds = layout.bounds();
+
+                let (label, accelerator, is_checked, has_submenu) = match &item
+                    .kind
+                {
+                    Kind::Entry {
+                        label,
+                        accelerator,
+                        is_checked,
+                        ..
+                    } => (label, accelerator.as_deref(), *is_checked, false),
+                    Kind::Submenu { label, .. } => (label, None, None, true),
+                    Kind::Separator => {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: Rectangle {
+                                    x: bounds.x + f32::from(padding.left),
+                                    y: bounds.center_y().round(),
+                                    width: bounds.width
+                                        - f32::from(padding.horizontal()),
+                                    height: 1.0,
+                                },
+                                border_color: Color::TRANSPARENT,
+                                border_width: 0.0,
+                                border_radius: 0.0.into(),
+                            },
+                            item_appearance.separator_color,
+                        );
+
+                        continue;
+                    }
+                };
+
+                let is_selectable = item.is_selectable();
+                let is_highlighted = is_selectable
+                    && (state.hovered == Some((level, index))
+                        || state.path.get(level) == Some(&index));
+
+                if is_highlighted {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds,
+                            border_color: Color::TRANSPARENT,
+                            border_width: 0.0,
+                            border_radius: appearance.border_radius.into(),
+                        },
+                        appearance.selected_background,
+                    );
+                }
+
+                let text_color = if !is_selectable {
+                    item_appearance.disabled_text_color
+                } else if is_highlighted {
+                    appearance.selected_text_color
+                } else {
+                    appearance.text_color
+                };
+
+                let left = bounds.x + f32::from(padding.left);
+                let right = bounds.x + bounds.width - f32::from(padding.right);
+
+                if is_checked == Some(true) {
+                    renderer.fill_text(Text {
+                        content: &Renderer::CHECKMARK_ICON.to_string(),
+                        font: Renderer::ICON_FONT,
+                        size: text_size * 0.8,
+                        bounds: Rectangle {
+                            x: left,
+                            y: bounds.center_y(),
+                            width: text_size,
+                            ..bounds
+                        },
+                        color: text_color,
+                        horizontal_alignment: alignment::Horizontal::Left,
+                        vertical_alignment: alignment::Vertical::Center,
+                    });
+                }
+
+                renderer.fill_text(Text {
+                    content: label,
+                    bounds: Rectangle {
+                        x: left + text_size,
+                        y: bounds.center_y(),
+                        width: f32::INFINITY,
+                        ..bounds
+                    },
+                    size: text_size,
+                    font: self.font.clone(),
+                    color: text_color,
+                    horizontal_alignment: alignment::Horizontal::Left,
+                    vertical_alignment: alignment::Vertical::Center,
+                });
+
+                if let Some(accelerator) = accelerator {
+                    renderer.fill_text(Text {
+                        content: accelerator,
+                        bounds: Rectangle {
+                            x: right - text_size,
+                            y: bounds.center_y(),
+                            ..bounds
+                        },
+                        size: text_size,
+                        font: self.font.clone(),
+                        color: if is_highlighted {
+                            appearance.selected_text_color
+                        } else {
+                            item_appearance.accelerator_color
+                        },
+                        horizontal_alignment: alignment::Horizontal::Right,
+                        vertical_alignment: alignment::Vertical::Center,
+                    });
+                }
+
+                if has_submenu {
+                    renderer.fill_text(Text {
+                        content: "›",
+                        bounds: Rectangle {
+                            x: right,
+                            y: bounds.center_y(),
+                            ..bounds
+                        },
+                        size: text_size,
+                        font: self.font.clone(),
+                        color: text_color,
+                        horizontal_alignment: alignment::Horizontal::Right,
+                        vertical_alignment: alignment::Vertical::Center,
+                    });
+                }
+            }
+        }
+    }
+}
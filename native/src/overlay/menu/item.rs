@@ -0,0 +1,132 @@
+/// An item of a [`Menu`], which may open a submenu.
+///
+/// [`Menu`]: super::Menu
+#[derive(Debug, Clone)]
+pub struct Item<Message> {
+    pub(super) kind: Kind<Message>,
+}
+
+#[derive(Debug, Clone)]
+pub(super) enum Kind<Message> {
+    Entry {
+        label: String,
+        accelerator: Option<String>,
+        is_checked: Option<bool>,
+        is_enabled: bool,
+        on_select: Option<Message>,
+    },
+    Submenu {
+        label: String,
+        is_enabled: bool,
+        items: Vec<Item<Message>>,
+    },
+    Separator,
+}
+
+impl<Message> Item<Message> {
+    /// Creates a new entry [`Item`] with the given label.
+    ///
+    /// The entry will be disabled until [`Item::on_select`] is set.
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            kind: Kind::Entry {
+                label: label.into(),
+                accelerator: None,
+                is_checked: None,
+                is_enabled: true,
+                on_select: None,
+            },
+        }
+    }
+
+    /// Creates a new [`Item`] that opens a submenu with the given items.
+    pub fn submenu(
+        label: impl Into<String>,
+        items: Vec<Item<Message>>,
+    ) -> Self {
+        Self {
+            kind: Kind::Submenu {
+                label: label.into(),
+                is_enabled: true,
+                items,
+            },
+        }
+    }
+
+    /// Creates a new separator [`Item`].
+    pub fn separator() -> Self {
+        Self {
+            kind: Kind::Separator,
+        }
+    }
+
+    /// Sets the message that will be produced when the [`Item`] is selected.
+    pub fn on_select(mut self, message: Message) -> Self {
+        if let Kind::Entry { on_select, .. } = &mut self.kind {
+            *on_select = Some(message);
+        }
+
+        self
+    }
+
+    /// Sets the keyboard accelerator displayed next to the [`Item`].
+    ///
+    /// The accelerator is only displayed; handling the key combination is
+    /// left to the application.
+    pub fn accelerator(mut self, text: impl Into<String>) -> Self {
+        if let Kind::Entry { accelerator, .. } = &mut self.kind {
+            *accelerator = Some(text.into());
+        }
+
+        self
+    }
+
+    /// Turns the [`Item`] into a checkable item with the given state.
+    pub fn checked(mut self, checked: bool) -> Self {
+        if let Kind::Entry { is_checked, .. } = &mut self.kind {
+            *is_checked = Some(checked);
+        }
+
+        self
+    }
+
+    /// Sets whether the [`Item`] can be selected or not.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        match &mut self.kind {
+            Kind::Entry { is_enabled, .. }
+            | Kind::Submenu { is_enabled, .. } => {
+                *is_enabled = enabled;
+            }
+            Kind::Separator => {}
+        }
+
+        self
+    }
+
+    pub(super) fn is_separator(&self) -> bool {
+        matches!(self.kind, Kind::Separator)
+    }
+
+    pub(super) fn is_selectable(&self) -> bool {
+        match &self.kind {
+            Kind::Entry {
+                is_enabled,
+                on_select,
+                ..
+            } => *is_enabled && on_select.is_some(),
+            Kind::Submenu { is_enabled, .. } => *is_enabled,
+            Kind::Separator => false,
+        }
+    }
+
+    pub(super) fn submenu_items(&self) -> Option<&[Item<Message>]> {
+        match &self.kind {
+            Kind::Submenu {
+                is_enabled: true,
+                items,
+                ..
+            } => Some(items),
+            _ => None,
+        }
+    }
+}
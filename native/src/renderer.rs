@@ -1,8 +1,9 @@
 //! Write your own renderer.
-#[cfg(debug_assertions)]
 mod null;
-#[cfg(debug_assertions)]
+mod recording;
+
 pub use null::Null;
+pub use recording::{Primitive, Recording};
 
 use crate::layout;
 use crate::{Background, Color, Element, Point, Rectangle, Vector};
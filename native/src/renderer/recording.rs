@@ -0,0 +1,494 @@
+use crate::alignment;
+use crate::image;
+use crate::renderer::{self, Renderer};
+use crate::svg;
+use crate::text::{self, Text};
+use crate::{Background, Color, Font, Point, Rectangle, Size, Theme, Vector};
+
+use std::fmt;
+
+/// A renderer that records its draw calls.
+///
+/// The recorded [`Primitive`] tree can be inspected with
+/// [`primitives`](Self::primitives) or turned into a stable textual snapshot
+/// using its [`Display`](fmt::Display) implementation. This is useful to test
+/// the drawing logic of widgets without a GPU.
+///
+/// Text is measured with a fixed advance of [`CHAR_WIDTH`](Self::CHAR_WIDTH)
+/// times its size per character, so layouts are deterministic on every
+/// platform.
+///
+/// ```
+/// use iced_native::renderer::Recording;
+/// use iced_native::testing::Simulator;
+/// use iced_native::widget::{button, text, Column};
+/// use iced_native::Theme;
+///
+/// let view = Column::<(), Recording>::new()
+///     .push(text("Hello"))
+///     .push(button(text("Click me")));
+///
+/// let mut simulator = Simulator::new(view);
+/// simulator.draw(&Theme::Light);
+///
+/// let snapshot = simulator.renderer().to_string();
+///
+/// assert!(snapshot.contains("text \"Hello\""));
+/// assert!(snapshot.contains("text \"Click me\""));
+/// ```
+///
+/// # Snapshot format
+/// The [`Display`](fmt::Display) implementation of a [`Recording`] is its
+/// stable snapshot format, meant to be stored and compared as plain text.
+/// Changing it is a breaking change.
+///
+/// Every [`Primitive`] is written on its own line, and the primitives of a
+/// layer or a translation are indented by two more spaces:
+///
+/// ```text
+/// quad at=(x, y) size=(w, h) fill=#rrggbbaa radius=[tl, tr, br, bl] border=(width, #rrggbbaa)
+/// text "content" at=(x, y) size=(w, h) size=s color=#rrggbbaa font=name align=(Left, Top)
+/// image <data> at=(x, y) size=(w, h)
+/// svg <data> at=(x, y) size=(w, h) color=#rrggbbaa
+/// shader "source" at=(x, y) size=(w, h) fill=#rrggbbaa
+/// layer at=(x, y) size=(w, h)
+/// translation (x, y)
+/// ```
+///
+/// The `color` of an `svg` is omitted when it has no color filter.
+///
+/// ```
+/// use iced_native::renderer::{self, Recording, Renderer as _};
+/// use iced_native::{Color, Point, Rectangle, Size};
+///
+/// let mut recording = Recording::new();
+/// let layer = Rectangle::new(Point::ORIGIN, Size::new(50.0, 50.0));
+/// let quad = Rectangle::new(Point::new(5.0, 5.0), Size::new(10.0, 20.0));
+///
+/// recording.with_layer(layer, |recording| {
+///     recording.fill_quad(
+///         renderer::Quad {
+///             bounds: quad,
+///             border_radius: 2.0.into(),
+///             border_width: 1.0,
+///             border_color: Color::BLACK,
+///         },
+///         Color::WHITE,
+///     );
+/// });
+///
+/// assert_eq!(
+///     recording.to_string(),
+///     "layer at=(0, 0) size=(50, 50)\n  \
+///      quad at=(5, 5) size=(10, 20) fill=#ffffffff radius=[2, 2, 2, 2] border=(1, #000000ff)\n"
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Recording {
+    primitives: Vec<Primitive>,
+}
+
+/// A draw call captured by a [`Recording`] renderer.
+///
+/// Its [`Display`](fmt::Display) implementation writes it in the
+/// [snapshot format](Recording#snapshot-format).
+#[derive(Debug, Clone)]
+pub enum Primitive {
+    /// A [`renderer::Quad`] filled with a [`Background`].
+    Quad {
+        /// The recorded quad.
+        quad: renderer::Quad,
+        /// The background of the quad.
+        background: Background,
+    },
+    /// A paragraph of text.
+    Text {
+        /// The contents of the text.
+        content: String,
+        /// The bounds of the text.
+        bounds: Rectangle,
+        /// The size of the text.
+        size: f32,
+        /// The color of the text.
+        color: Color,
+        /// The font of the text.
+        font: Font,
+        /// The horizontal alignment of the text.
+        horizontal_alignment: alignment::Horizontal,
+        /// The vertical alignment of the text.
+        vertical_alignment: alignment::Vertical,
+    },
+    /// A raster image.
+    Image {
+        /// The handle of the image.
+        handle: image::Handle,
+        /// The bounds of the image.
+        bounds: Rectangle,
+    },
+    /// A vector image.
+    Svg {
+        /// The handle of the image.
+        handle: svg::Handle,
+        /// The color filter of the image, if any.
+        color: Option<Color>,
+        /// The bounds of the image.
+        bounds: Rectangle,
+    },
+    /// A quad drawn with a custom shader.
+    CustomShaderQuad {
+        /// The recorded quad.
+        quad: renderer::CustomShaderQuad,
+        /// The background of the quad.
+        background: Background,
+    },
+    /// A group of primitives clipped to some bounds.
+    Layer {
+        /// The clip bounds of the layer.
+        bounds: Rectangle,
+        /// The primitives of the layer.
+        primitives: Vec<Primitive>,
+    },
+    /// A group of primitives moved by some translation.
+    Translation {
+        /// The translation applied to the primitives.
+        translation: Vector,
+        /// The translated primitives.
+        primitives: Vec<Primitive>,
+    },
+}
+
+impl Recording {
+    /// The advance of a character, relative to the size of its text.
+    pub const CHAR_WIDTH: f32 = 0.6;
+
+    /// Creates a new [`Recording`] renderer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the primitives recorded so far.
+    pub fn primitives(&self) -> &[Primitive] {
+        &self.primitives
+    }
+
+    /// Takes the primitives recorded so far, leaving the [`Recording`]
+    /// empty.
+    pub fn take(&mut self) -> Vec<Primitive> {
+        std::mem::take(&mut self.primitives)
+    }
+
+    fn record(&mut self, f: impl FnOnce(&mut Self)) -> Vec<Primitive> {
+        let current = std::mem::take(&mut self.primitives);
+
+        f(self);
+
+        std::mem::replace(&mut self.primitives, current)
+    }
+}
+
+impl Renderer for Recording {
+    type Theme = Theme;
+
+    fn with_layer(&mut self, bounds: Rectangle, f: impl FnOnce(&mut Self)) {
+        let primitives = self.record(f);
+
+        self.primitives
+            .push(Primitive::Layer { bounds, primitives });
+    }
+
+    fn with_translation(
+        &mut self,
+        translation: Vector,
+        f: impl FnOnce(&mut Self),
+    ) {
+        let primitives = self.record(f);
+
+        self.primitives.push(Primitive::Translation {
+            translation,
+            primitives,
+        });
+    }
+
+    fn clear(&mut self) {
+        self.primitives.clear();
+    }
+
+    fn fill_quad(
+        &mut self,
+        quad: renderer::Quad,
+        background: impl Into<Background>,
+    ) {
+        self.primitives.push(Primitive::Quad {
+            quad,
+            background: background.into(),
+        });
+    }
+
+    fn make_custom_shader_quad(
+        &mut self,
+        custom_shader_quad: renderer::CustomShaderQuad,
+        background: impl Into<Background>,
+    ) {
+        self.primitives.push(Primitive::CustomShaderQuad {
+            quad: custom_shader_quad,
+            background: background.into(),
+        });
+    }
+}
+
+impl text::Renderer for Recording {
+    type Font = Font;
+
+    const ICON_FONT: Font = Font::Default;
+    const CHECKMARK_ICON: char = '✓';
+    const ARROW_DOWN_ICON: char = '▼';
+
+    fn default_size(&self) -> u16 {
+        20
+    }
+
+    fn measure(
+        &self,
+        content: &str,
+        size: u16,
+        _font: Font,
+        _bounds: Size,
+    ) -> (f32, f32) {
+        let size = f32::from(size);
+
+        let (lines, columns) =
+            content.split('\n').fold((0, 0), |(lines, columns), line| {
+                (lines + 1, columns.max(line.chars().count()))
+            });
+
+        (
+            columns as f32 * size * Self::CHAR_WIDTH,
+            lines as f32 * size,
+        )
+    }
+
+    fn hit_test(
+        &self,
+        contents: &str,
+        size: f32,
+        _font: Self::Font,
+        _bounds: Size,
+        point: Point,
+        _nearest_only: bool,
+    ) -> Option<text::Hit> {
+        let advance = size * Self::CHAR_WIDTH;
+        let line = (point.y / size).floor().max(0.0) as usize;
+
+        let offset: usize = contents
+            .split('\n')
+            .take(line)
+            .map(|line| line.chars().count() + 1)
+            .sum();
+
+        let columns = contents
+            .split('\n')
+            .nth(line)
+            .map(|line| line.chars().count())?;
+
+        let column =
+            ((point.x / advance).round().max(0.0) as usize).min(columns);
+
+        Some(text::Hit::CharOffset(offset + column))
+    }
+
+    fn fill_text(&mut self, text: Text<'_, Self::Font>) {
+        self.primitives.push(Primitive::Text {
+            content: text.content.to_owned(),
+            bounds: text.bounds,
+            size: text.size,
+            color: text.color,
+            font: text.font,
+            horizontal_alignment: text.horizontal_alignment,
+            vertical_alignment: text.vertical_alignment,
+        });
+    }
+}
+
+impl image::Renderer for Recording {
+    type Handle = image::Handle;
+
+    fn dimensions(&self, handle: &image::Handle) -> Size<u32> {
+        match handle.data() {
+            image::Data::Rgba { width, height, .. } => {
+                Size::new(*width, *height)
+            }
+            _ => Size::new(0, 0),
+        }
+    }
+
+    fn draw(&mut self, handle: image::Handle, bounds: Rectangle) {
+        self.primitives.push(Primitive::Image { handle, bounds });
+    }
+}
+
+impl svg::Renderer for Recording {
+    fn dimensions(&self, _handle: &svg::Handle) -> Size<u32> {
+        Size::new(0, 0)
+    }
+
+    fn draw(
+        &mut self,
+        handle: svg::Handle,
+        color: Option<Color>,
+        bounds: Rectangle,
+    ) {
+        self.primitives.push(Primitive::Svg {
+            handle,
+            color,
+            bounds,
+        });
+    }
+}
+
+impl fmt::Display for Recording {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for primitive in &self.primitives {
+            primitive.write(f, 0)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for Primitive {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write(f, 0)
+    }
+}
+
+impl Primitive {
+    fn write(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+        write!(f, "{:indent$}", "", indent = depth * 2)?;
+
+        match self {
+            Primitive::Quad { quad, background } => {
+                let [top_left, top_right, bottom_right, bottom_left]: [f32; 4] =
+                    quad.border_radius.into();
+
+                writeln!(
+                    f,
+                    "quad {} {} radius=[{}, {}, {}, {}] border=({}, {})",
+                    Bounds(quad.bounds),
+                    Fill(*background),
+                    top_left,
+                    top_right,
+                    bottom_right,
+                    bottom_left,
+                    quad.border_width,
+                    Hex(quad.border_color),
+                )
+            }
+            Primitive::Text {
+                content,
+                bounds,
+                size,
+                color,
+                font,
+                horizontal_alignment,
+                vertical_alignment,
+            } => {
+                let font = match font {
+                    Font::Default => "default",
+                    Font::External { name, .. } => name,
+                };
+
+                writeln!(
+                    f,
+                    "text {:?} {} size={} color={} font={} align=({:?}, {:?})",
+                    content,
+                    Bounds(*bounds),
+                    size,
+                    Hex(*color),
+                    font,
+                    horizontal_alignment,
+                    vertical_alignment,
+                )
+            }
+            Primitive::Image { handle, bounds } => {
+                writeln!(f, "image {:?} {}", handle.data(), Bounds(*bounds))
+            }
+            Primitive::Svg {
+                handle,
+                color,
+                bounds,
+            } => {
+                write!(f, "svg {:?} {}", handle.data(), Bounds(*bounds))?;
+
+                match color {
+                    Some(color) => writeln!(f, " color={}", Hex(*color)),
+                    None => writeln!(f),
+                }
+            }
+            Primitive::CustomShaderQuad { quad, background } => {
+                writeln!(
+                    f,
+                    "shader {:?} {} {}",
+                    quad.handle.shader_content,
+                    Bounds(quad.bounds),
+                    Fill(*background),
+                )
+            }
+            Primitive::Layer { bounds, primitives } => {
+                writeln!(f, "layer {}", Bounds(*bounds))?;
+
+                primitives
+                    .iter()
+                    .try_for_each(|primitive| primitive.write(f, depth + 1))
+            }
+            Primitive::Translation {
+                translation,
+                primitives,
+            } => {
+                writeln!(
+                    f,
+                    "translation ({}, {})",
+                    translation.x, translation.y
+                )?;
+
+                primitives
+                    .iter()
+                    .try_for_each(|primitive| primitive.write(f, depth + 1))
+            }
+        }
+    }
+}
+
+struct Bounds(Rectangle);
+
+impl fmt::Display for Bounds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Rectangle {
+            x,
+            y,
+            width,
+            height,
+        } = self.0;
+
+        write!(f, "at=({}, {}) size=({}, {})", x, y, width, height)
+    }
+}
+
+struct Fill(Background);
+
+impl fmt::Display for Fill {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Background::Color(color) => write!(f, "fill={}", Hex(color)),
+        }
+    }
+}
+
+struct Hex(Color);
+
+impl fmt::Display for Hex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [r, g, b, a] = self.0.into_rgba8();
+
+        write!(f, "#{:02x}{:02x}{:02x}{:02x}", r, g, b, a)
+    }
+}
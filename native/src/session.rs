@@ -0,0 +1,733 @@
+//! Record and replay the events of a user interface.
+//!
+//! A [`Recorder`] writes a session as plain text, one [`Record`] per line,
+//! as it happens; so a recording survives a crash of the application. A
+//! [`Replay`] reads it back as a list of [`Step`]s, which can be fed to
+//! [`UserInterface::update`] to reproduce the session deterministically.
+//!
+//! ```
+//! use iced_native::mouse;
+//! use iced_native::session::{Record, Recorder, Replay};
+//! use iced_native::{Event, Point, Size};
+//!
+//! let mut recorder = Recorder::new(Vec::new());
+//!
+//! recorder.record(&Record::Viewport {
+//!     size: Size::new(800, 600),
+//!     scale_factor: 1.0,
+//! })?;
+//! recorder.record(&Record::Event(Event::Mouse(
+//!     mouse::Event::ButtonPressed(mouse::Button::Left),
+//! )))?;
+//! recorder.record(&Record::Update {
+//!     cursor_position: Point::new(10.0, 20.0),
+//! })?;
+//! recorder.record(&Record::Message(String::from("Increment")))?;
+//!
+//! let contents = String::from_utf8(recorder.into_inner()).unwrap();
+//! let mut replay = Replay::parse(&contents).unwrap();
+//!
+//! let step = replay.next().unwrap();
+//!
+//! assert_eq!(step.viewport, Some((Size::new(800, 600), 1.0)));
+//! assert_eq!(step.cursor_position, Point::new(10.0, 20.0));
+//! assert_eq!(step.messages, vec![String::from("Increment")]);
+//! assert!(replay.next().is_none());
+//! # Ok::<(), std::io::Error>(())
+//! ```
+//!
+//! [`UserInterface::update`]: crate::UserInterface::update
+use crate::event::{self, Event};
+use crate::keyboard::{self, KeyCode, Modifiers};
+use crate::mouse;
+use crate::time::Instant;
+use crate::touch;
+use crate::window;
+use crate::{Point, Size};
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Something that happened during a session.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Record {
+    /// The window was resized or its scale factor changed.
+    Viewport {
+        /// The new physical size of the window.
+        size: Size<u32>,
+
+        /// The new scale factor of the window.
+        scale_factor: f64,
+    },
+
+    /// An [`Event`] was received.
+    ///
+    /// Drag and drop events are produced by the user interface itself and
+    /// are never recorded.
+    Event(Event),
+
+    /// The events received since the last update were processed by the
+    /// user interface.
+    Update {
+        /// The position of the cursor during the update.
+        cursor_position: Point,
+    },
+
+    /// A message was produced, described by its `Debug` representation.
+    Message(String),
+}
+
+/// Writes the [`Record`]s of a session, one per line.
+#[derive(Debug)]
+pub struct Recorder<W: io::Write> {
+    writer: W,
+    start: Instant,
+}
+
+impl<W: io::Write> Recorder<W> {
+    /// Creates a new [`Recorder`] that writes to the given writer.
+    ///
+    /// The timestamps of the [`Record`]s are relative to this moment.
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            start: Instant::now(),
+        }
+    }
+
+    /// Writes a [`Record`] and flushes the writer.
+    pub fn record(&mut self, record: &Record) -> io::Result<()> {
+        let at = Instant::now().duration_since(self.start);
+
+        if let Some(line) = encode(record, self.start) {
+            writeln!(self.writer, "{:.6} {}", at.as_secs_f64(), line)?;
+            self.writer.flush()?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the writer of the [`Recorder`].
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+/// A step of a [`Replay`]: the input processed by a single update of the
+/// user interface.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Step {
+    /// The time of the update, relative to the start of the session.
+    pub at: Duration,
+
+    /// The last physical size and scale factor of the window recorded before
+    /// the update, if it changed.
+    pub viewport: Option<(Size<u32>, f64)>,
+
+    /// The events processed by the update.
+    pub events: Vec<Event>,
+
+    /// The position of the cursor during the update.
+    pub cursor_position: Point,
+
+    /// The `Debug` representation of the messages produced by the update.
+    ///
+    /// Compare them with the messages produced while replaying to find out
+    /// if the replay diverged from the original session.
+    pub messages: Vec<String>,
+}
+
+/// The [`Step`]s of a recorded session, in order.
+#[derive(Debug, Clone)]
+pub struct Replay {
+    start: Instant,
+    steps: VecDeque<Step>,
+}
+
+impl Replay {
+    /// Parses the contents written by a [`Recorder`].
+    ///
+    /// The replay starts at the moment it is parsed; the timestamps of
+    /// redraw requests are shifted accordingly. Records after the last
+    /// update of the session are ignored.
+    pub fn parse(contents: &str) -> Result<Self, Error> {
+        let start = Instant::now();
+        let mut steps = VecDeque::new();
+
+        let mut viewport = None;
+        let mut events = Vec::new();
+
+        for (index, line) in contents.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let (at, record) = decode(line, start).map_err(|reason| Error {
+                line: index + 1,
+                reason,
+            })?;
+
+            match record {
+                Record::Viewport { size, scale_factor } => {
+                    viewport = Some((size, scale_factor));
+                }
+                Record::Event(event) => {
+                    events.push(event);
+                }
+                Record::Update { cursor_position } => {
+                    steps.push_back(Step {
+                        at,
+                        viewport: viewport.take(),
+                        events: std::mem::take(&mut events),
+                        cursor_position,
+                        messages: Vec::new(),
+                    });
+                }
+                Record::Message(message) => match steps.back_mut() {
+                    Some(step) => step.messages.push(message),
+                    None => {
+                        return Err(Error {
+                            line: index + 1,
+                            reason: String::from(
+                                "message recorded before any update",
+                            ),
+                        });
+                    }
+                },
+            }
+        }
+
+        Ok(Self { start, steps })
+    }
+
+    /// Returns the moment the [`Replay`] started.
+    pub fn start(&self) -> Instant {
+        self.start
+    }
+
+    /// Returns the moment the next [`Step`] should be replayed, if any.
+    pub fn next_at(&self) -> Option<Instant> {
+        self.steps.front().map(|step| self.start + step.at)
+    }
+
+    /// Returns the next [`Step`] if it is due at the given moment.
+    pub fn next_due(&mut self, now: Instant) -> Option<Step> {
+        if self.next_at()? <= now {
+            self.steps.pop_front()
+        } else {
+            None
+        }
+    }
+}
+
+impl Iterator for Replay {
+    type Item = Step;
+
+    fn next(&mut self) -> Option<Step> {
+        self.steps.pop_front()
+    }
+}
+
+/// An error produced while parsing a recorded session.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    /// The line of the invalid record, starting at 1.
+    pub line: usize,
+
+    /// Why the record is invalid.
+    pub reason: String,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid record at line {}: {}", self.line, self.reason)
+    }
+}
+
+impl std::error::Error for Error {}
+
+fn encode(record: &Record, start: Instant) -> Option<String> {
+    Some(match record {
+        Record::Viewport { size, scale_factor } => {
+            format!("viewport {} {} {}", size.width, size.height, scale_factor)
+        }
+        Record::Event(event) => {
+            format!("event {}", encode_event(event, start)?)
+        }
+        Record::Update { cursor_position } => {
+            format!("update {} {}", cursor_position.x, cursor_position.y)
+        }
+        Record::Message(message) => format!("message {}", escape(message)),
+    })
+}
+
+fn encode_event(event: &Event, start: Instant) -> Option<String> {
+    Some(match event {
+        Event::Keyboard(event) => match event {
+            keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            } => {
+                format!("keyboard pressed {:?} {}", key_code, modifiers.bits())
+            }
+            keyboard::Event::KeyReleased {
+                key_code,
+                modifiers,
+            } => {
+                format!("keyboard released {:?} {}", key_code, modifiers.bits())
+            }
+            keyboard::Event::CharacterReceived(c) => {
+                format!("keyboard character {}", u32::from(*c))
+            }
+            keyboard::Event::ModifiersChanged(modifiers) => {
+                format!("keyboard modifiers {}", modifiers.bits())
+            }
+        },
+        Event::Mouse(event) => match event {
+            mouse::Event::CursorEntered => String::from("mouse entered"),
+            mouse::Event::CursorLeft => String::from("mouse left"),
+            mouse::Event::CursorMoved { position } => {
+                format!("mouse moved {} {}", position.x, position.y)
+            }
+            mouse::Event::ButtonPressed(button) => {
+                format!("mouse pressed {}", encode_button(*button))
+            }
+            mouse::Event::ButtonReleased(button) => {
+                format!("mouse released {}", encode_button(*button))
+            }
+            mouse::Event::WheelScrolled {
+                delta: mouse::ScrollDelta::Lines { x, y },
+            } => format!("mouse lines {} {}", x, y),
+            mouse::Event::WheelScrolled {
+                delta: mouse::ScrollDelta::Pixels { x, y },
+            } => format!("mouse pixels {} {}", x, y),
+        },
+        Event::Window(id, event) => {
+            let event = match event {
+                window::Event::Moved { x, y } => format!("moved {} {}", x, y),
+                window::Event::Resized { width, height } => {
+                    format!("resized {} {}", width, height)
+                }
+                window::Event::RedrawRequested(at) => format!(
+                    "redraw {:.6}",
+                    at.duration_since(start).as_secs_f64()
+                ),
+                window::Event::CloseRequested => String::from("close"),
+                window::Event::Focused => String::from("focused"),
+                window::Event::Unfocused => String::from("unfocused"),
+                window::Event::FileHovered(path) => {
+                    format!("file-hovered {}", escape(&path.to_string_lossy()))
+                }
+                window::Event::FileDropped(path) => {
+                    format!("file-dropped {}", escape(&path.to_string_lossy()))
+                }
+                window::Event::FilesHoveredLeft => String::from("files-left"),
+            };
+
+            format!("window {} {}", id.0, event)
+        }
+        Event::Touch(event) => {
+            let (kind, id, position) = match event {
+                touch::Event::FingerPressed { id, position } => {
+                    ("pressed", id, position)
+                }
+                touch::Event::FingerMoved { id, position } => {
+                    ("moved", id, position)
+                }
+                touch::Event::FingerLifted { id, position } => {
+                    ("lifted", id, position)
+                }
+                touch::Event::FingerLost { id, position } => {
+                    ("lost", id, position)
+                }
+            };
+
+            format!("touch {} {} {} {}", kind, id.0, position.x, position.y)
+        }
+        Event::DragAndDrop(_) => return None,
+        Event::PlatformSpecific(event::PlatformSpecific::MacOS(
+            event::MacOS::ReceivedUrl(url),
+        )) => format!("macos url {}", escape(url)),
+    })
+}
+
+fn encode_button(button: mouse::Button) -> String {
+    match button {
+        mouse::Button::Left => String::from("left"),
+        mouse::Button::Right => String::from("right"),
+        mouse::Button::Middle => String::from("middle"),
+        mouse::Button::Other(other) => other.to_string(),
+    }
+}
+
+fn decode(line: &str, start: Instant) -> Result<(Duration, Record), String> {
+    let mut tokens = Tokens::new(line);
+
+    let at = Duration::from_secs_f64(tokens.parse("timestamp")?);
+
+    let record = match tokens.next("record kind")? {
+        "viewport" => Record::Viewport {
+            size: Size::new(tokens.parse("width")?, tokens.parse("height")?),
+            scale_factor: tokens.parse("scale factor")?,
+        },
+        "event" => Record::Event(decode_event(&mut tokens, start)?),
+        "update" => Record::Update {
+            cursor_position: Point::new(
+                tokens.parse("cursor x")?,
+                tokens.parse("cursor y")?,
+            ),
+        },
+        "message" => Record::Message(unescape(tokens.rest())),
+        kind => return Err(format!("unknown record kind `{}`", kind)),
+    };
+
+    Ok((at, record))
+}
+
+fn decode_event(
+    tokens: &mut Tokens<'_>,
+    start: Instant,
+) -> Result<Event, String> {
+    let source = tokens.next("event source")?;
+    let kind = tokens.next("event kind")?;
+
+    Ok(match (source, kind) {
+        ("keyboard", "pressed") => {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: decode_key_code(tokens.next("key code")?)?,
+                modifiers: decode_modifiers(tokens)?,
+            })
+        }
+        ("keyboard", "released") => {
+            Event::Keyboard(keyboard::Event::KeyReleased {
+                key_code: decode_key_code(tokens.next("key code")?)?,
+                modifiers: decode_modifiers(tokens)?,
+            })
+        }
+        ("keyboard", "character") => {
+            let code: u32 = tokens.parse("character")?;
+
+            Event::Keyboard(keyboard::Event::CharacterReceived(
+                char::from_u32(code)
+                    .ok_or_else(|| format!("invalid character {}", code))?,
+            ))
+        }
+        ("keyboard", "modifiers") => Event::Keyboard(
+            keyboard::Event::ModifiersChanged(decode_modifiers(tokens)?),
+        ),
+        ("mouse", "entered") => Event::Mouse(mouse::Event::CursorEntered),
+        ("mouse", "left") => Event::Mouse(mouse::Event::CursorLeft),
+        ("mouse", "moved") => Event::Mouse(mouse::Event::CursorMoved {
+            position: Point::new(tokens.parse("x")?, tokens.parse("y")?),
+        }),
+        ("mouse", "pressed") => Event::Mouse(mouse::Event::ButtonPressed(
+            decode_button(tokens.next("button")?)?,
+        )),
+        ("mouse", "released") => Event::Mouse(mouse::Event::ButtonReleased(
+            decode_button(tokens.next("button")?)?,
+        )),
+        ("mouse", "lines") => Event::Mouse(mouse::Event::WheelScrolled {
+            delta: mouse::ScrollDelta::Lines {
+                x: tokens.parse("x")?,
+                y: tokens.parse("y")?,
+            },
+        }),
+        ("mouse", "pixels") => Event::Mouse(mouse::Event::WheelScrolled {
+            delta: mouse::ScrollDelta::Pixels {
+                x: tokens.parse("x")?,
+                y: tokens.parse("y")?,
+            },
+        }),
+        ("window", id) => {
+            let id = window::Id(
+                id.parse()
+                    .map_err(|_| format!("invalid window id `{}`", id))?,
+            );
+
+            let event = match tokens.next("window event")? {
+                "moved" => window::Event::Moved {
+                    x: tokens.parse("x")?,
+                    y: tokens.parse("y")?,
+                },
+                "resized" => window::Event::Resized {
+                    width: tokens.parse("width")?,
+                    height: tokens.parse("height")?,
+                },
+                "redraw" => window::Event::RedrawRequested(
+                    start + Duration::from_secs_f64(tokens.parse("timestamp")?),
+                ),
+                "close" => window::Event::CloseRequested,
+                "focused" => window::Event::Focused,
+                "unfocused" => window::Event::Unfocused,
+                "file-hovered" => window::Event::FileHovered(PathBuf::from(
+                    unescape(tokens.rest()),
+                )),
+                "file-dropped" => window::Event::FileDropped(PathBuf::from(
+                    unescape(tokens.rest()),
+                )),
+                "files-left" => window::Event::FilesHoveredLeft,
+                event => {
+                    return Err(format!("unknown window event `{}`", event))
+                }
+            };
+
+            Event::Window(id, event)
+        }
+        ("touch", kind) => {
+            let id = touch::Finger(tokens.parse("finger")?);
+            let position = Point::new(tokens.parse("x")?, tokens.parse("y")?);
+
+            Event::Touch(match kind {
+                "pressed" => touch::Event::FingerPressed { id, position },
+                "moved" => touch::Event::FingerMoved { id, position },
+                "lifted" => touch::Event::FingerLifted { id, position },
+                "lost" => touch::Event::FingerLost { id, position },
+                kind => return Err(format!("unknown touch event `{}`", kind)),
+            })
+        }
+        ("macos", "url") => {
+            Event::PlatformSpecific(event::PlatformSpecific::MacOS(
+                event::MacOS::ReceivedUrl(unescape(tokens.rest())),
+            ))
+        }
+        (source, kind) => {
+            return Err(format!("unknown event `{} {}`", source, kind))
+        }
+    })
+}
+
+fn decode_key_code(name: &str) -> Result<KeyCode, String> {
+    KeyCode::ALL
+        .iter()
+        .copied()
+        .find(|key_code| format!("{:?}", key_code) == name)
+        .ok_or_else(|| format!("unknown key code `{}`", name))
+}
+
+fn decode_modifiers(tokens: &mut Tokens<'_>) -> Result<Modifiers, String> {
+    Ok(Modifiers::from_bits_truncate(tokens.parse("modifiers")?))
+}
+
+fn decode_button(button: &str) -> Result<mouse::Button, String> {
+    Ok(match button {
+        "left" => mouse::Button::Left,
+        "right" => mouse::Button::Right,
+        "middle" => mouse::Button::Middle,
+        other => mouse::Button::Other(
+            other
+                .parse()
+                .map_err(|_| format!("unknown mouse button `{}`", other))?,
+        ),
+    })
+}
+
+/// Escapes the line breaks of a string, so it can be written as the rest of
+/// a line.
+fn escape(string: &str) -> String {
+    string
+        .replace('\\', "\\\\")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+}
+
+fn unescape(string: &str) -> String {
+    let mut result = String::with_capacity(string.len());
+    let mut chars = string.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('n') => result.push('\n'),
+            Some('r') => result.push('\r'),
+            Some(other) => result.push(other),
+            None => result.push('\\'),
+        }
+    }
+
+    result
+}
+
+/// The whitespace-separated tokens of a line.
+struct Tokens<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Tokens<'a> {
+    fn new(line: &'a str) -> Self {
+        Self { rest: line }
+    }
+
+    fn next(&mut self, name: &str) -> Result<&'a str, String> {
+        let line = self.rest.trim_start();
+        let end = line.find(char::is_whitespace).unwrap_or(line.len());
+
+        if end == 0 {
+            return Err(format!("missing {}", name));
+        }
+
+        self.rest = &line[end..];
+
+        Ok(&line[..end])
+    }
+
+    fn parse<T: std::str::FromStr>(&mut self, name: &str) -> Result<T, String> {
+        let token = self.next(name)?;
+
+        token
+            .parse()
+            .map_err(|_| format!("invalid {} `{}`", name, token))
+    }
+
+    /// Returns the rest of the line after the next space, verbatim.
+    fn rest(&mut self) -> &'a str {
+        let rest = std::mem::take(&mut self.rest);
+
+        rest.strip_prefix(' ').unwrap_or(rest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(event: Event) {
+        let start = Instant::now();
+        let record = Record::Event(event);
+
+        let line = encode(&record, start).expect("Encode event");
+        let (_, decoded) = decode(&format!("0.5 {}", line), start)
+            .unwrap_or_else(|reason| panic!("Decode `{}`: {}", line, reason));
+
+        assert_eq!(decoded, record, "Round trip of `{}`", line);
+    }
+
+    #[test]
+    fn keyboard_events_round_trip() {
+        let modifiers = Modifiers::SHIFT | Modifiers::LOGO;
+
+        for key_code in KeyCode::ALL.iter().copied() {
+            round_trip(Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }));
+            round_trip(Event::Keyboard(keyboard::Event::KeyReleased {
+                key_code,
+                modifiers,
+            }));
+        }
+
+        for c in ['a', ' ', '\n', '\\', 'é', '🦀'] {
+            round_trip(Event::Keyboard(keyboard::Event::CharacterReceived(c)));
+        }
+
+        round_trip(Event::Keyboard(keyboard::Event::ModifiersChanged(
+            Modifiers::CTRL | Modifiers::ALT,
+        )));
+    }
+
+    #[test]
+    fn mouse_events_round_trip() {
+        round_trip(Event::Mouse(mouse::Event::CursorEntered));
+        round_trip(Event::Mouse(mouse::Event::CursorLeft));
+        round_trip(Event::Mouse(mouse::Event::CursorMoved {
+            position: Point::new(-1.5, 20.25),
+        }));
+
+        for button in [
+            mouse::Button::Left,
+            mouse::Button::Right,
+            mouse::Button::Middle,
+            mouse::Button::Other(8),
+        ] {
+            round_trip(Event::Mouse(mouse::Event::ButtonPressed(button)));
+            round_trip(Event::Mouse(mouse::Event::ButtonReleased(button)));
+        }
+
+        round_trip(Event::Mouse(mouse::Event::WheelScrolled {
+            delta: mouse::ScrollDelta::Lines { x: 0.0, y: -3.0 },
+        }));
+        round_trip(Event::Mouse(mouse::Event::WheelScrolled {
+            delta: mouse::ScrollDelta::Pixels { x: 1.25, y: 0.1 },
+        }));
+    }
+
+    #[test]
+    fn window_events_round_trip() {
+        let id = window::Id(3);
+        let start = Instant::now();
+
+        for event in [
+            window::Event::Moved { x: -10, y: 20 },
+            window::Event::Resized {
+                width: 800,
+                height: 600,
+            },
+            window::Event::CloseRequested,
+            window::Event::Focused,
+            window::Event::Unfocused,
+            window::Event::FileHovered(PathBuf::from("/tmp/a file.txt")),
+            window::Event::FileDropped(PathBuf::from(
+                "C:\\Users\\new\nline\\file.txt",
+            )),
+            window::Event::FilesHoveredLeft,
+        ] {
+            round_trip(Event::Window(id, event));
+        }
+
+        let redraw = Record::Event(Event::Window(
+            id,
+            window::Event::RedrawRequested(start + Duration::from_millis(1500)),
+        ));
+        let line = encode(&redraw, start).unwrap();
+
+        assert_eq!(decode(&format!("2 {}", line), start).unwrap().1, redraw);
+    }
+
+    #[test]
+    fn touch_events_round_trip() {
+        let id = touch::Finger(42);
+        let position = Point::new(3.5, -7.0);
+
+        round_trip(Event::Touch(touch::Event::FingerPressed { id, position }));
+        round_trip(Event::Touch(touch::Event::FingerMoved { id, position }));
+        round_trip(Event::Touch(touch::Event::FingerLifted { id, position }));
+        round_trip(Event::Touch(touch::Event::FingerLost { id, position }));
+    }
+
+    #[test]
+    fn platform_specific_events_round_trip() {
+        round_trip(Event::PlatformSpecific(event::PlatformSpecific::MacOS(
+            event::MacOS::ReceivedUrl(String::from("app://open?a=1\\n&b=\n")),
+        )));
+    }
+
+    #[test]
+    fn messages_with_escapes_round_trip() {
+        let start = Instant::now();
+
+        for message in [
+            "Increment",
+            "Edited(\"a\\nb\")",
+            "Pasted(\"line\nbreak\")",
+            "Path(\"C:\\\\dir\\\\\")",
+            "\r\n\\",
+            "",
+        ] {
+            let record = Record::Message(String::from(message));
+            let line = encode(&record, start).unwrap();
+
+            assert!(!line.contains('\n'));
+            assert_eq!(
+                decode(&format!("0 {}", line), start).unwrap().1,
+                record
+            );
+        }
+    }
+}
@@ -1,3 +1,6 @@
+use crate::dnd;
+use crate::time::Instant;
+
 /// A connection to the state of a shell.
 ///
 /// A [`Widget`] can leverage a [`Shell`] to trigger changes in an application,
@@ -7,8 +10,11 @@
 #[derive(Debug)]
 pub struct Shell<'a, Message> {
     messages: &'a mut Vec<Message>,
+    redraw_request: Option<Instant>,
     is_layout_invalid: bool,
+    is_overlay_invalid: bool,
     are_widgets_invalid: bool,
+    drag_request: Option<dnd::Payload>,
 }
 
 impl<'a, Message> Shell<'a, Message> {
@@ -16,8 +22,11 @@ impl<'a, Message> Shell<'a, Message> {
     pub fn new(messages: &'a mut Vec<Message>) -> Self {
         Self {
             messages,
+            redraw_request: None,
             is_layout_invalid: false,
+            is_overlay_invalid: false,
             are_widgets_invalid: false,
+            drag_request: None,
         }
     }
 
@@ -41,6 +50,34 @@ impl<'a, Message> Shell<'a, Message> {
         self.messages.push(message);
     }
 
+    /// Requests a new frame to be drawn at the given [`Instant`].
+    ///
+    /// When multiple redraws are requested, the earliest one is kept.
+    pub fn request_redraw(&mut self, at: Instant) {
+        match self.redraw_request {
+            Some(current) if current <= at => {}
+            _ => self.redraw_request = Some(at),
+        }
+    }
+
+    /// Returns the requested [`Instant`] of the next redraw, if any.
+    pub fn redraw_request(&self) -> Option<Instant> {
+        self.redraw_request
+    }
+
+    /// Starts dragging the given [`dnd::Payload`].
+    ///
+    /// Until it is dropped or cancelled, the widgets of the application will
+    /// receive drag and drop events carrying the [`dnd::Payload`].
+    pub fn start_drag(&mut self, payload: dnd::Payload) {
+        self.drag_request = Some(payload);
+    }
+
+    /// Returns the [`dnd::Payload`] requested to be dragged, if any.
+    pub fn drag_request(&self) -> Option<&dnd::Payload> {
+        self.drag_request.as_ref()
+    }
+
     /// Invalidates the current application layout.
     ///
     /// The shell will relayout the application widgets.
@@ -48,6 +85,19 @@ impl<'a, Message> Shell<'a, Message> {
         self.is_layout_invalid = true;
     }
 
+    /// Invalidates the layout of the current overlay.
+    ///
+    /// The shell will lay out the overlay again, without relayouting the
+    /// application widgets.
+    pub fn invalidate_overlay(&mut self) {
+        self.is_overlay_invalid = true;
+    }
+
+    /// Returns whether the layout of the current overlay is invalid or not.
+    pub fn is_overlay_invalid(&self) -> bool {
+        self.is_overlay_invalid
+    }
+
     /// Invalidates the current application widgets.
     ///
     /// The shell will rebuild and relayout the widget tree.
@@ -62,11 +112,22 @@ impl<'a, Message> Shell<'a, Message> {
     pub fn merge<B>(&mut self, other: Shell<'_, B>, f: impl Fn(B) -> Message) {
         self.messages.extend(other.messages.drain(..).map(f));
 
+        if let Some(at) = other.redraw_request {
+            self.request_redraw(at);
+        }
+
         self.is_layout_invalid =
             self.is_layout_invalid || other.is_layout_invalid;
 
+        self.is_overlay_invalid =
+            self.is_overlay_invalid || other.is_overlay_invalid;
+
         self.are_widgets_invalid =
             self.are_widgets_invalid || other.are_widgets_invalid;
+
+        if other.drag_request.is_some() {
+            self.drag_request = other.drag_request;
+        }
     }
 
     /// Returns whether the widgets of the current application have been
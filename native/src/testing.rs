@@ -0,0 +1,39 @@
+//! Test your user interfaces without a window or a GPU.
+//!
+//! A [`Simulator`] drives the [`UserInterface`] of an [`Element`] with
+//! simulated input, while a [`Harness`] also runs the [`Program`] producing
+//! it.
+//!
+//! [`UserInterface`]: crate::UserInterface
+//! [`Element`]: crate::Element
+//! [`Program`]: crate::Program
+mod clipboard;
+mod harness;
+mod selector;
+mod simulator;
+
+pub use clipboard::Clipboard;
+pub use harness::Harness;
+pub use selector::Selector;
+pub use simulator::Simulator;
+
+use std::fmt;
+
+/// An error produced while testing a user interface.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// No widget matches the [`Selector`].
+    NotFound(Selector),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotFound(selector) => {
+                write!(f, "No {} could be found", selector)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,59 @@
+use crate::clipboard::{self, Content, Format};
+
+/// A [`Clipboard`] that keeps its contents in memory.
+///
+/// [`Clipboard`]: crate::Clipboard
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Clipboard {
+    content: Option<Content>,
+    primary: Option<String>,
+}
+
+impl Clipboard {
+    /// Creates a new empty [`Clipboard`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the current [`Content`] of the [`Clipboard`], if any.
+    pub fn content(&self) -> Option<&Content> {
+        self.content.as_ref()
+    }
+
+    /// Returns the current contents of the primary selection, if any.
+    pub fn primary(&self) -> Option<&str> {
+        self.primary.as_deref()
+    }
+}
+
+impl clipboard::Clipboard for Clipboard {
+    fn read(&self) -> Option<String> {
+        match &self.content {
+            Some(Content::Text(contents)) => Some(contents.clone()),
+            _ => None,
+        }
+    }
+
+    fn write(&mut self, contents: String) {
+        self.content = Some(Content::Text(contents));
+    }
+
+    fn read_primary(&self) -> Option<String> {
+        self.primary.clone()
+    }
+
+    fn write_primary(&mut self, contents: String) {
+        self.primary = Some(contents);
+    }
+
+    fn read_content(&self, format: Format) -> Option<Content> {
+        self.content
+            .as_ref()
+            .filter(|content| content.format() == format)
+            .cloned()
+    }
+
+    fn write_content(&mut self, content: Content) {
+        self.content = Some(content);
+    }
+}
@@ -0,0 +1,197 @@
+use crate::application;
+use crate::clipboard::{self, Clipboard as _};
+use crate::command;
+use crate::testing::{Clipboard, Simulator};
+use crate::user_interface::{self, UserInterface};
+use crate::widget::operation;
+use crate::window;
+use crate::{Command, Program, Size};
+
+use iced_futures::futures::executor;
+
+use std::collections::VecDeque;
+
+/// A [`Program`] driven by simulated input.
+///
+/// A [`Harness`] feeds the messages produced by the interactions with its
+/// [`Simulator`] to [`Program::update`], and runs the returned [`Command`]s
+/// to completion. Futures are executed in the current thread, clipboard
+/// actions use an in-memory [`Clipboard`], and widget operations are applied
+/// to the current view of the [`Program`].
+///
+/// Window and system actions are ignored, except for the queries of the size,
+/// scale factor and focus of the window, which are answered with the size of
+/// the [`Harness`], `1.0` and `true`, respectively.
+#[allow(missing_debug_implementations)]
+pub struct Harness<P: Program> {
+    program: P,
+    size: Size,
+    cache: user_interface::Cache,
+    clipboard: Clipboard,
+}
+
+impl<P> Harness<P>
+where
+    P: Program,
+    P::Renderer: Default,
+    <P::Renderer as crate::Renderer>::Theme: application::StyleSheet,
+{
+    /// Creates a new [`Harness`] for the given [`Program`] with the
+    /// [default size](Simulator::DEFAULT_SIZE).
+    pub fn new(program: P) -> Self {
+        Self::with_size(
+            program,
+            Simulator::<P::Message, P::Renderer>::DEFAULT_SIZE,
+        )
+    }
+
+    /// Creates a new [`Harness`] for the given [`Program`] with the given
+    /// viewport size.
+    pub fn with_size(program: P, size: Size) -> Self {
+        Self {
+            program,
+            size,
+            cache: user_interface::Cache::new(),
+            clipboard: Clipboard::new(),
+        }
+    }
+
+    /// Returns a reference to the [`Program`] of the [`Harness`].
+    pub fn program(&self) -> &P {
+        &self.program
+    }
+
+    /// Returns the [`Clipboard`] of the [`Harness`].
+    pub fn clipboard(&mut self) -> &mut Clipboard {
+        &mut self.clipboard
+    }
+
+    /// Consumes the [`Harness`] and returns its [`Program`].
+    pub fn into_program(self) -> P {
+        self.program
+    }
+
+    /// Interacts with the current view of the [`Program`] using a
+    /// [`Simulator`].
+    ///
+    /// Once the closure returns, the produced messages are processed until
+    /// no more messages are left.
+    pub fn interact<T>(
+        &mut self,
+        f: impl FnOnce(&mut Simulator<'_, P::Message, P::Renderer>) -> T,
+    ) -> T {
+        let cache = std::mem::take(&mut self.cache);
+
+        let mut simulator =
+            Simulator::with_cache(self.program.view(), self.size, cache);
+
+        simulator.clipboard = std::mem::take(&mut self.clipboard);
+
+        let output = f(&mut simulator);
+
+        self.clipboard = std::mem::take(&mut simulator.clipboard);
+
+        let (messages, cache) = simulator.into_messages_and_cache();
+        self.cache = cache;
+
+        for message in messages {
+            self.update(message);
+        }
+
+        output
+    }
+
+    /// Updates the [`Program`] with the given message and runs the returned
+    /// [`Command`] to completion.
+    pub fn update(&mut self, message: P::Message) {
+        let mut messages = VecDeque::from([message]);
+
+        while let Some(message) = messages.pop_front() {
+            let command = self.program.update(message);
+
+            messages.extend(self.run(command));
+        }
+    }
+
+    fn run(&mut self, command: Command<P::Message>) -> Vec<P::Message> {
+        let mut messages = Vec::new();
+
+        for action in command.actions() {
+            match action {
+                command::Action::Future(future) => {
+                    messages.push(executor::block_on(future));
+                }
+                command::Action::Clipboard(action) => match action {
+                    clipboard::Action::Read(tag) => {
+                        messages.push(tag(self.clipboard.read()));
+                    }
+                    clipboard::Action::Write(contents) => {
+                        self.clipboard.write(contents);
+                    }
+                    clipboard::Action::ReadPrimary(tag) => {
+                        messages.push(tag(self.clipboard.read_primary()));
+                    }
+                    clipboard::Action::WritePrimary(contents) => {
+                        self.clipboard.write_primary(contents);
+                    }
+                    clipboard::Action::ReadContent(format, tag) => {
+                        messages.push(tag(self.clipboard.read_content(format)));
+                    }
+                    clipboard::Action::WriteContent(content) => {
+                        self.clipboard.write_content(content);
+                    }
+                },
+                command::Action::Window(_id, action) => {
+                    let size = Size::new(
+                        self.size.width as u32,
+                        self.size.height as u32,
+                    );
+
+                    match action {
+                        window::Action::FetchInnerSize(tag)
+                        | window::Action::FetchOuterSize(tag) => {
+                            messages.push(tag(size));
+                        }
+                        window::Action::FetchScaleFactor(tag) => {
+                            messages.push(tag(1.0));
+                        }
+                        window::Action::FetchFocused(tag) => {
+                            messages.push(tag(true));
+                        }
+                        _ => {}
+                    }
+                }
+                command::Action::System(_) => {}
+                command::Action::Widget(action) => {
+                    let mut renderer = P::Renderer::default();
+                    let mut current_operation = Some(action.into_operation());
+
+                    let mut user_interface = UserInterface::build(
+                        self.program.view(),
+                        self.size,
+                        std::mem::take(&mut self.cache),
+                        &mut renderer,
+                    );
+
+                    while let Some(mut operation) = current_operation.take() {
+                        user_interface.operate(&renderer, operation.as_mut());
+
+                        match operation.finish() {
+                            operation::Outcome::None => {}
+                            operation::Outcome::Some(message) => {
+                                messages.push(message);
+                            }
+                            operation::Outcome::Chain(next) => {
+                                current_operation = Some(next);
+                            }
+                        }
+                    }
+
+                    self.cache = user_interface.into_cache();
+                }
+            }
+        }
+
+        messages
+    }
+}
@@ -0,0 +1,123 @@
+use crate::widget::operation::Scrollable;
+use crate::widget::{self, Operation};
+use crate::{Rectangle, Vector};
+
+use std::fmt;
+
+/// A query used to find a widget in a user interface.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Selector {
+    /// Finds the widget with the given [`widget::Id`].
+    Id(widget::Id),
+
+    /// Finds the first widget displaying exactly the given text.
+    Text(String),
+}
+
+impl Selector {
+    /// Returns an [`Operation`] that finds the bounds of the first widget
+    /// matching the [`Selector`].
+    pub(crate) fn find(&self) -> Find<'_> {
+        Find {
+            selector: self,
+            translation: Vector::new(0.0, 0.0),
+            pending: None,
+            bounds: None,
+        }
+    }
+}
+
+impl From<widget::Id> for Selector {
+    fn from(id: widget::Id) -> Self {
+        Self::Id(id)
+    }
+}
+
+impl From<&str> for Selector {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_owned())
+    }
+}
+
+impl From<String> for Selector {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl fmt::Display for Selector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Id(id) => write!(f, "widget with id {:?}", id),
+            Self::Text(text) => write!(f, "widget with text {:?}", text),
+        }
+    }
+}
+
+pub(crate) struct Find<'a> {
+    selector: &'a Selector,
+    translation: Vector,
+    pending: Option<Vector>,
+    pub bounds: Option<Rectangle>,
+}
+
+impl<'a, T> Operation<T> for Find<'a> {
+    fn scrollable(
+        &mut self,
+        state: &mut dyn Scrollable,
+        _id: Option<&widget::Id>,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        let offset = state.absolute_offset(bounds, content_bounds);
+
+        self.pending = Some(Vector::new(0.0, -offset));
+    }
+
+    fn container(
+        &mut self,
+        id: Option<&widget::Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        // A scrollable operates on its contents right after reporting
+        // itself, so its offset applies to the children visited here.
+        let offset = self.pending.take();
+
+        if self.bounds.is_some() {
+            return;
+        }
+
+        match self.selector {
+            Selector::Id(target) if Some(target) == id => {
+                self.bounds = Some(bounds + self.translation);
+            }
+            _ => {
+                let translation = self.translation;
+
+                if let Some(offset) = offset {
+                    self.translation = translation + offset;
+                }
+
+                operate_on_children(self);
+
+                self.translation = translation;
+            }
+        }
+    }
+
+    fn text(&mut self, id: Option<&widget::Id>, bounds: Rectangle, text: &str) {
+        if self.bounds.is_some() {
+            return;
+        }
+
+        let is_match = match self.selector {
+            Selector::Id(target) => Some(target) == id,
+            Selector::Text(target) => target == text,
+        };
+
+        if is_match {
+            self.bounds = Some(bounds + self.translation);
+        }
+    }
+}
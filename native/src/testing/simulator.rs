@@ -0,0 +1,294 @@
+use crate::application;
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::mouse;
+use crate::renderer;
+use crate::testing::{Clipboard, Error, Selector};
+use crate::user_interface::{self, UserInterface};
+use crate::widget::{self, operation};
+use crate::{Element, Point, Rectangle, Size};
+
+/// A user interface driven by simulated input.
+///
+/// A [`Simulator`] builds an [`Element`] without a window, lets you find its
+/// widgets by [`Selector`], and collects the messages produced by the
+/// simulated interactions.
+///
+/// ```
+/// use iced_native::renderer;
+/// use iced_native::testing::Simulator;
+/// use iced_native::widget::{button, text, Column};
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// enum Message {
+///     Increment,
+/// }
+///
+/// let view = Column::<_, renderer::Null>::new()
+///     .push(text("Count: 0"))
+///     .push(button(text("Increment")).on_press(Message::Increment));
+///
+/// let mut simulator = Simulator::new(view);
+///
+/// simulator.click("Increment").expect("Find the button");
+///
+/// assert_eq!(
+///     simulator.messages().collect::<Vec<_>>(),
+///     vec![Message::Increment]
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Simulator<'a, Message, Renderer> {
+    raw: UserInterface<'a, Message, Renderer>,
+    renderer: Renderer,
+    pub(super) clipboard: Clipboard,
+    cursor_position: Point,
+    modifiers: keyboard::Modifiers,
+    messages: Vec<Message>,
+}
+
+impl<'a, Message, Renderer> Simulator<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer + Default,
+    Renderer::Theme: application::StyleSheet,
+{
+    /// The default size of the viewport of a [`Simulator`].
+    pub const DEFAULT_SIZE: Size = Size::new(1024.0, 768.0);
+
+    /// Creates a new [`Simulator`] for the given [`Element`] with the
+    /// [default size](Self::DEFAULT_SIZE).
+    pub fn new(element: impl Into<Element<'a, Message, Renderer>>) -> Self {
+        Self::with_size(element, Self::DEFAULT_SIZE)
+    }
+
+    /// Creates a new [`Simulator`] for the given [`Element`] with the given
+    /// viewport size.
+    pub fn with_size(
+        element: impl Into<Element<'a, Message, Renderer>>,
+        size: Size,
+    ) -> Self {
+        Self::with_cache(element, size, user_interface::Cache::new())
+    }
+
+    /// Creates a new [`Simulator`] for the given [`Element`] reusing the
+    /// widget state of a previous [`Simulator`].
+    pub fn with_cache(
+        element: impl Into<Element<'a, Message, Renderer>>,
+        size: Size,
+        cache: user_interface::Cache,
+    ) -> Self {
+        let mut renderer = Renderer::default();
+        let raw = UserInterface::build(element, size, cache, &mut renderer);
+
+        Self {
+            raw,
+            renderer,
+            clipboard: Clipboard::new(),
+            cursor_position: Point::new(-1.0, -1.0),
+            modifiers: keyboard::Modifiers::default(),
+            messages: Vec::new(),
+        }
+    }
+
+    /// Finds the bounds of the first widget matching the given [`Selector`].
+    ///
+    /// The bounds are translated by the offsets of the scrollables containing
+    /// the widget, so they match where it is drawn.
+    pub fn find(
+        &mut self,
+        selector: impl Into<Selector>,
+    ) -> Result<Rectangle, Error> {
+        let selector = selector.into();
+        let mut find = selector.find();
+
+        self.raw.operate(&self.renderer, &mut find);
+
+        find.bounds.ok_or(Error::NotFound(selector))
+    }
+
+    /// Moves the cursor to the given position.
+    pub fn point_at(&mut self, position: Point) -> event::Status {
+        self.cursor_position = position;
+
+        self.simulate_one(Event::Mouse(mouse::Event::CursorMoved { position }))
+    }
+
+    /// Moves the cursor to the center of the first widget matching the given
+    /// [`Selector`] and clicks it with the left mouse button.
+    pub fn click(
+        &mut self,
+        selector: impl Into<Selector>,
+    ) -> Result<event::Status, Error> {
+        let bounds = self.find(selector)?;
+
+        let moved = self.point_at(bounds.center());
+        let statuses = self.simulate([
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+        ]);
+
+        Ok(statuses.into_iter().fold(moved, event::Status::merge))
+    }
+
+    /// Scrolls the mouse wheel at the current cursor position.
+    pub fn scroll(&mut self, delta: mouse::ScrollDelta) -> event::Status {
+        self.simulate_one(Event::Mouse(mouse::Event::WheelScrolled { delta }))
+    }
+
+    /// Changes the keyboard modifiers currently pressed.
+    pub fn set_modifiers(
+        &mut self,
+        modifiers: keyboard::Modifiers,
+    ) -> event::Status {
+        self.modifiers = modifiers;
+
+        self.simulate_one(Event::Keyboard(keyboard::Event::ModifiersChanged(
+            modifiers,
+        )))
+    }
+
+    /// Presses and releases the key with the given [`keyboard::KeyCode`].
+    pub fn tap_key(&mut self, key_code: keyboard::KeyCode) -> event::Status {
+        let modifiers = self.modifiers;
+
+        self.simulate([
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }),
+            Event::Keyboard(keyboard::Event::KeyReleased {
+                key_code,
+                modifiers,
+            }),
+        ])
+        .into_iter()
+        .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    /// Types the given text, character by character.
+    pub fn typewrite(&mut self, text: &str) -> event::Status {
+        self.simulate(
+            text.chars().map(|c| {
+                Event::Keyboard(keyboard::Event::CharacterReceived(c))
+            }),
+        )
+        .into_iter()
+        .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    /// Focuses the widget with the given [`widget::Id`].
+    pub fn focus(&mut self, id: widget::Id) {
+        let mut focus = operation::focusable::focus(id);
+
+        self.raw.operate(&self.renderer, &mut focus);
+    }
+
+    /// Applies the given [`widget::Operation`] to the user interface,
+    /// running any chained operations to completion.
+    ///
+    /// The output of the operations, if any, is collected with the rest of
+    /// the messages.
+    pub fn operate(&mut self, operation: &mut dyn widget::Operation<Message>) {
+        self.raw.operate(&self.renderer, operation);
+
+        let mut outcome = operation.finish();
+
+        while let operation::Outcome::Chain(mut next) = outcome {
+            self.raw.operate(&self.renderer, next.as_mut());
+
+            outcome = next.finish();
+        }
+
+        if let operation::Outcome::Some(message) = outcome {
+            self.messages.push(message);
+        }
+    }
+
+    /// Processes the given events and returns their [`event::Status`].
+    pub fn simulate(
+        &mut self,
+        events: impl IntoIterator<Item = Event>,
+    ) -> Vec<event::Status> {
+        let events: Vec<Event> = events.into_iter().collect();
+
+        let (_state, statuses) = self.raw.update(
+            &events,
+            self.cursor_position,
+            &mut self.renderer,
+            &mut self.clipboard,
+            &mut self.messages,
+        );
+
+        statuses
+    }
+
+    /// Draws the user interface with the given theme and returns the
+    /// resulting [`mouse::Interaction`].
+    pub fn draw(&mut self, theme: &Renderer::Theme) -> mouse::Interaction {
+        self.raw.draw(
+            &mut self.renderer,
+            theme,
+            &renderer::Style::default(),
+            self.cursor_position,
+        )
+    }
+
+    /// Returns the [`Renderer`] of the [`Simulator`].
+    ///
+    /// [`Renderer`]: crate::Renderer
+    pub fn renderer(&self) -> &Renderer {
+        &self.renderer
+    }
+
+    /// Returns the [`Clipboard`] of the [`Simulator`].
+    pub fn clipboard(&mut self) -> &mut Clipboard {
+        &mut self.clipboard
+    }
+
+    /// Drains the messages produced so far.
+    pub fn messages(&mut self) -> impl Iterator<Item = Message> + '_ {
+        self.messages.drain(..)
+    }
+
+    /// Consumes the [`Simulator`] and returns the produced messages and the
+    /// [`user_interface::Cache`] of its widgets.
+    pub fn into_messages_and_cache(
+        self,
+    ) -> (Vec<Message>, user_interface::Cache) {
+        (self.messages, self.raw.into_cache())
+    }
+
+    fn simulate_one(&mut self, event: Event) -> event::Status {
+        self.simulate([event])
+            .pop()
+            .unwrap_or(event::Status::Ignored)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widget::operation::scrollable::scroll_to;
+    use crate::widget::{button, scrollable, text, Column, Scrollable, Space};
+    use crate::Length;
+
+    #[test]
+    fn clicks_reach_widgets_inside_a_scrolled_scrollable() {
+        let content = Column::new()
+            .width(Length::Fill)
+            .push(Space::with_height(Length::Units(250)))
+            .push(button(text("A")).on_press("A"))
+            .push(Space::with_height(Length::Units(250)));
+
+        let mut simulator = Simulator::with_size(
+            Scrollable::<_, renderer::Null>::new(content)
+                .id(scrollable::Id::new("scrollable")),
+            Size::new(100.0, 100.0),
+        );
+
+        simulator.operate(&mut scroll_to(widget::Id::new("scrollable"), 200.0));
+
+        assert_eq!(simulator.click("A"), Ok(event::Status::Captured));
+        assert_eq!(simulator.messages().collect::<Vec<_>>(), vec!["A"]);
+    }
+}
@@ -1,9 +1,12 @@
 //! Implement your own event loop to drive a user interface.
 use crate::application;
+use crate::dnd;
 use crate::event::{self, Event};
+use crate::keyboard;
 use crate::layout;
 use crate::mouse;
 use crate::renderer;
+use crate::time::Instant;
 use crate::widget;
 use crate::{Clipboard, Element, Layout, Point, Rectangle, Shell, Size};
 
@@ -27,6 +30,7 @@ pub struct UserInterface<'a, Message, Renderer> {
     state: widget::Tree,
     overlay: Option<layout::Node>,
     bounds: Size,
+    drag: dnd::Tracker,
 }
 
 impl<'a, Message, Renderer> UserInterface<'a, Message, Renderer>
@@ -96,7 +100,7 @@ where
     ) -> Self {
         let root = root.into();
 
-        let Cache { mut state } = cache;
+        let Cache { mut state, drag } = cache;
         state.diff(root.as_widget());
 
         let base =
@@ -108,6 +112,7 @@ where
             state,
             overlay: None,
             bounds,
+            drag,
         }
     }
 
@@ -116,6 +121,10 @@ where
     /// It returns __messages__ that may have been produced as a result of user
     /// interactions. You should feed these to your __update logic__.
     ///
+    /// Mouse, touch and file events are also turned into drag and drop events
+    /// for the widgets. If a drag and drop event is captured, the events that
+    /// caused it are captured too.
+    ///
     /// # Example
     /// Let's allow our [counter](index.html#usage) to change state by
     /// completing [the previous example](#example):
@@ -188,7 +197,22 @@ where
     ) -> (State, Vec<event::Status>) {
         use std::mem::ManuallyDrop;
 
-        let mut state = State::Updated;
+        let was_dragging = self.drag.is_active();
+        let event_count = events.len();
+        let dnd_events = self.drag.translate(events, cursor_position);
+        let events: Vec<Event> = events
+            .iter()
+            .cloned()
+            .chain(
+                dnd_events
+                    .iter()
+                    .map(|(_, event)| Event::DragAndDrop(event.clone())),
+            )
+            .collect();
+
+        let mut outdated = false;
+        let mut is_overlay_invalid = false;
+        let mut redraw_request = None;
         let mut manual_overlay =
             ManuallyDrop::new(self.root.as_widget_mut().overlay(
                 &mut self.state,
@@ -217,6 +241,17 @@ where
 
                 event_statuses.push(event_status);
 
+                if let Some(at) = shell.redraw_request() {
+                    redraw_request = min_instant(redraw_request, at);
+                }
+
+                if let Some(payload) = shell.drag_request() {
+                    self.drag.start(payload.clone());
+                }
+
+                is_overlay_invalid =
+                    is_overlay_invalid || shell.is_overlay_invalid();
+
                 if shell.is_layout_invalid() {
                     let _ = ManuallyDrop::into_inner(manual_overlay);
 
@@ -244,7 +279,7 @@ where
                 }
 
                 if shell.are_widgets_invalid() {
-                    state = State::Outdated;
+                    outdated = true;
                 }
             }
 
@@ -264,7 +299,7 @@ where
 
         let _ = ManuallyDrop::into_inner(manual_overlay);
 
-        let event_statuses = events
+        let mut event_statuses: Vec<event::Status> = events
             .iter()
             .cloned()
             .zip(overlay_statuses.into_iter())
@@ -289,6 +324,17 @@ where
                     self.overlay = None;
                 }
 
+                if let Some(at) = shell.redraw_request() {
+                    redraw_request = min_instant(redraw_request, at);
+                }
+
+                if let Some(payload) = shell.drag_request() {
+                    self.drag.start(payload.clone());
+                }
+
+                is_overlay_invalid =
+                    is_overlay_invalid || shell.is_overlay_invalid();
+
                 shell.revalidate_layout(|| {
                     self.base = renderer.layout(
                         &self.root,
@@ -299,13 +345,35 @@ where
                 });
 
                 if shell.are_widgets_invalid() {
-                    state = State::Outdated;
+                    outdated = true;
                 }
 
                 event_status.merge(overlay_status)
             })
             .collect();
 
+        let dnd_statuses =
+            event_statuses.split_off(event_count.min(event_statuses.len()));
+
+        for ((causes, _), dnd_status) in dnd_events.iter().zip(dnd_statuses) {
+            for status in &mut event_statuses[causes.clone()] {
+                *status = status.merge(dnd_status);
+            }
+        }
+
+        // Widgets may have moved their overlay while processing the events
+        // (e.g. a drag preview following the cursor), so its layout is
+        // computed again when needed instead of relayouting the base.
+        if is_overlay_invalid || was_dragging || self.drag.is_active() {
+            self.overlay = None;
+        }
+
+        let state = if outdated {
+            State::Outdated { redraw_request }
+        } else {
+            State::Updated { redraw_request }
+        };
+
         (state, event_statuses)
     }
 
@@ -484,6 +552,16 @@ where
             .unwrap_or(base_interaction)
     }
 
+    /// Inspects the widget tree of the [`UserInterface`].
+    ///
+    /// This is meant to be called after [`UserInterface::draw`], so that
+    /// widgets resolved lazily while drawing are inspected too.
+    pub fn inspect(&self) -> widget::inspect::Node {
+        self.root
+            .as_widget()
+            .inspect(&self.state, Layout::new(&self.base))
+    }
+
     /// Applies a [`widget::Operation`] to the [`UserInterface`].
     pub fn operate(
         &mut self,
@@ -512,16 +590,72 @@ where
         }
     }
 
+    /// Moves the focus to the next focusable widget for every Tab press in
+    /// the given [`Event`]s that was ignored by the widgets, or to the
+    /// previous one for Shift+Tab, marking the press as captured.
+    ///
+    /// This is meant to be called after [`UserInterface::update`] with the
+    /// statuses it returned, by shells that enable focus traversal.
+    pub fn traverse_focus(
+        &mut self,
+        events: &[Event],
+        statuses: &mut [event::Status],
+        renderer: &Renderer,
+    ) {
+        use widget::operation::{self, focusable};
+
+        for (event, status) in events.iter().zip(statuses) {
+            let backwards = match (event, *status) {
+                (
+                    Event::Keyboard(keyboard::Event::KeyPressed {
+                        key_code: keyboard::KeyCode::Tab,
+                        modifiers,
+                    }),
+                    event::Status::Ignored,
+                ) => modifiers.shift(),
+                _ => continue,
+            };
+
+            let mut current: Option<Box<dyn widget::Operation<Message>>> =
+                Some(if backwards {
+                    Box::new(focusable::focus_previous())
+                } else {
+                    Box::new(focusable::focus_next())
+                });
+
+            while let Some(mut operation) = current.take() {
+                self.operate(renderer, operation.as_mut());
+
+                if let operation::Outcome::Chain(next) = operation.finish() {
+                    current = Some(next);
+                }
+            }
+
+            *status = event::Status::Captured;
+        }
+    }
+
     /// Relayouts and returns a new  [`UserInterface`] using the provided
     /// bounds.
     pub fn relayout(self, bounds: Size, renderer: &mut Renderer) -> Self {
-        Self::build(self.root, bounds, Cache { state: self.state }, renderer)
+        Self::build(
+            self.root,
+            bounds,
+            Cache {
+                state: self.state,
+                drag: self.drag,
+            },
+            renderer,
+        )
     }
 
     /// Extract the [`Cache`] of the [`UserInterface`], consuming it in the
     /// process.
     pub fn into_cache(self) -> Cache {
-        Cache { state: self.state }
+        Cache {
+            state: self.state,
+            drag: self.drag,
+        }
     }
 }
 
@@ -529,6 +663,7 @@ where
 #[derive(Debug)]
 pub struct Cache {
     state: widget::Tree,
+    drag: dnd::Tracker,
 }
 
 impl Cache {
@@ -539,6 +674,7 @@ impl Cache {
     pub fn new() -> Cache {
         Cache {
             state: widget::Tree::empty(),
+            drag: dnd::Tracker::default(),
         }
     }
 }
@@ -553,9 +689,110 @@ impl Default for Cache {
 #[derive(Debug, Clone, Copy)]
 pub enum State {
     /// The [`UserInterface`] is outdated and needs to be rebuilt.
-    Outdated,
+    Outdated {
+        /// The [`Instant`] when a redraw has been requested, if any.
+        redraw_request: Option<Instant>,
+    },
 
     /// The [`UserInterface`] is up-to-date and can be reused without
     /// rebuilding.
-    Updated,
+    Updated {
+        /// The [`Instant`] when a redraw has been requested, if any.
+        redraw_request: Option<Instant>,
+    },
+}
+
+impl State {
+    /// Returns the [`Instant`] when a redraw has been requested, if any.
+    pub fn redraw_request(&self) -> Option<Instant> {
+        match self {
+            State::Outdated { redraw_request }
+            | State::Updated { redraw_request } => *redraw_request,
+        }
+    }
+}
+
+fn min_instant(current: Option<Instant>, at: Instant) -> Option<Instant> {
+    Some(current.map_or(at, |current| current.min(at)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::Duration;
+    use crate::{Length, Widget};
+
+    /// A widget that rebuilds the interface and schedules a redraw on every
+    /// event, like a `Component` showing a delayed tooltip.
+    struct Delayed(Instant);
+
+    impl Widget<(), renderer::Null> for Delayed {
+        fn width(&self) -> Length {
+            Length::Fill
+        }
+
+        fn height(&self) -> Length {
+            Length::Fill
+        }
+
+        fn layout(
+            &self,
+            _renderer: &renderer::Null,
+            limits: &layout::Limits,
+        ) -> layout::Node {
+            layout::Node::new(limits.max())
+        }
+
+        fn on_event(
+            &mut self,
+            _tree: &mut widget::Tree,
+            _event: Event,
+            _layout: Layout<'_>,
+            _cursor_position: Point,
+            _renderer: &renderer::Null,
+            _clipboard: &mut dyn Clipboard,
+            shell: &mut Shell<'_, ()>,
+        ) -> event::Status {
+            shell.invalidate_widgets();
+            shell.request_redraw(self.0);
+
+            event::Status::Ignored
+        }
+
+        fn draw(
+            &self,
+            _tree: &widget::Tree,
+            _renderer: &mut renderer::Null,
+            _theme: &crate::Theme,
+            _style: &renderer::Style,
+            _layout: Layout<'_>,
+            _cursor_position: Point,
+            _viewport: &Rectangle,
+        ) {
+        }
+    }
+
+    #[test]
+    fn outdated_interface_keeps_the_redraw_request() {
+        let at = Instant::now() + Duration::from_millis(500);
+        let mut renderer = renderer::Null::new();
+
+        let mut user_interface = UserInterface::build(
+            Element::new(Delayed(at)),
+            Size::new(100.0, 100.0),
+            Cache::default(),
+            &mut renderer,
+        );
+
+        let (state, _) = user_interface.update(
+            &[Event::Mouse(mouse::Event::CursorEntered)],
+            Point::ORIGIN,
+            &mut renderer,
+            &mut crate::clipboard::Null,
+            &mut Vec::new(),
+        );
+
+        assert!(matches!(state, State::Outdated { .. }));
+        assert_eq!(state.redraw_request(), Some(at));
+    }
 }
@@ -14,9 +14,16 @@
 pub mod button;
 pub mod checkbox;
 pub mod column;
+pub mod combo_box;
 pub mod container;
+pub mod context_menu;
+pub mod draggable;
+pub mod drop_zone;
 pub mod helpers;
 pub mod image;
+pub mod inspect;
+pub mod menu_bar;
+pub mod modal;
 pub mod operation;
 pub mod pane_grid;
 pub mod pick_list;
@@ -28,11 +35,15 @@ pub mod scrollable;
 pub mod slider;
 pub mod space;
 pub mod svg;
+pub mod table;
+pub mod tabs;
 pub mod text;
 pub mod text_input;
 pub mod toggler;
 pub mod tooltip;
 pub mod tree;
+pub mod tree_view;
+pub mod virtual_list;
 pub mod wgsl_shader_quad;
 
 mod action;
@@ -45,12 +56,24 @@ pub use checkbox::Checkbox;
 #[doc(no_inline)]
 pub use column::Column;
 #[doc(no_inline)]
+pub use combo_box::ComboBox;
+#[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use context_menu::ContextMenu;
+#[doc(no_inline)]
+pub use draggable::Draggable;
+#[doc(no_inline)]
+pub use drop_zone::DropZone;
+#[doc(no_inline)]
 pub use helpers::*;
 #[doc(no_inline)]
 pub use image::Image;
 #[doc(no_inline)]
+pub use menu_bar::MenuBar;
+#[doc(no_inline)]
+pub use modal::Modal;
+#[doc(no_inline)]
 pub use pane_grid::PaneGrid;
 #[doc(no_inline)]
 pub use pick_list::PickList;
@@ -71,6 +94,10 @@ pub use space::Space;
 #[doc(no_inline)]
 pub use svg::Svg;
 #[doc(no_inline)]
+pub use table::Table;
+#[doc(no_inline)]
+pub use tabs::Tabs;
+#[doc(no_inline)]
 pub use text::Text;
 #[doc(no_inline)]
 pub use text_input::TextInput;
@@ -81,6 +108,10 @@ pub use tooltip::Tooltip;
 #[doc(no_inline)]
 pub use tree::Tree;
 #[doc(no_inline)]
+pub use tree_view::TreeView;
+#[doc(no_inline)]
+pub use virtual_list::VirtualList;
+#[doc(no_inline)]
 pub use wgsl_shader_quad::WgslShaderQuad;
 
 pub use action::Action;
@@ -179,6 +210,14 @@ where
     ) {
     }
 
+    /// Describes the [`Widget`] and its children as an [`inspect::Node`], for
+    /// debugging purposes.
+    ///
+    /// By default, the [`Widget`] is described without any children.
+    fn inspect(&self, _state: &Tree, layout: Layout<'_>) -> inspect::Node {
+        inspect::Node::new::<Self>(layout.bounds(), self.width(), self.height())
+    }
+
     /// Processes a runtime [`Event`].
     ///
     /// By default, it does nothing.
@@ -1,5 +1,8 @@
-use crate::widget::operation::{self, Focusable, Operation, Scrollable};
+use crate::widget::operation::{
+    self, Focusable, Operation, Scrollable, TextInput,
+};
 use crate::widget::Id;
+use crate::Rectangle;
 
 use iced_futures::MaybeSend;
 
@@ -50,48 +53,39 @@ where
     fn container(
         &mut self,
         id: Option<&Id>,
+        bounds: Rectangle,
         operate_on_children: &mut dyn FnMut(&mut dyn Operation<B>),
     ) {
-        struct MapRef<'a, A> {
-            operation: &'a mut dyn Operation<A>,
-        }
-
-        impl<'a, A, B> Operation<B> for MapRef<'a, A> {
-            fn container(
-                &mut self,
-                id: Option<&Id>,
-                operate_on_children: &mut dyn FnMut(&mut dyn Operation<B>),
-            ) {
-                let Self { operation, .. } = self;
-
-                operation.container(id, &mut |operation| {
-                    operate_on_children(&mut MapRef { operation });
-                });
-            }
-
-            fn scrollable(
-                &mut self,
-                state: &mut dyn Scrollable,
-                id: Option<&Id>,
-            ) {
-                self.operation.scrollable(state, id);
-            }
+        let Self { operation, .. } = self;
 
-            fn focusable(
-                &mut self,
-                state: &mut dyn Focusable,
-                id: Option<&Id>,
-            ) {
-                self.operation.focusable(state, id);
-            }
+        MapRef {
+            operation: operation.as_mut(),
         }
+        .container(id, bounds, operate_on_children);
+    }
 
-        let Self { operation, .. } = self;
+    fn focus_scope(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<B>),
+    ) {
+        MapRef {
+            operation: self.operation.as_mut(),
+        }
+        .focus_scope(id, bounds, operate_on_children);
+    }
 
+    fn focus_trap(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<B>),
+    ) {
         MapRef {
-            operation: operation.as_mut(),
+            operation: self.operation.as_mut(),
         }
-        .container(id, operate_on_children);
+        .focus_trap(id, bounds, operate_on_children);
     }
 
     fn focusable(
@@ -106,8 +100,10 @@ where
         &mut self,
         state: &mut dyn operation::Scrollable,
         id: Option<&Id>,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
     ) {
-        self.operation.scrollable(state, id);
+        self.operation.scrollable(state, id, bounds, content_bounds);
     }
 
     fn text_input(
@@ -118,6 +114,10 @@ where
         self.operation.text_input(state, id);
     }
 
+    fn text(&mut self, id: Option<&Id>, bounds: Rectangle, text: &str) {
+        self.operation.text(id, bounds, text);
+    }
+
     fn finish(&self) -> operation::Outcome<B> {
         match self.operation.finish() {
             operation::Outcome::None => operation::Outcome::None,
@@ -133,3 +133,71 @@ where
         }
     }
 }
+
+#[allow(missing_debug_implementations)]
+struct MapRef<'a, A> {
+    operation: &'a mut dyn Operation<A>,
+}
+
+impl<'a, A, B> Operation<B> for MapRef<'a, A> {
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<B>),
+    ) {
+        let Self { operation, .. } = self;
+
+        operation.container(id, bounds, &mut |operation| {
+            operate_on_children(&mut MapRef { operation });
+        });
+    }
+
+    fn focus_scope(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<B>),
+    ) {
+        let Self { operation, .. } = self;
+
+        operation.focus_scope(id, bounds, &mut |operation| {
+            operate_on_children(&mut MapRef { operation });
+        });
+    }
+
+    fn focus_trap(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<B>),
+    ) {
+        let Self { operation, .. } = self;
+
+        operation.focus_trap(id, bounds, &mut |operation| {
+            operate_on_children(&mut MapRef { operation });
+        });
+    }
+
+    fn scrollable(
+        &mut self,
+        state: &mut dyn Scrollable,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        self.operation.scrollable(state, id, bounds, content_bounds);
+    }
+
+    fn focusable(&mut self, state: &mut dyn Focusable, id: Option<&Id>) {
+        self.operation.focusable(state, id);
+    }
+
+    fn text_input(&mut self, state: &mut dyn TextInput, id: Option<&Id>) {
+        self.operation.text_input(state, id);
+    }
+
+    fn text(&mut self, id: Option<&Id>, bounds: Rectangle, text: &str) {
+        self.operation.text(id, bounds, text);
+    }
+}
@@ -1,17 +1,21 @@
 //! Allow your users to perform actions by pressing a button.
 //!
 //! A [`Button`] has some local [`State`].
+use crate::animation;
 use crate::event::{self, Event};
+use crate::keyboard;
 use crate::layout;
 use crate::mouse;
 use crate::overlay;
 use crate::renderer;
 use crate::touch;
+use crate::widget;
+use crate::widget::inspect;
+use crate::widget::operation::{self, Operation};
 use crate::widget::tree::{self, Tree};
-use crate::widget::Operation;
 use crate::{
-    Background, Clipboard, Color, Element, Layout, Length, Padding, Point,
-    Rectangle, Shell, Vector, Widget,
+    Background, Clipboard, Color, Command, Element, Layout, Length, Padding,
+    Point, Rectangle, Shell, Vector, Widget,
 };
 
 pub use iced_style::button::{Appearance, StyleSheet};
@@ -56,6 +60,7 @@ where
     Renderer: crate::Renderer,
     Renderer::Theme: StyleSheet,
 {
+    id: Option<Id>,
     content: Element<'a, Message, Renderer>,
     on_press: Option<Message>,
     width: Length,
@@ -72,6 +77,7 @@ where
     /// Creates a new [`Button`] with the given content.
     pub fn new(content: impl Into<Element<'a, Message, Renderer>>) -> Self {
         Button {
+            id: None,
             content: content.into(),
             on_press: None,
             width: Length::Shrink,
@@ -81,6 +87,12 @@ where
         }
     }
 
+    /// Sets the [`Id`] of the [`Button`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
     /// Sets the width of the [`Button`].
     pub fn width(mut self, width: Length) -> Self {
         self.width = width;
@@ -165,13 +177,30 @@ where
         )
     }
 
+    fn inspect(&self, tree: &Tree, layout: Layout<'_>) -> inspect::Node {
+        inspect::Node::new::<Self>(layout.bounds(), self.width, self.height)
+            .id(self.id.as_ref().map(|id| &id.0))
+            .padding(self.padding)
+            .children([self
+                .content
+                .as_widget()
+                .inspect(&tree.children[0], layout.children().next().unwrap())])
+    }
+
     fn operate(
         &self,
         tree: &mut Tree,
         layout: Layout<'_>,
         operation: &mut dyn Operation<Message>,
     ) {
-        operation.container(None, &mut |operation| {
+        if self.on_press.is_some() {
+            operation.focusable(
+                tree.state.downcast_mut::<State>(),
+                self.id.as_ref().map(|id| &id.0),
+            );
+        }
+
+        operation.container(None, layout.bounds(), &mut |operation| {
             self.content.as_widget().operate(
                 &mut tree.children[0],
                 layout.children().next().unwrap(),
@@ -285,10 +314,41 @@ where
     }
 }
 
+/// The identifier of a [`Button`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Id(widget::Id);
+
+impl Id {
+    /// Creates a custom [`Id`].
+    pub fn new(id: impl Into<std::borrow::Cow<'static, str>>) -> Self {
+        Self(widget::Id::new(id))
+    }
+
+    /// Creates a unique [`Id`].
+    ///
+    /// This function produces a different [`Id`] every time it is called.
+    pub fn unique() -> Self {
+        Self(widget::Id::unique())
+    }
+}
+
+impl From<Id> for widget::Id {
+    fn from(id: Id) -> Self {
+        id.0
+    }
+}
+
+/// Produces a [`Command`] that focuses the [`Button`] with the given [`Id`].
+pub fn focus<Message: 'static>(id: Id) -> Command<Message> {
+    Command::widget(operation::focusable::focus(id.0))
+}
+
 /// The local state of a [`Button`].
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, PartialEq, Default)]
 pub struct State {
     is_pressed: bool,
+    is_focused: bool,
+    animation: animation::State<Status>,
 }
 
 impl State {
@@ -298,6 +358,61 @@ impl State {
     }
 }
 
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+/// The status of a [`Button`], which determines its [`Appearance`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum Status {
+    #[default]
+    Active,
+    Hovered,
+    Focused,
+    Pressed,
+    Disabled,
+}
+
+impl Status {
+    fn new(is_enabled: bool, is_mouse_over: bool, state: &State) -> Self {
+        if !is_enabled {
+            Status::Disabled
+        } else if is_mouse_over && state.is_pressed {
+            Status::Pressed
+        } else if state.is_focused {
+            Status::Focused
+        } else if is_mouse_over {
+            Status::Hovered
+        } else {
+            Status::Active
+        }
+    }
+
+    fn appearance<Style: Default>(
+        self,
+        style_sheet: &dyn StyleSheet<Style = Style>,
+        style: &Style,
+    ) -> Appearance {
+        match self {
+            Status::Active => style_sheet.active(style),
+            Status::Hovered => style_sheet.hovered(style),
+            Status::Focused => style_sheet.focused(style),
+            Status::Pressed => style_sheet.pressed(style),
+            Status::Disabled => style_sheet.disabled(style),
+        }
+    }
+}
+
 /// Processes the given [`Event`] and updates the [`State`] of a [`Button`]
 /// accordingly.
 pub fn update<'a, Message: Clone>(
@@ -308,48 +423,67 @@ pub fn update<'a, Message: Clone>(
     on_press: &Option<Message>,
     state: impl FnOnce() -> &'a mut State,
 ) -> event::Status {
-    match event {
-        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-        | Event::Touch(touch::Event::FingerPressed { .. }) => {
-            if on_press.is_some() {
-                let bounds = layout.bounds();
-
-                if bounds.contains(cursor_position) {
-                    let state = state();
+    let state = state();
+    let bounds = layout.bounds();
+
+    // Using the mouse dismisses the keyboard focus
+    if let Event::Mouse(mouse::Event::ButtonPressed(_))
+    | Event::Touch(touch::Event::FingerPressed { .. }) = event
+    {
+        state.is_focused = false;
+    }
 
-                    state.is_pressed = true;
+    let status = match event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. })
+            if on_press.is_some() && bounds.contains(cursor_position) =>
+        {
+            state.is_pressed = true;
 
-                    return event::Status::Captured;
-                }
-            }
+            event::Status::Captured
         }
         Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
-        | Event::Touch(touch::Event::FingerLifted { .. }) => {
-            if let Some(on_press) = on_press.clone() {
-                let state = state();
-
-                if state.is_pressed {
-                    state.is_pressed = false;
-
-                    let bounds = layout.bounds();
-
-                    if bounds.contains(cursor_position) {
-                        shell.publish(on_press);
-                    }
+        | Event::Touch(touch::Event::FingerLifted { .. })
+            if on_press.is_some() && state.is_pressed =>
+        {
+            state.is_pressed = false;
 
-                    return event::Status::Captured;
+            if bounds.contains(cursor_position) {
+                if let Some(on_press) = on_press.clone() {
+                    shell.publish(on_press);
                 }
             }
+
+            event::Status::Captured
         }
         Event::Touch(touch::Event::FingerLost { .. }) => {
-            let state = state();
-
             state.is_pressed = false;
+
+            event::Status::Ignored
         }
-        _ => {}
-    }
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code: keyboard::KeyCode::Space | keyboard::KeyCode::Enter,
+            ..
+        }) if state.is_focused => match on_press.clone() {
+            Some(on_press) => {
+                shell.publish(on_press);
+
+                event::Status::Captured
+            }
+            None => event::Status::Ignored,
+        },
+        _ => event::Status::Ignored,
+    };
+
+    let status_now = Status::new(
+        on_press.is_some(),
+        bounds.contains(cursor_position),
+        state,
+    );
 
-    event::Status::Ignored
+    state.animation.update(status_now, &event, shell);
+
+    status
 }
 
 /// Draws a [`Button`].
@@ -367,20 +501,19 @@ pub fn draw<'a, Renderer: crate::Renderer>(
 where
     Renderer::Theme: StyleSheet,
 {
-    let is_mouse_over = bounds.contains(cursor_position);
-
-    let styling = if !is_enabled {
-        style_sheet.disabled(style)
-    } else if is_mouse_over {
-        let state = state();
-
-        if state.is_pressed {
-            style_sheet.pressed(style)
-        } else {
-            style_sheet.hovered(style)
-        }
+    let state = state();
+    let status =
+        Status::new(is_enabled, bounds.contains(cursor_position), state);
+
+    // The animation may lag behind until the next event is processed
+    let styling = if state.animation.status() == status {
+        state
+            .animation
+            .interpolate(style_sheet.transition(style), |status| {
+                status.appearance(style_sheet, style)
+            })
     } else {
-        style_sheet.active(style)
+        status.appearance(style_sheet, style)
     };
 
     if styling.background.is_some() || styling.border_width > 0.0 {
@@ -451,3 +584,24 @@ pub fn mouse_interaction(
         mouse::Interaction::default()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Simulator;
+    use crate::widget::Column;
+
+    #[test]
+    fn focusing_a_button_by_id_lets_the_keyboard_press_it() {
+        let column: Column<'_, _, renderer::Null> = Column::new()
+            .push(Button::new("A").id(Id::new("a")).on_press("A"))
+            .push(Button::new("B").id(Id::new("b")).on_press("B"));
+
+        let mut simulator = Simulator::new(column);
+
+        simulator.focus(Id::new("b").into());
+        let _ = simulator.tap_key(keyboard::KeyCode::Enter);
+
+        assert_eq!(simulator.messages().collect::<Vec<_>>(), ["B"]);
+    }
+}
@@ -1,12 +1,16 @@
 //! Show toggle controls using checkboxes.
 use crate::alignment;
+use crate::animation;
 use crate::event::{self, Event};
+use crate::keyboard;
 use crate::layout;
 use crate::mouse;
 use crate::renderer;
 use crate::text;
 use crate::touch;
-use crate::widget::{self, Row, Text, Tree};
+use crate::widget::operation::{self, Operation};
+use crate::widget::tree::{self, Tree};
+use crate::widget::{self, Row, Text};
 use crate::{
     Alignment, Clipboard, Element, Layout, Length, Point, Rectangle, Shell,
     Widget,
@@ -132,6 +136,21 @@ where
     Renderer: text::Renderer,
     Renderer::Theme: StyleSheet + widget::text::StyleSheet,
 {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            is_focused: false,
+            animation: animation::State::new(Status {
+                is_hovered: false,
+                is_checked: self.is_checked,
+                is_focused: false,
+            }),
+        })
+    }
+
     fn width(&self) -> Length {
         self.width
     }
@@ -166,9 +185,18 @@ where
             .layout(renderer, limits)
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.focusable(tree.state.downcast_mut::<State>(), None);
+    }
+
     fn on_event(
         &mut self,
-        _tree: &mut Tree,
+        tree: &mut Tree,
         event: Event,
         layout: Layout<'_>,
         cursor_position: Point,
@@ -176,21 +204,54 @@ where
         _clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
-        match event {
+        let state = tree.state.downcast_mut::<State>();
+        let mouse_over = layout.bounds().contains(cursor_position);
+        let mut is_checked = self.is_checked;
+
+        // Using the mouse dismisses the keyboard focus
+        if let Event::Mouse(mouse::Event::ButtonPressed(_))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            state.is_focused = false;
+        }
+
+        let status = match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                let mouse_over = layout.bounds().contains(cursor_position);
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if mouse_over =>
+            {
+                shell.publish((self.on_toggle)(!self.is_checked));
 
-                if mouse_over {
-                    shell.publish((self.on_toggle)(!self.is_checked));
+                // Start transitioning right away, expecting the toggle to
+                // be accepted
+                is_checked = !is_checked;
 
-                    return event::Status::Captured;
-                }
+                event::Status::Captured
             }
-            _ => {}
-        }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Space | keyboard::KeyCode::Enter,
+                ..
+            }) if state.is_focused => {
+                shell.publish((self.on_toggle)(!self.is_checked));
+
+                is_checked = !is_checked;
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        };
+
+        state.animation.update(
+            Status {
+                is_hovered: mouse_over,
+                is_checked,
+                is_focused: state.is_focused,
+            },
+            &event,
+            shell,
+        );
 
-        event::Status::Ignored
+        status
     }
 
     fn mouse_interaction(
@@ -210,7 +271,7 @@ where
 
     fn draw(
         &self,
-        _tree: &Tree,
+        tree: &Tree,
         renderer: &mut Renderer,
         theme: &Renderer::Theme,
         style: &renderer::Style,
@@ -223,10 +284,38 @@ where
 
         let mut children = layout.children();
 
-        let custom_style = if is_mouse_over {
-            theme.hovered(&self.style, self.is_checked)
+        let state = tree.state.downcast_ref::<State>();
+
+        let status = Status {
+            is_hovered: is_mouse_over,
+            is_checked: self.is_checked,
+            is_focused: state.is_focused,
+        };
+
+        let appearance = |status: Status| {
+            let mut appearance = if status.is_focused {
+                theme.focused(&self.style, status.is_checked)
+            } else if status.is_hovered {
+                theme.hovered(&self.style, status.is_checked)
+            } else {
+                theme.active(&self.style, status.is_checked)
+            };
+
+            // The checkmark fades in and out
+            if !status.is_checked {
+                appearance.checkmark_color.a = 0.0;
+            }
+
+            appearance
+        };
+
+        // The animation may lag behind until the next event is processed
+        let custom_style = if state.animation.status() == status {
+            state
+                .animation
+                .interpolate(theme.transition(&self.style), appearance)
         } else {
-            theme.active(&self.style, self.is_checked)
+            appearance(status)
         };
 
         {
@@ -243,7 +332,7 @@ where
                 custom_style.background,
             );
 
-            if self.is_checked {
+            if custom_style.checkmark_color.a > 0.0 {
                 renderer.fill_text(text::Text {
                     content: &Renderer::CHECKMARK_ICON.to_string(),
                     font: Renderer::ICON_FONT,
@@ -293,3 +382,32 @@ where
         Element::new(checkbox)
     }
 }
+
+/// The local state of a [`Checkbox`].
+#[derive(Debug, Clone, PartialEq, Default)]
+struct State {
+    is_focused: bool,
+    animation: animation::State<Status>,
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+/// The status of a [`Checkbox`], which determines its [`Appearance`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+struct Status {
+    is_hovered: bool,
+    is_checked: bool,
+    is_focused: bool,
+}
@@ -0,0 +1,676 @@
+//! Search a list of options and select one of them.
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::overlay::menu::{self, Menu};
+use crate::renderer;
+use crate::text;
+use crate::touch;
+use crate::widget::container;
+use crate::widget::scrollable;
+use crate::widget::text_input::{self, Value};
+use crate::widget::tree::{self, Tree};
+use crate::widget::{self, Operation};
+use crate::{
+    Clipboard, Element, Layout, Length, Padding, Point, Rectangle, Shell,
+    Widget,
+};
+use std::borrow::Cow;
+
+pub use iced_style::text_input::{Appearance, StyleSheet};
+
+/// A text input that filters a list of options as the user types and lets
+/// them pick one of the matches.
+///
+/// The options are shown in a dropdown [`Menu`] while the [`ComboBox`] is
+/// focused. The arrow keys move the highlighted option and `Enter` selects
+/// it.
+#[allow(missing_debug_implementations)]
+pub struct ComboBox<'a, T, Message, Renderer>
+where
+    [T]: ToOwned<Owned = Vec<T>>,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet + menu::StyleSheet,
+{
+    id: Option<widget::Id>,
+    options: Cow<'a, [T]>,
+    placeholder: String,
+    selected: Option<T>,
+    on_selected: Box<dyn Fn(T) -> Message + 'a>,
+    on_input: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    on_option_hovered: Option<Box<dyn Fn(T) -> Message + 'a>>,
+    width: Length,
+    padding: Padding,
+    size: Option<u16>,
+    font: Renderer::Font,
+    style: <Renderer::Theme as StyleSheet>::Style,
+    menu_style: <Renderer::Theme as menu::StyleSheet>::Style,
+}
+
+impl<'a, T: 'a, Message, Renderer> ComboBox<'a, T, Message, Renderer>
+where
+    T: ToString,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet + menu::StyleSheet,
+{
+    /// The default padding of a [`ComboBox`].
+    pub const DEFAULT_PADDING: Padding = Padding::new(5);
+
+    /// Creates a new [`ComboBox`] with the given list of options, a
+    /// placeholder, the current selected value, and the message to produce
+    /// when an option is selected.
+    pub fn new(
+        options: impl Into<Cow<'a, [T]>>,
+        placeholder: &str,
+        selected: Option<T>,
+        on_selected: impl Fn(T) -> Message + 'a,
+    ) -> Self {
+        Self {
+            id: None,
+            options: options.into(),
+            placeholder: String::from(placeholder),
+            selected,
+            on_selected: Box::new(on_selected),
+            on_input: None,
+            on_option_hovered: None,
+            width: Length::Fill,
+            padding: Self::DEFAULT_PADDING,
+            size: None,
+            font: Default::default(),
+            style: Default::default(),
+            menu_style: Default::default(),
+        }
+    }
+
+    /// Sets the [`Id`] of the [`ComboBox`].
+    ///
+    /// [`Id`]: text_input::Id
+    pub fn id(mut self, id: text_input::Id) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Allows free-text entry in the [`ComboBox`].
+    ///
+    /// When `Enter` is pressed and no option is highlighted, the message
+    /// produced by the given closure is published with the typed text.
+    pub fn on_input(
+        mut self,
+        on_input: impl Fn(String) -> Message + 'a,
+    ) -> Self {
+        self.on_input = Some(Box::new(on_input));
+        self
+    }
+
+    /// Sets the message that should be produced when an option of the
+    /// [`ComboBox`] is highlighted, either with the mouse or the keyboard.
+    pub fn on_option_hovered(
+        mut self,
+        on_option_hovered: impl Fn(T) -> Message + 'a,
+    ) -> Self {
+        self.on_option_hovered = Some(Box::new(on_option_hovered));
+        self
+    }
+
+    /// Sets the width of the [`ComboBox`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`ComboBox`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`ComboBox`].
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the font of the [`ComboBox`].
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the input of the [`ComboBox`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the dropdown [`Menu`] of the [`ComboBox`].
+    pub fn menu_style(
+        mut self,
+        style: impl Into<<Renderer::Theme as menu::StyleSheet>::Style>,
+    ) -> Self {
+        self.menu_style = style.into();
+        self
+    }
+}
+
+/// The local state of a [`ComboBox`].
+#[derive(Debug)]
+struct State<T> {
+    text_input: text_input::State,
+    value: Value,
+    menu: menu::State,
+    filtered: Vec<T>,
+    hovered_option: Option<usize>,
+    last_hovered: Option<usize>,
+    last_selection: Option<T>,
+}
+
+impl<T> State<T> {
+    fn new() -> Self {
+        Self {
+            text_input: text_input::State::new(),
+            value: Value::new(""),
+            menu: menu::State::default(),
+            filtered: Vec::new(),
+            hovered_option: None,
+            last_hovered: None,
+            last_selection: None,
+        }
+    }
+}
+
+impl<T> State<T>
+where
+    T: Clone + ToString,
+{
+    fn filter(&mut self, options: &[T]) {
+        let query = self.value.to_string().to_lowercase();
+
+        self.filtered = options
+            .iter()
+            .filter(|option| option.to_string().to_lowercase().contains(&query))
+            .cloned()
+            .collect();
+
+        if self
+            .hovered_option
+            .is_some_and(|index| index >= self.filtered.len())
+        {
+            self.hovered_option = None;
+        }
+    }
+
+    /// Clears the query and shows every option, as it happens whenever the
+    /// [`ComboBox`] gains focus.
+    fn reset(&mut self, options: &[T]) {
+        self.value = Value::new("");
+        self.text_input.move_cursor_to_end();
+        self.hovered_option = None;
+        self.filter(options);
+    }
+}
+
+impl<'a, T: 'a, Message, Renderer> Widget<Message, Renderer>
+    for ComboBox<'a, T, Message, Renderer>
+where
+    T: Clone + ToString + 'static,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Message: Clone,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme: StyleSheet
+        + menu::StyleSheet
+        + scrollable::StyleSheet
+        + container::StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<T>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<T>::new())
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State<T>>();
+
+        if state.text_input.is_focused() {
+            state.filter(&self.options);
+        }
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        text_input::layout(
+            renderer,
+            limits,
+            self.width,
+            self.padding,
+            self.size,
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State<T>>();
+        let was_focused = state.text_input.is_focused();
+
+        operation.focusable(&mut state.text_input, self.id.as_ref());
+
+        if state.text_input.is_focused() {
+            if !was_focused {
+                state.reset(&self.options);
+            }
+
+            operation.text(
+                self.id.as_ref(),
+                layout.bounds(),
+                &state.value.to_string(),
+            );
+        } else {
+            operation.text(
+                self.id.as_ref(),
+                layout.bounds(),
+                &self
+                    .selected
+                    .as_ref()
+                    .map(ToString::to_string)
+                    .unwrap_or_default(),
+            );
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<T>>();
+        let was_focused = state.text_input.is_focused();
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(_))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            if let Some(selection) = state.last_selection.take() {
+                shell.publish((self.on_selected)(selection));
+
+                state.text_input.unfocus();
+                shell.invalidate_layout();
+
+                return event::Status::Captured;
+            }
+        }
+
+        if was_focused {
+            if let Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                ..
+            }) = event
+            {
+                match key_code {
+                    keyboard::KeyCode::Up | keyboard::KeyCode::Down => {
+                        let len = state.filtered.len();
+
+                        state.hovered_option = (len > 0).then(|| {
+                            match (key_code, state.hovered_option) {
+                                (keyboard::KeyCode::Down, None) => 0,
+                                (keyboard::KeyCode::Down, Some(index)) => {
+                                    (index + 1).min(len - 1)
+                                }
+                                (_, None) => len - 1,
+                                (_, Some(index)) => index.saturating_sub(1),
+                            }
+                        });
+
+                        if let Some(index) = state.hovered_option {
+                            let text_size = self
+                                .size
+                                .unwrap_or_else(|| renderer.default_size());
+
+                            state.menu.scroll_to_option(
+                                index,
+                                f32::from(text_size + self.padding.vertical()),
+                            );
+                        }
+
+                        self.publish_hovered(state, shell);
+
+                        return event::Status::Captured;
+                    }
+                    keyboard::KeyCode::Enter
+                    | keyboard::KeyCode::NumpadEnter => {
+                        let option = state
+                            .hovered_option
+                            .and_then(|index| state.filtered.get(index));
+
+                        if let Some(option) = option {
+                            shell.publish((self.on_selected)(option.clone()));
+                        } else if let Some(on_input) = &self.on_input {
+                            let text = state.value.to_string();
+
+                            if text.is_empty() {
+                                return event::Status::Captured;
+                            }
+
+                            shell.publish(on_input(text));
+                        } else {
+                            return event::Status::Captured;
+                        }
+
+                        state.text_input.unfocus();
+                        shell.invalidate_layout();
+
+                        return event::Status::Captured;
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let mut changes = Vec::new();
+        let mut local_shell = Shell::new(&mut changes);
+
+        let status = text_input::update(
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            &mut local_shell,
+            &mut state.value,
+            self.size,
+            &self.font,
+            false,
+            &|text| text,
+            None,
+            &None,
+            || &mut state.text_input,
+        );
+
+        if local_shell.is_layout_invalid() {
+            shell.invalidate_layout();
+        }
+
+        if let Some(at) = local_shell.redraw_request() {
+            shell.request_redraw(at);
+        }
+
+        let is_focused = state.text_input.is_focused();
+
+        if is_focused && !was_focused {
+            state.reset(&self.options);
+
+            shell.invalidate_layout();
+        } else if !is_focused && was_focused {
+            shell.invalidate_layout();
+        } else if !changes.is_empty() {
+            state.hovered_option = None;
+            state.filter(&self.options);
+
+            shell.invalidate_layout();
+        }
+
+        if is_focused {
+            self.publish_hovered(state, shell);
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        text_input::mouse_interaction(layout, cursor_position)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<T>>();
+        let selected = self.selected.as_ref().map(ToString::to_string);
+
+        if state.text_input.is_focused() {
+            text_input::draw(
+                renderer,
+                theme,
+                layout,
+                cursor_position,
+                &state.text_input,
+                &state.value,
+                selected.as_deref().unwrap_or(&self.placeholder),
+                self.size,
+                &self.font,
+                false,
+                &self.style,
+            );
+        } else {
+            text_input::draw(
+                renderer,
+                theme,
+                layout,
+                cursor_position,
+                &state.text_input,
+                &Value::new(selected.as_deref().unwrap_or_default()),
+                &self.placeholder,
+                self.size,
+                &self.font,
+                false,
+                &self.style,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let state = tree.state.downcast_mut::<State<T>>();
+
+        if !state.text_input.is_focused() || state.filtered.is_empty() {
+            return None;
+        }
+
+        let bounds = layout.bounds();
+
+        let mut menu = Menu::new(
+            &mut state.menu,
+            &state.filtered,
+            &mut state.hovered_option,
+            &mut state.last_selection,
+        )
+        .width(bounds.width.round() as u16)
+        .padding(self.padding)
+        .font(self.font.clone())
+        .style(self.menu_style.clone());
+
+        if let Some(size) = self.size {
+            menu = menu.text_size(size);
+        }
+
+        Some(menu.overlay(layout.position(), bounds.height))
+    }
+}
+
+impl<'a, T: 'a, Message, Renderer> ComboBox<'a, T, Message, Renderer>
+where
+    T: Clone + ToString,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet + menu::StyleSheet,
+{
+    fn publish_hovered(
+        &self,
+        state: &mut State<T>,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        if state.hovered_option == state.last_hovered {
+            return;
+        }
+
+        state.last_hovered = state.hovered_option;
+
+        let option = state
+            .hovered_option
+            .and_then(|index| state.filtered.get(index));
+
+        if let (Some(on_option_hovered), Some(option)) =
+            (&self.on_option_hovered, option)
+        {
+            shell.publish(on_option_hovered(option.clone()));
+        }
+    }
+}
+
+impl<'a, T: 'a, Message, Renderer> From<ComboBox<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: Clone + ToString + 'static,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Message: Clone + 'a,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme: StyleSheet
+        + menu::StyleSheet
+        + scrollable::StyleSheet
+        + container::StyleSheet,
+{
+    fn from(combo_box: ComboBox<'a, T, Message, Renderer>) -> Self {
+        Self::new(combo_box)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Simulator;
+    use crate::Size;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Select(&'static str),
+        Input(String),
+    }
+
+    const FRUITS: &[&str] = &["Apple", "Banana", "Cherry"];
+
+    fn fruits(
+        selected: Option<&'static str>,
+    ) -> Simulator<'static, Message, renderer::Null> {
+        let combo_box =
+            ComboBox::new(FRUITS, "Fruit", selected, Message::Select)
+                .id(text_input::Id::new("fruit"))
+                .on_input(Message::Input);
+
+        Simulator::with_size(combo_box, Size::new(400.0, 400.0))
+    }
+
+    #[test]
+    fn typing_filters_the_options() {
+        let mut simulator = fruits(None);
+
+        let _ = simulator.click(widget::Id::new("fruit")).unwrap();
+        let _ = simulator.typewrite("rr");
+        let _ = simulator.tap_key(keyboard::KeyCode::Down);
+        let _ = simulator.tap_key(keyboard::KeyCode::Enter);
+
+        let _ = simulator.click(widget::Id::new("fruit")).unwrap();
+        let _ = simulator.typewrite("Kiwi");
+        let _ = simulator.tap_key(keyboard::KeyCode::Enter);
+
+        assert_eq!(
+            simulator.messages().collect::<Vec<_>>(),
+            [
+                Message::Select("Cherry"),
+                Message::Input(String::from("Kiwi"))
+            ]
+        );
+    }
+
+    #[test]
+    fn arrow_keys_scroll_the_hovered_option_into_view() {
+        const OPTIONS: &[&str] =
+            &["0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11"];
+
+        let combo_box: ComboBox<'_, _, _, renderer::Null> =
+            ComboBox::new(OPTIONS, "Number", None, Message::Select);
+        let mut simulator =
+            Simulator::with_size(combo_box, Size::new(400.0, 200.0));
+
+        let _ = simulator.click("").unwrap();
+
+        for _ in 0..10 {
+            let _ = simulator.tap_key(keyboard::KeyCode::Down);
+        }
+
+        // The menu below the 30px input shows 170px of 30px options, so
+        // option 9 is at its bottom once scrolled.
+        let _ = simulator.point_at(Point::new(200.0, 195.0));
+        let _ = simulator.simulate([
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+        ]);
+
+        assert_eq!(
+            simulator.messages().collect::<Vec<_>>(),
+            [Message::Select("9")]
+        );
+    }
+
+    #[test]
+    fn focusing_clears_the_query() {
+        let mut simulator = fruits(Some("Banana"));
+
+        simulator.focus(widget::Id::new("fruit"));
+
+        assert_eq!(
+            simulator.find(""),
+            Ok(Rectangle::new(Point::ORIGIN, Size::new(400.0, 30.0)))
+        );
+
+        let _ = simulator.typewrite("App");
+        let _ = simulator.tap_key(keyboard::KeyCode::Up);
+        let _ = simulator.tap_key(keyboard::KeyCode::Enter);
+
+        assert_eq!(
+            simulator.messages().collect::<Vec<_>>(),
+            [Message::Select("Apple")]
+        );
+    }
+}
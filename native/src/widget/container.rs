@@ -5,7 +5,8 @@ use crate::layout;
 use crate::mouse;
 use crate::overlay;
 use crate::renderer;
-use crate::widget::{Operation, Tree};
+use crate::widget::inspect;
+use crate::widget::{self, Operation, Tree};
 use crate::{
     Background, Clipboard, Color, Element, Layout, Length, Padding, Point,
     Rectangle, Shell, Widget,
@@ -24,6 +25,7 @@ where
     Renderer: crate::Renderer,
     Renderer::Theme: StyleSheet,
 {
+    id: Option<Id>,
     padding: Padding,
     width: Length,
     height: Length,
@@ -32,6 +34,7 @@ where
     horizontal_alignment: alignment::Horizontal,
     vertical_alignment: alignment::Vertical,
     style: <Renderer::Theme as StyleSheet>::Style,
+    is_focus_scope: bool,
     content: Element<'a, Message, Renderer>,
 }
 
@@ -46,6 +49,7 @@ where
         T: Into<Element<'a, Message, Renderer>>,
     {
         Container {
+            id: None,
             padding: Padding::ZERO,
             width: Length::Shrink,
             height: Length::Shrink,
@@ -54,10 +58,17 @@ where
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Top,
             style: Default::default(),
+            is_focus_scope: false,
             content: content.into(),
         }
     }
 
+    /// Sets the [`Id`] of the [`Container`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
     /// Sets the [`Padding`] of the [`Container`].
     pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
         self.padding = padding.into();
@@ -112,6 +123,15 @@ where
         self
     }
 
+    /// Makes the [`Container`] a focus scope.
+    ///
+    /// While one of its contents is focused, traversing the focus with the
+    /// keyboard cycles through the contents of the [`Container`] only.
+    pub fn focus_scope(mut self) -> Self {
+        self.is_focus_scope = true;
+        self
+    }
+
     /// Sets the style of the [`Container`].
     pub fn style(
         mut self,
@@ -165,19 +185,42 @@ where
         )
     }
 
+    fn inspect(&self, tree: &Tree, layout: Layout<'_>) -> inspect::Node {
+        inspect::Node::new::<Self>(layout.bounds(), self.width, self.height)
+            .id(self.id.as_ref().map(|id| &id.0))
+            .padding(self.padding)
+            .children([self
+                .content
+                .as_widget()
+                .inspect(&tree.children[0], layout.children().next().unwrap())])
+    }
+
     fn operate(
         &self,
         tree: &mut Tree,
         layout: Layout<'_>,
         operation: &mut dyn Operation<Message>,
     ) {
-        operation.container(None, &mut |operation| {
-            self.content.as_widget().operate(
-                &mut tree.children[0],
-                layout.children().next().unwrap(),
-                operation,
+        let id = self.id.as_ref().map(|id| &id.0);
+
+        let mut operate_on_children =
+            |operation: &mut dyn Operation<Message>| {
+                self.content.as_widget().operate(
+                    &mut tree.children[0],
+                    layout.children().next().unwrap(),
+                    operation,
+                );
+            };
+
+        if self.is_focus_scope {
+            operation.focus_scope(
+                id,
+                layout.bounds(),
+                &mut operate_on_children,
             );
-        });
+        } else {
+            operation.container(id, layout.bounds(), &mut operate_on_children);
+        }
     }
 
     fn on_event(
@@ -275,6 +318,30 @@ where
     }
 }
 
+/// The identifier of a [`Container`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Id(widget::Id);
+
+impl Id {
+    /// Creates a custom [`Id`].
+    pub fn new(id: impl Into<std::borrow::Cow<'static, str>>) -> Self {
+        Self(widget::Id::new(id))
+    }
+
+    /// Creates a unique [`Id`].
+    ///
+    /// This function produces a different [`Id`] every time it is called.
+    pub fn unique() -> Self {
+        Self(widget::Id::unique())
+    }
+}
+
+impl From<Id> for widget::Id {
+    fn from(id: Id) -> Self {
+        id.0
+    }
+}
+
 /// Computes the layout of a [`Container`].
 pub fn layout<Renderer>(
     renderer: &Renderer,
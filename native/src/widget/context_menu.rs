@@ -0,0 +1,361 @@
+//! Show a menu of actions when right-clicking some content.
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::overlay::menu::{self, Menu};
+use crate::renderer;
+use crate::text;
+use crate::touch;
+use crate::widget::container;
+use crate::widget::inspect;
+use crate::widget::scrollable;
+use crate::widget::tree::{self, Tree};
+use crate::widget::Operation;
+use crate::{
+    Clipboard, Element, Layout, Length, Padding, Point, Rectangle, Shell,
+    Vector, Widget,
+};
+
+pub use crate::overlay::menu::Item;
+pub use iced_style::menu::{Appearance, ItemAppearance, StyleSheet};
+
+/// A widget that shows a menu of [`Item`]s at the cursor position when its
+/// content is right-clicked.
+#[allow(missing_debug_implementations)]
+pub struct ContextMenu<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    content: Element<'a, Message, Renderer>,
+    items: Vec<Item<Message>>,
+    width: u16,
+    padding: Padding,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> ContextMenu<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`ContextMenu`] for the given content with the given
+    /// items.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Renderer>>,
+        items: Vec<Item<Message>>,
+    ) -> Self {
+        ContextMenu {
+            content: content.into(),
+            items,
+            width: 0,
+            padding: Padding::from([4, 8]),
+            text_size: None,
+            font: Default::default(),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the minimum width of the menus of the [`ContextMenu`].
+    pub fn width(mut self, width: u16) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the [`Padding`] of every item of the [`ContextMenu`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`ContextMenu`].
+    pub fn text_size(mut self, text_size: u16) -> Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Sets the font of the [`ContextMenu`].
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`ContextMenu`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// The local state of a [`ContextMenu`].
+#[derive(Debug)]
+struct State<Message> {
+    menu: menu::State,
+    offset: Vector,
+    last_selection: Option<Message>,
+}
+
+impl<Message> Default for State<Message> {
+    fn default() -> Self {
+        Self {
+            menu: menu::State::default(),
+            offset: Vector::default(),
+            last_selection: None,
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for ContextMenu<'a, Message, Renderer>
+where
+    Message: Clone + 'static,
+    Renderer: text::Renderer,
+    Renderer::Theme:
+        StyleSheet + container::StyleSheet + scrollable::StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Message>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Message>::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content))
+    }
+
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.as_widget().layout(renderer, limits)
+    }
+
+    fn inspect(&self, tree: &Tree, layout: Layout<'_>) -> inspect::Node {
+        inspect::Node::new::<Self>(
+            layout.bounds(),
+            self.content.as_widget().width(),
+            self.content.as_widget().height(),
+        )
+        .children([self.content.as_widget().inspect(&tree.children[0], layout)])
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        // The menu ignores the event that selects an item, so it reaches the
+        // content only after the selection has been published.
+        if let Some(message) = tree
+            .state
+            .downcast_mut::<State<Message>>()
+            .last_selection
+            .take()
+        {
+            shell.publish(message);
+
+            return event::Status::Captured;
+        }
+
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        );
+
+        let state = tree.state.downcast_mut::<State<Message>>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right))
+                if layout.bounds().contains(cursor_position) =>
+            {
+                let position = layout.position();
+
+                state.offset = cursor_position - position;
+                state.menu.open();
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if state.menu.is_open() =>
+            {
+                state.menu.close();
+            }
+            _ => {}
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor_position,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let state = tree.state.downcast_mut::<State<Message>>();
+
+        if !state.menu.is_open() {
+            return self.content.as_widget_mut().overlay(
+                &mut tree.children[0],
+                layout,
+                renderer,
+            );
+        }
+
+        let mut menu = Menu::with_items(
+            &mut state.menu,
+            &self.items,
+            &mut state.last_selection,
+        )
+        .width(self.width)
+        .padding(self.padding)
+        .font(self.font.clone())
+        .style(self.style.clone());
+
+        if let Some(text_size) = self.text_size {
+            menu = menu.text_size(text_size);
+        }
+
+        Some(menu.overlay(layout.position() + state.offset, 0.0))
+    }
+}
+
+impl<'a, Message, Renderer> From<ContextMenu<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: Clone + 'static,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme:
+        StyleSheet + container::StyleSheet + scrollable::StyleSheet,
+{
+    fn from(context_menu: ContextMenu<'a, Message, Renderer>) -> Self {
+        Self::new(context_menu)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Simulator;
+    use crate::widget::Space;
+    use crate::Size;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Copy,
+        Paste,
+    }
+
+    #[test]
+    fn selecting_an_item_publishes_its_message() {
+        let context_menu: ContextMenu<'_, Message, renderer::Null> =
+            ContextMenu::new(
+                Space::new(Length::Units(200), Length::Units(200)),
+                vec![
+                    Item::new("Copy").on_select(Message::Copy),
+                    Item::separator(),
+                    Item::new("Paste").on_select(Message::Paste),
+                ],
+            );
+
+        let mut simulator =
+            Simulator::with_size(context_menu, Size::new(400.0, 400.0));
+
+        let _ = simulator.point_at(Point::new(50.0, 50.0));
+        let _ = simulator.simulate([Event::Mouse(
+            mouse::Event::ButtonPressed(mouse::Button::Right),
+        )]);
+
+        // Items are 28 units tall, and the separator 10 units
+        let _ = simulator.point_at(Point::new(60.0, 50.0 + 28.0 + 10.0 + 14.0));
+        let _ = simulator.simulate([
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+        ]);
+
+        assert_eq!(simulator.messages().collect::<Vec<_>>(), [Message::Paste]);
+    }
+}
@@ -0,0 +1,405 @@
+//! Start dragging some content with the mouse or touch.
+use crate::dnd;
+use crate::event;
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::touch;
+use crate::widget::inspect;
+use crate::widget::tree::{self, Tree};
+use crate::widget::Operation;
+use crate::{
+    Clipboard, Element, Event, Layout, Length, Point, Rectangle, Shell, Size,
+    Vector, Widget,
+};
+
+/// A widget that starts dragging a [`dnd::Payload`] when its content is
+/// pressed and moved.
+///
+/// While dragging, an optional preview is drawn next to the cursor. The
+/// [`dnd::Payload`] can be received by a [`DropZone`].
+///
+/// [`DropZone`]: crate::widget::DropZone
+#[allow(missing_debug_implementations)]
+pub struct Draggable<'a, Message, Renderer> {
+    content: Element<'a, Message, Renderer>,
+    preview: Option<Element<'a, Message, Renderer>>,
+    payload: dnd::Payload,
+    on_drag: Option<Message>,
+}
+
+impl<'a, Message, Renderer> Draggable<'a, Message, Renderer> {
+    /// The distance the cursor needs to travel before a drag starts.
+    const DRAG_THRESHOLD: f32 = 4.0;
+
+    /// The offset of the preview from the cursor.
+    const PREVIEW_OFFSET: Vector = Vector::new(12.0, 12.0);
+
+    /// Creates a new [`Draggable`] with the given content and
+    /// [`dnd::Payload`].
+    pub fn new(
+        content: impl Into<Element<'a, Message, Renderer>>,
+        payload: dnd::Payload,
+    ) -> Self {
+        Draggable {
+            content: content.into(),
+            preview: None,
+            payload,
+            on_drag: None,
+        }
+    }
+
+    /// Sets the element drawn next to the cursor while dragging.
+    pub fn preview(
+        mut self,
+        preview: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        self.preview = Some(preview.into());
+        self
+    }
+
+    /// Sets the message that will be produced when a drag starts.
+    pub fn on_drag(mut self, message: Message) -> Self {
+        self.on_drag = Some(message);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    origin: Option<Point>,
+    is_dragging: bool,
+    cursor_position: Point,
+}
+
+impl State {
+    fn reset(&mut self) {
+        *self = State {
+            cursor_position: self.cursor_position,
+            ..State::default()
+        };
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Draggable<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: crate::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        std::iter::once(&self.content)
+            .chain(&self.preview)
+            .map(Tree::new)
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let children: Vec<_> = std::iter::once(&self.content)
+            .chain(&self.preview)
+            .collect();
+
+        tree.diff_children(&children);
+    }
+
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.as_widget().layout(renderer, limits)
+    }
+
+    fn inspect(&self, tree: &Tree, layout: Layout<'_>) -> inspect::Node {
+        inspect::Node::new::<Self>(
+            layout.bounds(),
+            self.content.as_widget().width(),
+            self.content.as_widget().height(),
+        )
+        .children([self.content.as_widget().inspect(&tree.children[0], layout)])
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        );
+
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if status == event::Status::Ignored
+                    && layout.bounds().contains(cursor_position) =>
+            {
+                state.origin = Some(cursor_position);
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                state.cursor_position = position;
+
+                // The preview follows the cursor in the layout of its own
+                // overlay, so the base layout is never invalidated
+                if let (Some(origin), false) = (state.origin, state.is_dragging)
+                {
+                    if origin.distance(position) > Self::DRAG_THRESHOLD {
+                        state.is_dragging = true;
+
+                        shell.start_drag(self.payload.clone());
+
+                        if let Some(on_drag) = self.on_drag.clone() {
+                            shell.publish(on_drag);
+                        }
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. })
+            | Event::DragAndDrop(dnd::Event::Left) => {
+                state.reset();
+            }
+            _ => {}
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.is_dragging {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let interaction = self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor_position,
+            viewport,
+            renderer,
+        );
+
+        if interaction == mouse::Interaction::default()
+            && layout.bounds().contains(cursor_position)
+        {
+            mouse::Interaction::Grab
+        } else {
+            interaction
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor_position,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let state = tree.state.downcast_ref::<State>();
+
+        match &mut self.preview {
+            Some(preview) if state.is_dragging => {
+                let position = state.cursor_position + Self::PREVIEW_OFFSET;
+
+                Some(overlay::Element::new(
+                    position,
+                    Box::new(Preview {
+                        preview,
+                        tree: &mut tree.children[1],
+                    }),
+                ))
+            }
+            _ => self.content.as_widget_mut().overlay(
+                &mut tree.children[0],
+                layout,
+                renderer,
+            ),
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<Draggable<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + crate::Renderer,
+{
+    fn from(
+        draggable: Draggable<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(draggable)
+    }
+}
+
+struct Preview<'a, 'b, Message, Renderer> {
+    preview: &'b mut Element<'a, Message, Renderer>,
+    tree: &'b mut Tree,
+}
+
+impl<'a, 'b, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for Preview<'a, 'b, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let limits = layout::Limits::new(Size::ZERO, bounds);
+
+        let mut node = self.preview.as_widget().layout(renderer, &limits);
+        node.move_to(position);
+
+        node
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.preview
+            .as_widget()
+            .operate(self.tree, layout, operation);
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) {
+        self.preview.as_widget().draw(
+            self.tree,
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor_position,
+            &layout.bounds(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Simulator;
+    use crate::theme::Theme;
+    use crate::widget::Text;
+
+    #[test]
+    fn moving_past_the_threshold_drags_a_preview() {
+        let draggable: Draggable<'_, _, renderer::Null> =
+            Draggable::new(Text::new("Handle"), dnd::Payload::new(42))
+                .preview(Text::new("Preview"))
+                .on_drag(());
+
+        let mut simulator =
+            Simulator::with_size(draggable, Size::new(400.0, 400.0));
+
+        let _ = simulator.point_at(Point::new(0.0, 10.0));
+        let _ = simulator.simulate([Event::Mouse(
+            mouse::Event::ButtonPressed(mouse::Button::Left),
+        )]);
+        let _ = simulator.point_at(Point::new(3.0, 10.0));
+
+        assert_eq!(simulator.messages().count(), 0);
+
+        let _ = simulator.point_at(Point::new(10.0, 10.0));
+        let _ = simulator.point_at(Point::new(20.0, 10.0));
+
+        assert_eq!(simulator.draw(&Theme::Light), mouse::Interaction::Grabbing);
+        assert_eq!(
+            simulator.find("Preview").map(|bounds| bounds.position()),
+            Ok(Point::new(32.0, 22.0))
+        );
+
+        let _ = simulator.simulate([Event::Mouse(
+            mouse::Event::ButtonReleased(mouse::Button::Left),
+        )]);
+        let _ = simulator.point_at(Point::new(0.0, 10.0));
+
+        assert_eq!(simulator.draw(&Theme::Light), mouse::Interaction::Grab);
+        assert_eq!(simulator.messages().count(), 1);
+    }
+}
@@ -0,0 +1,272 @@
+//! Receive content dropped by a drag and drop operation.
+use crate::dnd;
+use crate::event;
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::widget::inspect;
+use crate::widget::tree::{self, Tree};
+use crate::widget::Operation;
+use crate::{
+    Clipboard, Element, Event, Layout, Length, Point, Rectangle, Shell, Widget,
+};
+
+/// A widget that receives the [`dnd::Payload`] dropped over its content.
+///
+/// The payload can be dragged from a [`Draggable`] or from the operating
+/// system, in which case it contains the dropped files.
+///
+/// The positions given to the handlers are relative to the top-left corner of
+/// the [`DropZone`]. When drop zones are nested, the innermost one receives
+/// the payload.
+///
+/// [`Draggable`]: crate::widget::Draggable
+#[allow(missing_debug_implementations)]
+pub struct DropZone<'a, Message, Renderer> {
+    content: Element<'a, Message, Renderer>,
+    on_hover: Option<Box<dyn Fn(dnd::Payload, Point) -> Message + 'a>>,
+    on_drop: Option<Box<dyn Fn(dnd::Payload, Point) -> Message + 'a>>,
+    on_leave: Option<Message>,
+}
+
+impl<'a, Message, Renderer> DropZone<'a, Message, Renderer> {
+    /// Creates a new [`DropZone`] with the given content.
+    pub fn new(content: impl Into<Element<'a, Message, Renderer>>) -> Self {
+        DropZone {
+            content: content.into(),
+            on_hover: None,
+            on_drop: None,
+            on_leave: None,
+        }
+    }
+
+    /// Sets the message that will be produced when a [`dnd::Payload`] is
+    /// dragged over the [`DropZone`].
+    pub fn on_hover(
+        mut self,
+        f: impl Fn(dnd::Payload, Point) -> Message + 'a,
+    ) -> Self {
+        self.on_hover = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message that will be produced when a [`dnd::Payload`] is
+    /// dropped on the [`DropZone`].
+    pub fn on_drop(
+        mut self,
+        f: impl Fn(dnd::Payload, Point) -> Message + 'a,
+    ) -> Self {
+        self.on_drop = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message that will be produced when a drag leaves the
+    /// [`DropZone`] without dropping its [`dnd::Payload`].
+    pub fn on_leave(mut self, message: Message) -> Self {
+        self.on_leave = Some(message);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    is_hovered: bool,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for DropZone<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: crate::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content))
+    }
+
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.as_widget().layout(renderer, limits)
+    }
+
+    fn inspect(&self, tree: &Tree, layout: Layout<'_>) -> inspect::Node {
+        inspect::Node::new::<Self>(
+            layout.bounds(),
+            self.content.as_widget().width(),
+            self.content.as_widget().height(),
+        )
+        .children([self.content.as_widget().inspect(&tree.children[0], layout)])
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        );
+
+        let dnd_event = match event {
+            Event::DragAndDrop(dnd_event) => dnd_event,
+            _ => return status,
+        };
+
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        let target = match &dnd_event {
+            dnd::Event::Moved { position, .. }
+            | dnd::Event::Dropped { position, .. }
+                if status == event::Status::Ignored
+                    && bounds.contains(*position) =>
+            {
+                Some(Point::new(position.x - bounds.x, position.y - bounds.y))
+            }
+            _ => None,
+        };
+
+        match (dnd_event, target) {
+            (dnd::Event::Moved { payload, .. }, Some(position)) => {
+                state.is_hovered = true;
+
+                if let Some(on_hover) = &self.on_hover {
+                    shell.publish(on_hover(payload, position));
+                }
+
+                event::Status::Captured
+            }
+            (dnd::Event::Dropped { payload, .. }, Some(position)) => {
+                state.is_hovered = false;
+
+                if let Some(on_drop) = &self.on_drop {
+                    shell.publish(on_drop(payload, position));
+                }
+
+                event::Status::Captured
+            }
+            _ => {
+                if state.is_hovered {
+                    state.is_hovered = false;
+
+                    if let Some(on_leave) = self.on_leave.clone() {
+                        shell.publish(on_leave);
+                    }
+                }
+
+                status
+            }
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor_position,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<DropZone<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + crate::Renderer,
+{
+    fn from(
+        drop_zone: DropZone<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(drop_zone)
+    }
+}
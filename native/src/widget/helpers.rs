@@ -1,4 +1,5 @@
 //! Helper functions to create pure widgets.
+use crate::dnd;
 use crate::overlay;
 use crate::widget;
 use crate::{Element, Length};
@@ -76,6 +77,65 @@ where
     widget::Scrollable::new(content)
 }
 
+/// Creates a new [`VirtualList`] with the given amount of rows, their
+/// [`RowHeight`], and a closure that produces the row with the given index.
+///
+/// [`VirtualList`]: widget::VirtualList
+/// [`RowHeight`]: widget::virtual_list::RowHeight
+pub fn virtual_list<'a, Message, Renderer>(
+    len: usize,
+    row_height: widget::virtual_list::RowHeight,
+    view_row: impl Fn(usize) -> Element<'a, Message, Renderer> + 'a,
+) -> widget::VirtualList<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: widget::scrollable::StyleSheet,
+{
+    widget::VirtualList::new(len, row_height, view_row)
+}
+
+/// Creates a new [`Table`] with the provided columns.
+///
+/// [`Table`]: widget::Table
+pub fn table<'a, Message, Renderer>(
+    columns: Vec<widget::table::Column<'a, Message, Renderer>>,
+) -> widget::Table<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: crate::Renderer + 'a,
+    Renderer::Theme: widget::table::StyleSheet,
+{
+    widget::Table::new(columns)
+}
+
+/// Creates a new [`Tabs`] widget where the tab with the given index is
+/// active.
+///
+/// [`Tabs`]: widget::Tabs
+pub fn tabs<'a, Message, Renderer>(
+    active: usize,
+) -> widget::Tabs<'a, Message, Renderer>
+where
+    Renderer: crate::text::Renderer,
+    Renderer::Theme: widget::tabs::StyleSheet,
+{
+    widget::Tabs::new(active)
+}
+
+/// Creates a new [`TreeView`] with the provided root nodes.
+///
+/// [`TreeView`]: widget::TreeView
+pub fn tree_view<'a, Message, Renderer>(
+    nodes: Vec<widget::tree_view::Node<'a, Message, Renderer>>,
+) -> widget::TreeView<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: crate::Renderer + 'a,
+    Renderer::Theme: widget::tree_view::StyleSheet,
+{
+    widget::TreeView::new(nodes)
+}
+
 /// Creates a new [`Button`] with the provided content.
 ///
 /// [`Button`]: widget::Button
@@ -90,20 +150,20 @@ where
     widget::Button::new(content)
 }
 
-/// Creates a new [`Tooltip`] with the provided content, tooltip text, and [`tooltip::Position`].
+/// Creates a new [`Tooltip`] with the provided content, tooltip, and [`tooltip::Position`].
 ///
 /// [`Tooltip`]: widget::Tooltip
 /// [`tooltip::Position`]: widget::tooltip::Position
 pub fn tooltip<'a, Message, Renderer>(
     content: impl Into<Element<'a, Message, Renderer>>,
-    tooltip: impl ToString,
+    tooltip: impl Into<Element<'a, Message, Renderer>>,
     position: widget::tooltip::Position,
 ) -> widget::Tooltip<'a, Message, Renderer>
 where
-    Renderer: crate::text::Renderer,
-    Renderer::Theme: widget::container::StyleSheet + widget::text::StyleSheet,
+    Renderer: crate::Renderer,
+    Renderer::Theme: widget::container::StyleSheet,
 {
-    widget::Tooltip::new(content, tooltip.to_string(), position)
+    widget::Tooltip::new(content, tooltip, position)
 }
 
 /// Creates a new [`Text`] widget with the provided content.
@@ -220,6 +280,87 @@ where
     widget::PickList::new(options, selected, on_selected)
 }
 
+/// Creates a new [`ComboBox`].
+///
+/// [`ComboBox`]: widget::ComboBox
+pub fn combo_box<'a, Message, Renderer, T>(
+    options: impl Into<Cow<'a, [T]>>,
+    placeholder: &str,
+    selected: Option<T>,
+    on_selected: impl Fn(T) -> Message + 'a,
+) -> widget::ComboBox<'a, T, Message, Renderer>
+where
+    T: ToString + 'static,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Renderer: crate::text::Renderer,
+    Renderer::Theme: widget::text_input::StyleSheet + overlay::menu::StyleSheet,
+{
+    widget::ComboBox::new(options, placeholder, selected, on_selected)
+}
+
+/// Creates a new [`ContextMenu`] for the given content with the given items.
+///
+/// [`ContextMenu`]: widget::ContextMenu
+pub fn context_menu<'a, Message, Renderer>(
+    content: impl Into<Element<'a, Message, Renderer>>,
+    items: Vec<overlay::menu::Item<Message>>,
+) -> widget::ContextMenu<'a, Message, Renderer>
+where
+    Renderer: crate::text::Renderer,
+    Renderer::Theme: overlay::menu::StyleSheet,
+{
+    widget::ContextMenu::new(content, items)
+}
+
+/// Creates a new [`Draggable`] with the given content and [`dnd::Payload`].
+///
+/// [`Draggable`]: widget::Draggable
+pub fn draggable<'a, Message, Renderer>(
+    content: impl Into<Element<'a, Message, Renderer>>,
+    payload: dnd::Payload,
+) -> widget::Draggable<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    widget::Draggable::new(content, payload)
+}
+
+/// Creates a new [`DropZone`] with the given content.
+///
+/// [`DropZone`]: widget::DropZone
+pub fn drop_zone<'a, Message, Renderer>(
+    content: impl Into<Element<'a, Message, Renderer>>,
+) -> widget::DropZone<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    widget::DropZone::new(content)
+}
+
+/// Creates a new empty [`MenuBar`].
+///
+/// [`MenuBar`]: widget::MenuBar
+pub fn menu_bar<Message, Renderer>() -> widget::MenuBar<Message, Renderer>
+where
+    Renderer: crate::text::Renderer,
+    Renderer::Theme: overlay::menu::StyleSheet,
+{
+    widget::MenuBar::new()
+}
+
+/// Creates a new [`Modal`] with the given base content.
+///
+/// [`Modal`]: widget::Modal
+pub fn modal<'a, Message, Renderer>(
+    base: impl Into<Element<'a, Message, Renderer>>,
+) -> widget::Modal<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: widget::modal::StyleSheet,
+{
+    widget::Modal::new(base)
+}
+
 /// Creates a new [`Image`].
 ///
 /// [`Image`]: widget::Image
@@ -0,0 +1,260 @@
+//! Inspect the widget tree of a user interface for debugging purposes.
+use crate::renderer;
+use crate::widget;
+use crate::{Background, Color, Length, Padding, Point, Rectangle, Vector};
+
+use std::any;
+
+/// A widget in the inspected widget tree, along with its layout.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Node {
+    /// The name of the type of the widget, without its path and generics.
+    pub name: &'static str,
+
+    /// The [`widget::Id`] of the widget, if it has one.
+    pub id: Option<widget::Id>,
+
+    /// The layout bounds of the widget.
+    pub bounds: Rectangle,
+
+    /// The width setting of the widget.
+    pub width: Length,
+
+    /// The height setting of the widget.
+    pub height: Length,
+
+    /// The padding between the bounds of the widget and its contents.
+    pub padding: Padding,
+
+    /// The inspected children of the widget.
+    pub children: Vec<Node>,
+}
+
+impl Node {
+    /// Creates a new [`Node`] for a widget of type `W` without children.
+    pub fn new<W: ?Sized>(
+        bounds: Rectangle,
+        width: Length,
+        height: Length,
+    ) -> Self {
+        Self {
+            name: short_name(any::type_name::<W>()),
+            id: None,
+            bounds,
+            width,
+            height,
+            padding: Padding::ZERO,
+            children: Vec::new(),
+        }
+    }
+
+    /// Sets the [`widget::Id`] of the [`Node`].
+    pub fn id(mut self, id: Option<&widget::Id>) -> Self {
+        self.id = id.cloned();
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`Node`].
+    pub fn padding(mut self, padding: Padding) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the children of the [`Node`].
+    pub fn children(
+        mut self,
+        children: impl IntoIterator<Item = Node>,
+    ) -> Self {
+        self.children = children.into_iter().collect();
+        self
+    }
+
+    /// Translates the [`Node`] and all of its children by the given
+    /// [`Vector`].
+    ///
+    /// Widgets that draw their contents with a translation, like a
+    /// [`Scrollable`], use it to place their children where they are drawn.
+    ///
+    /// [`Scrollable`]: crate::widget::Scrollable
+    pub fn translate(mut self, translation: Vector) -> Self {
+        self.bounds = self.bounds + translation;
+        self.children = self
+            .children
+            .into_iter()
+            .map(|child| child.translate(translation))
+            .collect();
+        self
+    }
+
+    /// Returns the innermost [`Node`] containing the given point, if any.
+    ///
+    /// When siblings overlap, the last one wins, since it is drawn on top.
+    pub fn find(&self, point: Point) -> Option<&Node> {
+        if !self.bounds.contains(point) {
+            return None;
+        }
+
+        self.children
+            .iter()
+            .rev()
+            .find_map(|child| child.find(point))
+            .or(Some(self))
+    }
+
+    /// Describes the widget tree of the [`Node`], one widget per line.
+    ///
+    /// The given [`Node`], if any, is marked as selected.
+    pub fn lines(&self, selected: Option<&Node>) -> Vec<String> {
+        let mut lines = Vec::new();
+
+        self.write_lines(0, selected, &mut lines);
+
+        lines
+    }
+
+    fn write_lines(
+        &self,
+        depth: usize,
+        selected: Option<&Node>,
+        lines: &mut Vec<String>,
+    ) {
+        let marker = if selected.is_some_and(|node| std::ptr::eq(node, self)) {
+            ">"
+        } else {
+            " "
+        };
+
+        let mut line = format!(
+            "{} {:indent$}{} {}x{} at ({}, {}) width={:?} height={:?}",
+            marker,
+            "",
+            self.name,
+            self.bounds.width,
+            self.bounds.height,
+            self.bounds.x,
+            self.bounds.y,
+            self.width,
+            self.height,
+            indent = depth * 2,
+        );
+
+        if self.padding != Padding::ZERO {
+            line.push_str(&format!(
+                " padding=[{}, {}, {}, {}]",
+                self.padding.top,
+                self.padding.right,
+                self.padding.bottom,
+                self.padding.left
+            ));
+        }
+
+        if let Some(id) = &self.id {
+            line.push_str(&format!(" id={:?}", id));
+        }
+
+        lines.push(line);
+
+        for child in &self.children {
+            child.write_lines(depth + 1, selected, lines);
+        }
+    }
+}
+
+/// Highlights the bounds and the padding of the given [`Node`].
+pub fn highlight<Renderer>(renderer: &mut Renderer, node: &Node)
+where
+    Renderer: crate::Renderer,
+{
+    const CONTENT: Color = Color::from_rgba(0.3, 0.6, 1.0, 0.3);
+    const PADDING: Color = Color::from_rgba(0.4, 0.9, 0.4, 0.3);
+    const BORDER: Color = Color::from_rgb(0.3, 0.6, 1.0);
+
+    let bounds = node.bounds;
+    let padding = node.padding;
+
+    let top = f32::from(padding.top).min(bounds.height);
+    let bottom = f32::from(padding.bottom).min(bounds.height - top);
+    let left = f32::from(padding.left).min(bounds.width);
+    let right = f32::from(padding.right).min(bounds.width - left);
+
+    let content = Rectangle {
+        x: bounds.x + left,
+        y: bounds.y + top,
+        width: bounds.width - left - right,
+        height: bounds.height - top - bottom,
+    };
+
+    let padding = [
+        Rectangle {
+            height: top,
+            ..bounds
+        },
+        Rectangle {
+            y: bounds.y + bounds.height - bottom,
+            height: bottom,
+            ..bounds
+        },
+        Rectangle {
+            y: content.y,
+            width: left,
+            height: content.height,
+            ..bounds
+        },
+        Rectangle {
+            x: content.x + content.width,
+            y: content.y,
+            width: right,
+            height: content.height,
+        },
+    ];
+
+    // A new layer keeps the highlight on top of the text of the widgets.
+    renderer.with_layer(bounds, |renderer| {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: content,
+                border_radius: 0.0.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            Background::Color(CONTENT),
+        );
+
+        for bounds in padding {
+            if bounds.width > 0.0 && bounds.height > 0.0 {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border_radius: 0.0.into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    Background::Color(PADDING),
+                );
+            }
+        }
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: 0.0.into(),
+                border_width: 1.0,
+                border_color: BORDER,
+            },
+            Background::Color(Color::TRANSPARENT),
+        );
+    });
+}
+
+/// Strips the path and the generic parameters of a type name.
+fn short_name(type_name: &'static str) -> &'static str {
+    let name = match type_name.find('<') {
+        Some(index) => &type_name[..index],
+        None => type_name,
+    };
+
+    match name.rfind("::") {
+        Some(index) => &name[index + 2..],
+        None => name,
+    }
+}
@@ -0,0 +1,401 @@
+//! Show a bar of menus with nested submenus.
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::overlay::menu::{self, Menu};
+use crate::renderer;
+use crate::text::{self, Text};
+use crate::touch;
+use crate::widget::container;
+use crate::widget::inspect;
+use crate::widget::scrollable;
+use crate::widget::tree::{self, Tree};
+use crate::{
+    Clipboard, Color, Element, Layout, Length, Padding, Point, Rectangle,
+    Shell, Size, Widget,
+};
+
+pub use crate::overlay::menu::Item;
+pub use iced_style::menu::{
+    Appearance, BarAppearance, ItemAppearance, StyleSheet,
+};
+
+/// A horizontal bar of titles that open menus of [`Item`]s when clicked.
+///
+/// While a menu is open, hovering another title opens its menu instead.
+#[allow(missing_debug_implementations)]
+pub struct MenuBar<Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    menus: Vec<(String, Vec<Item<Message>>)>,
+    width: Length,
+    padding: Padding,
+    menu_width: u16,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<Message, Renderer> MenuBar<Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new empty [`MenuBar`].
+    pub fn new() -> Self {
+        MenuBar {
+            menus: Vec::new(),
+            width: Length::Fill,
+            padding: Padding::from([4, 8]),
+            menu_width: 0,
+            text_size: None,
+            font: Default::default(),
+            style: Default::default(),
+        }
+    }
+
+    /// Adds a menu with the given title and items to the [`MenuBar`].
+    pub fn push(
+        mut self,
+        title: impl Into<String>,
+        items: Vec<Item<Message>>,
+    ) -> Self {
+        self.menus.push((title.into(), items));
+        self
+    }
+
+    /// Sets the width of the [`MenuBar`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the [`Padding`] of the titles and items of the [`MenuBar`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the minimum width of the menus of the [`MenuBar`].
+    pub fn menu_width(mut self, menu_width: u16) -> Self {
+        self.menu_width = menu_width;
+        self
+    }
+
+    /// Sets the text size of the [`MenuBar`].
+    pub fn text_size(mut self, text_size: u16) -> Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Sets the font of the [`MenuBar`].
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`MenuBar`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<Message, Renderer> Default for MenuBar<Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A title of a [`MenuBar`], as shown by the inspector.
+struct Title;
+
+/// The local state of a [`MenuBar`].
+#[derive(Debug)]
+struct State<Message> {
+    active: Option<usize>,
+    menu: menu::State,
+    last_selection: Option<Message>,
+}
+
+impl<Message> State<Message> {
+    fn open_menu(&self) -> Option<usize> {
+        self.active.filter(|_| self.menu.is_open())
+    }
+}
+
+impl<Message> Default for State<Message> {
+    fn default() -> Self {
+        Self {
+            active: None,
+            menu: menu::State::default(),
+            last_selection: None,
+        }
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for MenuBar<Message, Renderer>
+where
+    Message: Clone + 'static,
+    Renderer: text::Renderer,
+    Renderer::Theme:
+        StyleSheet + container::StyleSheet + scrollable::StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Message>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Message>::default())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(Length::Shrink);
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+        let height = f32::from(text_size + self.padding.vertical());
+
+        let mut x = 0.0;
+
+        let titles = self
+            .menus
+            .iter()
+            .map(|(title, _)| {
+                let width =
+                    renderer.measure_width(title, text_size, self.font.clone())
+                        + f32::from(self.padding.horizontal());
+
+                let mut node = layout::Node::new(Size::new(width, height));
+                node.move_to(Point::new(x, 0.0));
+
+                x += width;
+
+                node
+            })
+            .collect();
+
+        layout::Node::with_children(
+            limits.resolve(Size::new(x, height)),
+            titles,
+        )
+    }
+
+    fn inspect(&self, _tree: &Tree, layout: Layout<'_>) -> inspect::Node {
+        inspect::Node::new::<Self>(layout.bounds(), self.width, Length::Shrink)
+            .children(layout.children().map(|layout| {
+                inspect::Node::new::<Title>(
+                    layout.bounds(),
+                    Length::Shrink,
+                    Length::Shrink,
+                )
+                .padding(self.padding)
+            }))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<Message>>();
+
+        // The menu ignores the event that selects an item, so the selection
+        // can be published here.
+        if let Some(message) = state.last_selection.take() {
+            shell.publish(message);
+
+            return event::Status::Captured;
+        }
+
+        let hovered = layout
+            .children()
+            .position(|title| title.bounds().contains(cursor_position));
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(index) = hovered {
+                    if state.open_menu() == Some(index) {
+                        state.menu.close();
+                    } else {
+                        state.active = Some(index);
+                        state.menu.open();
+                    }
+
+                    return event::Status::Captured;
+                } else if state.menu.is_open() {
+                    state.menu.close();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+                if state.menu.is_open() =>
+            {
+                state.menu.close();
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if let (Some(index), Some(open)) = (hovered, state.open_menu())
+                {
+                    if index != open {
+                        state.active = Some(index);
+                        state.menu.open();
+
+                        shell.invalidate_layout();
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if layout
+            .children()
+            .any(|title| title.bounds().contains(cursor_position))
+        {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Message>>();
+        let appearance = theme.bar(&self.style);
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        if let Some(background) = appearance.background {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: layout.bounds(),
+                    border_radius: 0.0.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                background,
+            );
+        }
+
+        for (index, ((title, _), layout)) in
+            self.menus.iter().zip(layout.children()).enumerate()
+        {
+            let bounds = layout.bounds();
+            let is_open = state.open_menu() == Some(index);
+
+            if is_open {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border_radius: 0.0.into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    appearance.selected_background,
+                );
+            }
+
+            renderer.fill_text(Text {
+                content: title,
+                bounds: Rectangle {
+                    x: bounds.center_x(),
+                    y: bounds.center_y(),
+                    ..bounds
+                },
+                size: f32::from(text_size),
+                font: self.font.clone(),
+                color: if is_open {
+                    appearance.selected_text_color
+                } else {
+                    appearance.text_color
+                },
+                horizontal_alignment: alignment::Horizontal::Center,
+                vertical_alignment: alignment::Vertical::Center,
+            });
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let state = tree.state.downcast_mut::<State<Message>>();
+        let index = state.open_menu()?;
+        let (_, items) = self.menus.get(index)?;
+        let title = layout.children().nth(index)?.bounds();
+
+        let mut menu =
+            Menu::with_items(&mut state.menu, items, &mut state.last_selection)
+                .width(self.menu_width)
+                .padding(self.padding)
+                .font(self.font.clone())
+                .style(self.style.clone());
+
+        if let Some(text_size) = self.text_size {
+            menu = menu.text_size(text_size);
+        }
+
+        Some(menu.overlay(title.position(), title.height))
+    }
+}
+
+impl<'a, Message, Renderer> From<MenuBar<Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: Clone + 'static,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme:
+        StyleSheet + container::StyleSheet + scrollable::StyleSheet,
+{
+    fn from(menu_bar: MenuBar<Message, Renderer>) -> Self {
+        Self::new(menu_bar)
+    }
+}
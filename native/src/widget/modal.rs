@@ -0,0 +1,564 @@
+//! Show dialogs on top of some content.
+use crate::alignment::Alignment;
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::touch;
+use crate::widget::inspect;
+use crate::widget::tree::Tree;
+use crate::widget::Operation;
+use crate::{
+    Clipboard, Color, Element, Layout, Length, Point, Rectangle, Shell, Size,
+    Widget,
+};
+
+pub use iced_style::modal::{Appearance, StyleSheet};
+
+/// A widget that shows a stack of dialogs on top of some base content.
+///
+/// Every dialog is centered over a backdrop that covers the whole window.
+/// Only the topmost dialog receives events and traps the focus traversal;
+/// the base content and the dialogs below it are blocked until it is closed.
+#[allow(missing_debug_implementations)]
+pub struct Modal<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    base: Element<'a, Message, Renderer>,
+    dialogs: Vec<Element<'a, Message, Renderer>>,
+    on_close: Option<Message>,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Modal<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`Modal`] with the given base content and no dialogs.
+    pub fn new(base: impl Into<Element<'a, Message, Renderer>>) -> Self {
+        Modal {
+            base: base.into(),
+            dialogs: Vec::new(),
+            on_close: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Shows a dialog on top of the base content and any previously pushed
+    /// dialogs of the [`Modal`].
+    pub fn push(
+        mut self,
+        dialog: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        self.dialogs.push(dialog.into());
+        self
+    }
+
+    /// Sets the message that will be produced when the topmost dialog of the
+    /// [`Modal`] is dismissed, either by pressing `Escape` or by clicking its
+    /// backdrop.
+    pub fn on_close(mut self, message: Message) -> Self {
+        self.on_close = Some(message);
+        self
+    }
+
+    /// Sets the style of the [`Modal`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Modal<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn children(&self) -> Vec<Tree> {
+        std::iter::once(&self.base)
+            .chain(&self.dialogs)
+            .map(Tree::new)
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let children: Vec<_> =
+            std::iter::once(&self.base).chain(&self.dialogs).collect();
+
+        tree.diff_children(&children);
+    }
+
+    fn width(&self) -> Length {
+        self.base.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.base.as_widget().height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.base.as_widget().layout(renderer, limits)
+    }
+
+    fn inspect(&self, tree: &Tree, layout: Layout<'_>) -> inspect::Node {
+        inspect::Node::new::<Self>(
+            layout.bounds(),
+            self.base.as_widget().width(),
+            self.base.as_widget().height(),
+        )
+        .children([self.base.as_widget().inspect(&tree.children[0], layout)])
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        // The dialogs are operated through the overlay, which traps the
+        // focus traversal in the topmost one.
+        self.base
+            .as_widget()
+            .operate(&mut tree.children[0], layout, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if !self.dialogs.is_empty() && !matches!(event, Event::Window(..)) {
+            return event::Status::Ignored;
+        }
+
+        self.base.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if !self.dialogs.is_empty() {
+            return mouse::Interaction::default();
+        }
+
+        self.base.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        self.base.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor_position,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let (base, dialogs) = tree.children.split_first_mut()?;
+
+        if self.dialogs.is_empty() {
+            return self.base.as_widget_mut().overlay(base, layout, renderer);
+        }
+
+        Some(overlay::Element::new(
+            Point::ORIGIN,
+            Box::new(Overlay {
+                dialogs: &mut self.dialogs,
+                trees: dialogs,
+                on_close: self.on_close.clone(),
+                style: &self.style,
+            }),
+        ))
+    }
+}
+
+struct Overlay<'a, 'b, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    dialogs: &'b mut [Element<'a, Message, Renderer>],
+    trees: &'b mut [Tree],
+    on_close: Option<Message>,
+    style: &'b <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, 'b, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for Overlay<'a, 'b, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let limits = layout::Limits::new(Size::ZERO, bounds);
+
+        let dialogs = self
+            .dialogs
+            .iter()
+            .map(|dialog| {
+                let mut node = dialog.as_widget().layout(renderer, &limits);
+                node.align(Alignment::Center, Alignment::Center, bounds);
+
+                node
+            })
+            .collect();
+
+        let mut node = layout::Node::with_children(bounds, dialogs);
+        node.move_to(position);
+
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let (dialog, tree, dialog_layout) = match (
+            self.dialogs.last_mut(),
+            self.trees.last_mut(),
+            layout.children().last(),
+        ) {
+            (Some(dialog), Some(tree), Some(layout)) => (dialog, tree, layout),
+            _ => return event::Status::Ignored,
+        };
+
+        match &event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Escape,
+                ..
+            }) => {
+                if let Some(on_close) = &self.on_close {
+                    shell.publish(on_close.clone());
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if !dialog_layout.bounds().contains(cursor_position) =>
+            {
+                if let Some(on_close) = &self.on_close {
+                    shell.publish(on_close.clone());
+                }
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        let status = dialog.as_widget_mut().on_event(
+            tree,
+            event.clone(),
+            dialog_layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        );
+
+        // Nothing below the topmost dialog may react to user input.
+        match event {
+            Event::Window(..) => status,
+            _ => event::Status::Captured,
+        }
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let top = self.dialogs.len().saturating_sub(1);
+
+        for (index, ((dialog, tree), layout)) in self
+            .dialogs
+            .iter()
+            .zip(self.trees.iter_mut())
+            .zip(layout.children())
+            .enumerate()
+        {
+            if index == top {
+                operation.focus_trap(None, layout.bounds(), &mut |operation| {
+                    dialog.as_widget().operate(tree, layout, operation);
+                });
+            } else {
+                dialog.as_widget().operate(tree, layout, operation);
+            }
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        match (
+            self.dialogs.last(),
+            self.trees.last(),
+            layout.children().last(),
+        ) {
+            (Some(dialog), Some(tree), Some(layout)) => {
+                dialog.as_widget().mouse_interaction(
+                    tree,
+                    layout,
+                    cursor_position,
+                    viewport,
+                    renderer,
+                )
+            }
+            _ => mouse::Interaction::default(),
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) {
+        let appearance = theme.appearance(self.style);
+        let bounds = layout.bounds();
+        let top = self.dialogs.len().saturating_sub(1);
+
+        for (index, ((dialog, tree), layout)) in self
+            .dialogs
+            .iter()
+            .zip(self.trees.iter())
+            .zip(layout.children())
+            .enumerate()
+        {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: 0.0.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                appearance.backdrop,
+            );
+
+            // Dialogs below the topmost one are drawn as if the cursor was
+            // outside of them, so they do not show hover effects.
+            let cursor_position = if index == top {
+                cursor_position
+            } else {
+                Point::new(-1.0, -1.0)
+            };
+
+            renderer.with_layer(bounds, |renderer| {
+                dialog.as_widget().draw(
+                    tree,
+                    renderer,
+                    theme,
+                    style,
+                    layout,
+                    cursor_position,
+                    &bounds,
+                );
+            });
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<Modal<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: crate::Renderer + 'a,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(modal: Modal<'a, Message, Renderer>) -> Self {
+        Self::new(modal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Simulator;
+    use crate::widget::operation::focusable::{self, Focusable};
+    use crate::widget::{self, text_input, Column, Container, TextInput};
+
+    fn input(id: &'static str) -> TextInput<'static, (), renderer::Null> {
+        TextInput::new("", "", |_| ()).id(text_input::Id::new(id))
+    }
+
+    fn modal() -> Modal<'static, (), renderer::Null> {
+        Modal::new(Column::new().push(input("base")))
+            .push(Column::new().push(input("first")).push(input("second")))
+    }
+
+    fn focused(
+        simulator: &mut Simulator<'_, (), renderer::Null>,
+    ) -> Option<widget::Id> {
+        struct Focused(Option<widget::Id>);
+
+        impl Operation<()> for Focused {
+            fn focusable(
+                &mut self,
+                state: &mut dyn Focusable,
+                id: Option<&widget::Id>,
+            ) {
+                if state.is_focused() {
+                    self.0 = id.cloned();
+                }
+            }
+
+            fn container(
+                &mut self,
+                _id: Option<&widget::Id>,
+                _bounds: Rectangle,
+                operate_on_children: &mut dyn FnMut(&mut dyn Operation<()>),
+            ) {
+                operate_on_children(self)
+            }
+        }
+
+        let mut operation = Focused(None);
+        simulator.operate(&mut operation);
+
+        operation.0
+    }
+
+    #[test]
+    fn operations_reach_the_base_content() {
+        let mut simulator =
+            Simulator::with_size(modal(), Size::new(400.0, 400.0));
+
+        simulator.focus(widget::Id::new("base"));
+
+        assert_eq!(focused(&mut simulator), Some(widget::Id::new("base")));
+    }
+
+    #[test]
+    fn focus_traversal_is_trapped_in_the_topmost_dialog() {
+        let mut simulator =
+            Simulator::with_size(modal(), Size::new(400.0, 400.0));
+
+        simulator.focus(widget::Id::new("base"));
+
+        let mut traversed = Vec::new();
+
+        for _ in 0..3 {
+            simulator.operate(&mut focusable::focus_next());
+            traversed.extend(focused(&mut simulator));
+        }
+
+        assert_eq!(
+            traversed,
+            ["first", "second", "first"].map(widget::Id::new)
+        );
+
+        simulator.operate(&mut focusable::focus_previous());
+
+        assert_eq!(focused(&mut simulator), Some(widget::Id::new("second")));
+    }
+
+    #[test]
+    fn focus_traversal_leaves_a_scope_in_the_base_for_the_dialog() {
+        let modal = Modal::new(
+            Container::new(
+                Column::new().push(input("base")).push(input("other")),
+            )
+            .focus_scope(),
+        )
+        .push(Column::new().push(input("first")).push(input("second")));
+
+        let mut simulator =
+            Simulator::with_size(modal, Size::new(400.0, 400.0));
+
+        simulator.focus(widget::Id::new("base"));
+        simulator.operate(&mut focusable::focus_next());
+
+        assert_eq!(focused(&mut simulator), Some(widget::Id::new("first")));
+
+        simulator.focus(widget::Id::new("other"));
+        simulator.operate(&mut focusable::focus_previous());
+
+        assert_eq!(focused(&mut simulator), Some(widget::Id::new("second")));
+    }
+
+    #[test]
+    fn clicking_outside_or_pressing_escape_closes_the_dialog() {
+        let mut simulator =
+            Simulator::with_size(modal().on_close(()), Size::new(400.0, 400.0));
+
+        let _ = simulator.click(widget::Id::new("first")).unwrap();
+        let _ = simulator.click(widget::Id::new("base")).unwrap();
+        let _ = simulator.tap_key(keyboard::KeyCode::Escape);
+
+        // The base never receives the click
+        assert_eq!(simulator.messages().count(), 2);
+        assert_eq!(focused(&mut simulator), Some(widget::Id::new("first")));
+    }
+}
@@ -1,6 +1,7 @@
 //! Operate on widgets that can be focused.
 use crate::widget::operation::{Operation, Outcome};
 use crate::widget::Id;
+use crate::Rectangle;
 
 /// The internal state of a widget that can be focused.
 pub trait Focusable {
@@ -22,6 +23,77 @@ pub struct Count {
 
     /// The total amount of focusable widgets.
     total: usize,
+
+    /// The range of focusable widgets in the innermost focus scope that
+    /// contains the focused widget, if any.
+    scope: Option<(usize, usize)>,
+
+    /// The range of focusable widgets in the last focus trap, if any.
+    trap: Option<(usize, usize)>,
+}
+
+impl Count {
+    /// Returns the range of focusable widgets the focus traversal is
+    /// confined to, if any.
+    ///
+    /// A focus trap takes precedence over any focus scope outside of it.
+    fn range(&self) -> Option<(usize, usize)> {
+        match (self.scope, self.trap) {
+            (Some((start, end)), Some((trap_start, trap_end)))
+                if start >= trap_start && end <= trap_end =>
+            {
+                Some((start, end))
+            }
+            (_, Some(trap)) => Some(trap),
+            (scope, None) => scope,
+        }
+    }
+
+    /// Returns the index of the focusable widget that follows the focused
+    /// one, wrapping around its focus scope.
+    ///
+    /// If a focus trap is present and the focused widget is outside of it,
+    /// the first widget of the trap follows.
+    fn next(&self) -> Option<usize> {
+        match (self.focused, self.range()) {
+            (Some(focused), Some((start, end)))
+                if (start..end).contains(&focused) =>
+            {
+                Some(if focused + 1 < end {
+                    focused + 1
+                } else {
+                    start
+                })
+            }
+            (_, Some((start, end))) => (start < end).then_some(start),
+            (Some(focused), None) => Some(focused + 1),
+            (None, None) => Some(0),
+        }
+    }
+
+    /// Returns the index of the focusable widget that precedes the focused
+    /// one, wrapping around its focus scope.
+    ///
+    /// If a focus trap is present and the focused widget is outside of it,
+    /// the last widget of the trap precedes.
+    fn previous(&self) -> Option<usize> {
+        match (self.focused, self.range()) {
+            (Some(focused), Some((start, end)))
+                if (start..end).contains(&focused) =>
+            {
+                Some(if focused > start {
+                    focused - 1
+                } else {
+                    end - 1
+                })
+            }
+            (_, Some((start, end))) => {
+                end.checked_sub(1).filter(|last| *last >= start)
+            }
+            (Some(focused), None) => focused.checked_sub(1),
+            (None, None) => self.total.checked_sub(1),
+        }
+    }
 }
 
 /// Produces an [`Operation`] that focuses the widget with the given [`Id`].
@@ -45,6 +117,7 @@ pub fn focus<T>(target: Id) -> impl Operation<T> {
         fn container(
             &mut self,
             _id: Option<&Id>,
+            _bounds: Rectangle,
             operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
         ) {
             operate_on_children(self)
@@ -80,11 +153,48 @@ where
         fn container(
             &mut self,
             _id: Option<&Id>,
+            _bounds: Rectangle,
             operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
         ) {
             operate_on_children(self)
         }
 
+        fn focus_scope(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            let start = self.count.total;
+
+            operate_on_children(self);
+
+            let end = self.count.total;
+
+            // Inner scopes finish first, so the innermost one wins
+            if self.count.scope.is_none()
+                && matches!(
+                    self.count.focused,
+                    Some(focused) if (start..end).contains(&focused)
+                )
+            {
+                self.count.scope = Some((start, end));
+            }
+        }
+
+        fn focus_trap(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            let start = self.count.total;
+
+            self.focus_scope(id, bounds, operate_on_children);
+
+            self.count.trap = Some((start, self.count.total));
+        }
+
         fn finish(&self) -> Outcome<T> {
             Outcome::Chain(Box::new((self.next)(self.count)))
         }
@@ -99,6 +209,8 @@ where
 /// Produces an [`Operation`] that searches for the current focused widget, and
 /// - if found, focuses the previous focusable widget.
 /// - if not found, focuses the last focusable widget.
+///
+/// The focus wraps around the innermost focus scope of the focused widget.
 pub fn focus_previous<T>() -> impl Operation<T> {
     struct FocusPrevious {
         count: Count,
@@ -107,17 +219,10 @@ pub fn focus_previous<T>() -> impl Operation<T> {
 
     impl<T> Operation<T> for FocusPrevious {
         fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
-            if self.count.total == 0 {
-                return;
-            }
-
-            match self.count.focused {
-                None if self.current == self.count.total - 1 => state.focus(),
-                Some(0) if self.current == 0 => state.unfocus(),
-                Some(0) => {}
-                Some(focused) if focused == self.current => state.unfocus(),
-                Some(focused) if focused - 1 == self.current => state.focus(),
-                _ => {}
+            if self.count.previous() == Some(self.current) {
+                state.focus();
+            } else if self.count.focused == Some(self.current) {
+                state.unfocus();
             }
 
             self.current += 1;
@@ -126,6 +231,7 @@ pub fn focus_previous<T>() -> impl Operation<T> {
         fn container(
             &mut self,
             _id: Option<&Id>,
+            _bounds: Rectangle,
             operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
         ) {
             operate_on_children(self)
@@ -138,6 +244,8 @@ pub fn focus_previous<T>() -> impl Operation<T> {
 /// Produces an [`Operation`] that searches for the current focused widget, and
 /// - if found, focuses the next focusable widget.
 /// - if not found, focuses the first focusable widget.
+///
+/// The focus wraps around the innermost focus scope of the focused widget.
 pub fn focus_next<T>() -> impl Operation<T> {
     struct FocusNext {
         count: Count,
@@ -146,11 +254,10 @@ pub fn focus_next<T>() -> impl Operation<T> {
 
     impl<T> Operation<T> for FocusNext {
         fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
-            match self.count.focused {
-                None if self.current == 0 => state.focus(),
-                Some(focused) if focused == self.current => state.unfocus(),
-                Some(focused) if focused + 1 == self.current => state.focus(),
-                _ => {}
+            if self.count.next() == Some(self.current) {
+                state.focus();
+            } else if self.count.focused == Some(self.current) {
+                state.unfocus();
             }
 
             self.current += 1;
@@ -159,6 +266,7 @@ pub fn focus_next<T>() -> impl Operation<T> {
         fn container(
             &mut self,
             _id: Option<&Id>,
+            _bounds: Rectangle,
             operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
         ) {
             operate_on_children(self)
@@ -185,6 +293,7 @@ pub fn find_focused() -> impl Operation<Id> {
         fn container(
             &mut self,
             _id: Option<&Id>,
+            _bounds: Rectangle,
             operate_on_children: &mut dyn FnMut(&mut dyn Operation<Id>),
         ) {
             operate_on_children(self)
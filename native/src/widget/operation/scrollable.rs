@@ -1,10 +1,34 @@
 //! Operate on widgets that can be scrolled.
+use crate::widget::operation::Outcome;
 use crate::widget::{Id, Operation};
+use crate::Rectangle;
 
 /// The internal state of a widget that can be scrolled.
 pub trait Scrollable {
     /// Snaps the scroll of the widget to the given `percentage`.
     fn snap_to(&mut self, percentage: f32);
+
+    /// Scrolls the widget to the given absolute `offset`, in pixels.
+    fn scroll_to(&mut self, offset: f32);
+
+    /// Scrolls the widget by the given `delta`, in pixels, given its bounds
+    /// and the bounds of its contents.
+    ///
+    /// A positive `delta` moves the viewport towards the end of the contents.
+    fn scroll_by(
+        &mut self,
+        delta: f32,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    );
+
+    /// Returns the current absolute offset of the widget, in pixels, given its
+    /// bounds and the bounds of its contents.
+    fn absolute_offset(
+        &self,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> f32;
 }
 
 /// Produces an [`Operation`] that snaps the widget with the given [`Id`] to
@@ -16,7 +40,13 @@ pub fn snap_to<T>(target: Id, percentage: f32) -> impl Operation<T> {
     }
 
     impl<T> Operation<T> for SnapTo {
-        fn scrollable(&mut self, state: &mut dyn Scrollable, id: Option<&Id>) {
+        fn scrollable(
+            &mut self,
+            state: &mut dyn Scrollable,
+            id: Option<&Id>,
+            _bounds: Rectangle,
+            _content_bounds: Rectangle,
+        ) {
             if Some(&self.target) == id {
                 state.snap_to(self.percentage);
             }
@@ -25,6 +55,7 @@ pub fn snap_to<T>(target: Id, percentage: f32) -> impl Operation<T> {
         fn container(
             &mut self,
             _id: Option<&Id>,
+            _bounds: Rectangle,
             operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
         ) {
             operate_on_children(self)
@@ -33,3 +64,370 @@ pub fn snap_to<T>(target: Id, percentage: f32) -> impl Operation<T> {
 
     SnapTo { target, percentage }
 }
+
+/// Produces an [`Operation`] that scrolls the widget with the given [`Id`] to
+/// the provided absolute `offset`.
+pub fn scroll_to<T>(target: Id, offset: f32) -> impl Operation<T> {
+    struct ScrollTo {
+        target: Id,
+        offset: f32,
+    }
+
+    impl<T> Operation<T> for ScrollTo {
+        fn scrollable(
+            &mut self,
+            state: &mut dyn Scrollable,
+            id: Option<&Id>,
+            _bounds: Rectangle,
+            _content_bounds: Rectangle,
+        ) {
+            if Some(&self.target) == id {
+                state.scroll_to(self.offset);
+            }
+        }
+
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self)
+        }
+    }
+
+    ScrollTo { target, offset }
+}
+
+/// Produces an [`Operation`] that scrolls the widget with the given [`Id`] by
+/// the provided `delta`.
+pub fn scroll_by<T>(target: Id, delta: f32) -> impl Operation<T> {
+    struct ScrollBy {
+        target: Id,
+        delta: f32,
+    }
+
+    impl<T> Operation<T> for ScrollBy {
+        fn scrollable(
+            &mut self,
+            state: &mut dyn Scrollable,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            content_bounds: Rectangle,
+        ) {
+            if Some(&self.target) == id {
+                state.scroll_by(self.delta, bounds, content_bounds);
+            }
+        }
+
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self)
+        }
+    }
+
+    ScrollBy { target, delta }
+}
+
+/// Produces an [`Operation`] that scrolls the nearest scrollable ancestor of
+/// the widget with the given [`Id`] by the minimum amount needed to make the
+/// widget visible.
+///
+/// If the widget is taller than its scrollable ancestor, the ancestor is
+/// scrolled to the top of the widget.
+///
+/// Focusable widgets, like a [`TextInput`], are found by the bounds they
+/// report along with their text.
+///
+/// [`TextInput`]: crate::widget::TextInput
+pub fn scroll_into_view<T>(target: Id) -> impl Operation<T> {
+    #[derive(Debug, Clone, Copy)]
+    struct Ancestor {
+        index: usize,
+        offset: f32,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    }
+
+    struct FindTarget {
+        target: Id,
+        scrollables: usize,
+        ancestors: Vec<Ancestor>,
+        pending: Option<Ancestor>,
+        found: Option<(Ancestor, Rectangle)>,
+    }
+
+    impl FindTarget {
+        fn visit(&mut self, id: Option<&Id>, bounds: Rectangle) {
+            if self.found.is_some() || id != Some(&self.target) {
+                return;
+            }
+
+            if let Some(ancestor) = self.ancestors.last() {
+                self.found = Some((*ancestor, bounds));
+            }
+        }
+    }
+
+    impl<T> Operation<T> for FindTarget {
+        fn scrollable(
+            &mut self,
+            state: &mut dyn Scrollable,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            content_bounds: Rectangle,
+        ) {
+            self.visit(id, bounds);
+
+            self.pending = Some(Ancestor {
+                index: self.scrollables,
+                offset: state.absolute_offset(bounds, content_bounds),
+                bounds,
+                content_bounds,
+            });
+
+            self.scrollables += 1;
+        }
+
+        fn container(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            // A scrollable operates on its contents right after reporting
+            // itself, so the pending ancestor owns the children visited here.
+            let ancestor = self.pending.take();
+
+            self.visit(id, bounds);
+
+            if let Some(ancestor) = ancestor {
+                self.ancestors.push(ancestor);
+            }
+
+            operate_on_children(self);
+
+            if ancestor.is_some() {
+                let _ = self.ancestors.pop();
+            }
+        }
+
+        fn text(&mut self, id: Option<&Id>, bounds: Rectangle, _text: &str) {
+            self.visit(id, bounds);
+        }
+
+        fn finish(&self) -> Outcome<T> {
+            match self.found {
+                Some((ancestor, target)) => {
+                    let top = target.y - ancestor.content_bounds.y;
+                    let bottom = top + target.height;
+
+                    let offset = if top < ancestor.offset
+                        || target.height > ancestor.bounds.height
+                    {
+                        top
+                    } else if bottom > ancestor.offset + ancestor.bounds.height
+                    {
+                        bottom - ancestor.bounds.height
+                    } else {
+                        return Outcome::None;
+                    };
+
+                    Outcome::Chain(Box::new(ScrollNth {
+                        index: ancestor.index,
+                        current: 0,
+                        offset,
+                    }))
+                }
+                None => Outcome::None,
+            }
+        }
+    }
+
+    struct ScrollNth {
+        index: usize,
+        current: usize,
+        offset: f32,
+    }
+
+    impl<T> Operation<T> for ScrollNth {
+        fn scrollable(
+            &mut self,
+            state: &mut dyn Scrollable,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            _content_bounds: Rectangle,
+        ) {
+            if self.current == self.index {
+                state.scroll_to(self.offset);
+            }
+
+            self.current += 1;
+        }
+
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self)
+        }
+    }
+
+    FindTarget {
+        target,
+        scrollables: 0,
+        ancestors: Vec::new(),
+        pending: None,
+        found: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer;
+    use crate::testing::Simulator;
+    use crate::widget::{
+        container, scrollable, text_input, Column, Container, Space, TextInput,
+    };
+    use crate::{Element, Length, Size};
+
+    fn offsets(
+        simulator: &mut Simulator<'_, (), renderer::Null>,
+    ) -> Vec<(Option<Id>, f32)> {
+        struct Offsets(Vec<(Option<Id>, f32)>);
+
+        impl Operation<()> for Offsets {
+            fn scrollable(
+                &mut self,
+                state: &mut dyn Scrollable,
+                id: Option<&Id>,
+                bounds: Rectangle,
+                content_bounds: Rectangle,
+            ) {
+                self.0.push((
+                    id.cloned(),
+                    state.absolute_offset(bounds, content_bounds),
+                ));
+            }
+
+            fn container(
+                &mut self,
+                _id: Option<&Id>,
+                _bounds: Rectangle,
+                operate_on_children: &mut dyn FnMut(&mut dyn Operation<()>),
+            ) {
+                operate_on_children(self)
+            }
+        }
+
+        let mut operation = Offsets(Vec::new());
+        simulator.operate(&mut operation);
+
+        operation.0
+    }
+
+    fn row(
+        id: &'static str,
+        height: u16,
+    ) -> Element<'static, (), renderer::Null> {
+        Container::new(Space::with_height(Length::Units(height)))
+            .id(container::Id::new(id))
+            .into()
+    }
+
+    fn nested() -> Simulator<'static, (), renderer::Null> {
+        let inner = scrollable::Scrollable::new(Column::with_children(
+            ["row-0", "row-1", "row-2", "row-3", "row-4", "row-5"]
+                .into_iter()
+                .map(|id| row(id, 40))
+                .collect(),
+        ))
+        .id(scrollable::Id::new("inner"))
+        .height(Length::Units(100));
+
+        let outer = scrollable::Scrollable::new(
+            Column::new()
+                .push(Space::with_height(Length::Units(50)))
+                .push(inner)
+                .push(Space::with_height(Length::Units(400)))
+                .push(row("outer-target", 20)),
+        )
+        .id(scrollable::Id::new("outer"))
+        .height(Length::Fill);
+
+        Simulator::with_size(outer, Size::new(300.0, 200.0))
+    }
+
+    #[test]
+    fn scroll_into_view_scrolls_the_nearest_scrollable() {
+        let mut simulator = nested();
+
+        simulator.operate(&mut scroll_into_view(Id::new("row-5")));
+
+        assert_eq!(
+            offsets(&mut simulator),
+            vec![
+                (Some(Id::new("outer")), 0.0),
+                (Some(Id::new("inner")), 140.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn scroll_into_view_skips_inner_scrollables() {
+        let mut simulator = nested();
+
+        simulator.operate(&mut scroll_into_view(Id::new("outer-target")));
+
+        assert_eq!(
+            offsets(&mut simulator),
+            vec![
+                (Some(Id::new("outer")), 370.0),
+                (Some(Id::new("inner")), 0.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn scroll_into_view_ignores_visible_targets() {
+        let mut simulator = nested();
+
+        simulator.operate(&mut scroll_into_view(Id::new("row-1")));
+
+        assert_eq!(
+            offsets(&mut simulator),
+            vec![(Some(Id::new("outer")), 0.0), (Some(Id::new("inner")), 0.0)]
+        );
+    }
+
+    #[test]
+    fn scroll_into_view_reaches_text_inputs() {
+        let content = scrollable::Scrollable::new(
+            Column::new()
+                .push(Space::with_height(Length::Units(300)))
+                .push(
+                    TextInput::new("Name", "", |_| ())
+                        .id(text_input::Id::new("input")),
+                ),
+        )
+        .id(scrollable::Id::new("outer"))
+        .height(Length::Fill);
+
+        let mut simulator =
+            Simulator::with_size(content, Size::new(300.0, 200.0));
+
+        simulator.operate(&mut scroll_into_view(Id::new("input")));
+
+        assert_eq!(
+            offsets(&mut simulator),
+            vec![(Some(Id::new("outer")), 130.0)]
+        );
+    }
+}
@@ -17,6 +17,7 @@ mod pane;
 mod split;
 mod title_bar;
 
+pub(crate) mod drag;
 pub mod state;
 
 pub use axis::Axis;
@@ -40,6 +41,7 @@ use crate::renderer;
 use crate::touch;
 use crate::widget;
 use crate::widget::container;
+use crate::widget::inspect;
 use crate::widget::tree::{self, Tree};
 use crate::{
     Clipboard, Color, Element, Layout, Length, Point, Rectangle, Shell, Size,
@@ -290,13 +292,26 @@ where
         )
     }
 
+    fn inspect(&self, tree: &Tree, layout: Layout<'_>) -> inspect::Node {
+        inspect::Node::new::<Self>(layout.bounds(), self.width, self.height)
+            .children(
+                self.contents
+                    .iter()
+                    .zip(&tree.children)
+                    .zip(layout.children())
+                    .map(|(((_pane, content), state), layout)| {
+                        content.inspect(state, layout)
+                    }),
+            )
+    }
+
     fn operate(
         &self,
         tree: &mut Tree,
         layout: Layout<'_>,
         operation: &mut dyn widget::Operation<Message>,
     ) {
-        operation.container(None, &mut |operation| {
+        operation.container(None, layout.bounds(), &mut |operation| {
             self.contents
                 .iter()
                 .zip(&mut tree.children)
@@ -588,17 +603,15 @@ pub fn update<'a, Message, T: Draggable>(
         | Event::Touch(touch::Event::FingerLost { .. }) => {
             if let Some((pane, _)) = action.picked_pane() {
                 if let Some(on_drag) = on_drag {
-                    let mut dropped_region = contents
-                        .zip(layout.children())
-                        .filter(|(_, layout)| {
-                            layout.bounds().contains(cursor_position)
-                        });
-
-                    let event = match dropped_region.next() {
-                        Some(((target, _), _)) if pane != target => {
-                            DragEvent::Dropped { pane, target }
-                        }
-                        _ => DragEvent::Canceled { pane },
+                    let target = drag::target(
+                        &pane,
+                        contents.map(|(pane, _)| pane).zip(layout.children()),
+                        cursor_position,
+                    );
+
+                    let event = match target {
+                        Some(target) => DragEvent::Dropped { pane, target },
+                        None => DragEvent::Canceled { pane },
                     };
 
                     shell.publish(on_drag(event));
@@ -664,21 +677,17 @@ fn click_pane<'a, Message, T>(
 ) where
     T: Draggable,
 {
-    let mut clicked_region = contents
-        .zip(layout.children())
-        .filter(|(_, layout)| layout.bounds().contains(cursor_position));
+    let clicked_region =
+        drag::hovered(contents.zip(layout.children()), cursor_position);
 
-    if let Some(((pane, content), layout)) = clicked_region.next() {
+    if let Some(((pane, content), layout)) = clicked_region {
         if let Some(on_click) = &on_click {
             shell.publish(on_click(pane));
         }
 
         if let Some(on_drag) = &on_drag {
             if content.can_be_dragged_at(layout, cursor_position) {
-                let pane_position = layout.position();
-
-                let origin = cursor_position
-                    - Vector::new(pane_position.x, pane_position.y);
+                let origin = drag::origin(layout, cursor_position);
 
                 *action = state::Action::Dragging { pane, origin };
 
@@ -828,22 +837,20 @@ pub fn draw<Renderer, T>(
 
     // Render picked pane last
     if let Some((pane, origin, layout)) = render_picked_pane {
-        let bounds = layout.bounds();
-
-        renderer.with_translation(
-            cursor_position
-                - Point::new(bounds.x + origin.x, bounds.y + origin.y),
+        drag::draw_picked(
+            renderer,
+            layout.bounds(),
+            origin,
+            cursor_position,
             |renderer| {
-                renderer.with_layer(bounds, |renderer| {
-                    draw_pane(
-                        pane,
-                        renderer,
-                        default_style,
-                        layout,
-                        pane_cursor_position,
-                        viewport,
-                    );
-                });
+                draw_pane(
+                    pane,
+                    renderer,
+                    default_style,
+                    layout,
+                    pane_cursor_position,
+                    viewport,
+                );
             },
         );
     };
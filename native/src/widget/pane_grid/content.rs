@@ -4,9 +4,12 @@ use crate::mouse;
 use crate::overlay;
 use crate::renderer;
 use crate::widget::container;
+use crate::widget::inspect;
 use crate::widget::pane_grid::{Draggable, TitleBar};
 use crate::widget::{self, Tree};
-use crate::{Clipboard, Element, Layout, Point, Rectangle, Shell, Size};
+use crate::{
+    Clipboard, Element, Layout, Length, Point, Rectangle, Shell, Size,
+};
 
 /// The content of a [`Pane`].
 ///
@@ -183,6 +186,37 @@ where
         }
     }
 
+    pub(crate) fn inspect(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+    ) -> inspect::Node {
+        let node = inspect::Node::new::<Self>(
+            layout.bounds(),
+            Length::Fill,
+            Length::Fill,
+        );
+
+        if let Some(title_bar) = &self.title_bar {
+            let mut children = layout.children();
+
+            let title_bar =
+                title_bar.inspect(&tree.children[1], children.next().unwrap());
+
+            let body = self
+                .body
+                .as_widget()
+                .inspect(&tree.children[0], children.next().unwrap());
+
+            node.children([title_bar, body])
+        } else {
+            node.children([self
+                .body
+                .as_widget()
+                .inspect(&tree.children[0], layout)])
+        }
+    }
+
     pub(crate) fn operate(
         &self,
         tree: &mut Tree,
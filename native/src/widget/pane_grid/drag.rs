@@ -0,0 +1,50 @@
+//! Pick, drop, and draw the items of a widget that can be reordered with
+//! drag and drop.
+use crate::{Layout, Point, Rectangle, Vector};
+
+/// Returns the first item whose [`Layout`] contains the cursor.
+pub(crate) fn hovered<'a, T>(
+    mut items: impl Iterator<Item = (T, Layout<'a>)>,
+    cursor_position: Point,
+) -> Option<(T, Layout<'a>)> {
+    items.find(|(_, layout)| layout.bounds().contains(cursor_position))
+}
+
+/// Returns the origin of a drag interaction that picks the item with the
+/// given [`Layout`] at the current cursor position.
+pub(crate) fn origin(layout: Layout<'_>, cursor_position: Point) -> Point {
+    let position = layout.position();
+
+    cursor_position - Vector::new(position.x, position.y)
+}
+
+/// Returns the item where the `picked` one is being dropped on, if it is not
+/// the `picked` one itself.
+pub(crate) fn target<'a, T: PartialEq>(
+    picked: &T,
+    items: impl Iterator<Item = (T, Layout<'a>)>,
+    cursor_position: Point,
+) -> Option<T> {
+    hovered(items, cursor_position)
+        .map(|(target, _)| target)
+        .filter(|target| target != picked)
+}
+
+/// Draws the picked item with the given `bounds` following the cursor, on top
+/// of everything else.
+pub(crate) fn draw_picked<Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    origin: Point,
+    cursor_position: Point,
+    draw: impl FnOnce(&mut Renderer),
+) where
+    Renderer: crate::Renderer,
+{
+    renderer.with_translation(
+        cursor_position - Point::new(bounds.x + origin.x, bounds.y + origin.y),
+        |renderer| {
+            renderer.with_layer(bounds, draw);
+        },
+    );
+}
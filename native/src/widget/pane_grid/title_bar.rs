@@ -4,9 +4,10 @@ use crate::mouse;
 use crate::overlay;
 use crate::renderer;
 use crate::widget::container;
+use crate::widget::inspect;
 use crate::widget::{self, Tree};
 use crate::{
-    Clipboard, Element, Layout, Padding, Point, Rectangle, Shell, Size,
+    Clipboard, Element, Layout, Length, Padding, Point, Rectangle, Shell, Size,
 };
 
 /// The title bar of a [`Pane`].
@@ -257,6 +258,53 @@ where
         layout::Node::with_children(node.size().pad(self.padding), vec![node])
     }
 
+    pub(crate) fn inspect(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+    ) -> inspect::Node {
+        let mut children = layout.children();
+        let padded = children.next().unwrap();
+
+        let mut children = padded.children();
+        let title_layout = children.next().unwrap();
+        let mut nodes = Vec::new();
+        let mut show_title = true;
+
+        if let Some(controls) = &self.controls {
+            let controls_layout = children.next().unwrap();
+
+            if title_layout.bounds().width + controls_layout.bounds().width
+                > padded.bounds().width
+            {
+                show_title = false;
+            }
+
+            nodes.push(
+                controls
+                    .as_widget()
+                    .inspect(&tree.children[1], controls_layout),
+            );
+        }
+
+        if show_title {
+            nodes.insert(
+                0,
+                self.content
+                    .as_widget()
+                    .inspect(&tree.children[0], title_layout),
+            );
+        }
+
+        inspect::Node::new::<Self>(
+            layout.bounds(),
+            Length::Fill,
+            Length::Shrink,
+        )
+        .padding(self.padding)
+        .children(nodes)
+    }
+
     pub(crate) fn operate(
         &self,
         tree: &mut Tree,
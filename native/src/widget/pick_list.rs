@@ -10,6 +10,7 @@ use crate::renderer;
 use crate::text::{self, Text};
 use crate::touch;
 use crate::widget::container;
+use crate::widget::operation::{self, Operation};
 use crate::widget::scrollable;
 use crate::widget::tree::{self, Tree};
 use crate::{
@@ -161,6 +162,15 @@ where
         )
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.focusable(tree.state.downcast_mut::<State<T>>(), None);
+    }
+
     fn on_event(
         &mut self,
         tree: &mut Tree,
@@ -196,7 +206,7 @@ where
 
     fn draw(
         &self,
-        _tree: &Tree,
+        tree: &Tree,
         renderer: &mut Renderer,
         theme: &Renderer::Theme,
         _style: &renderer::Style,
@@ -215,6 +225,7 @@ where
             self.placeholder.as_deref(),
             self.selected.as_ref(),
             &self.style,
+            || tree.state.downcast_ref::<State<T>>(),
         )
     }
 
@@ -263,6 +274,7 @@ pub struct State<T> {
     menu: menu::State,
     keyboard_modifiers: keyboard::Modifiers,
     is_open: bool,
+    is_focused: bool,
     hovered_option: Option<usize>,
     last_selection: Option<T>,
 }
@@ -274,6 +286,7 @@ impl<T> State<T> {
             menu: menu::State::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
             is_open: bool::default(),
+            is_focused: bool::default(),
             hovered_option: Option::default(),
             last_selection: Option::default(),
         }
@@ -286,6 +299,25 @@ impl<T> Default for State<T> {
     }
 }
 
+impl<T> operation::Focusable for State<T> {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        // A menu opened with the keyboard is closed when leaving
+        if self.is_focused {
+            self.is_open = false;
+        }
+
+        self.is_focused = false;
+    }
+}
+
 /// Computes the layout of a [`PickList`].
 pub fn layout<Renderer, T>(
     renderer: &Renderer,
@@ -364,6 +396,9 @@ where
         | Event::Touch(touch::Event::FingerPressed { .. }) => {
             let state = state();
 
+            // Using the mouse dismisses the keyboard focus
+            state.is_focused = false;
+
             let event_status = if state.is_open {
                 // Event wasn't processed by overlay, so cursor was clicked either outside it's
                 // bounds or on the drop-down, either way we close the overlay.
@@ -433,6 +468,63 @@ where
                 event::Status::Ignored
             }
         }
+        Event::Mouse(mouse::Event::ButtonPressed(_)) => {
+            state().is_focused = false;
+
+            event::Status::Ignored
+        }
+        Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) => {
+            let state = state();
+
+            if !state.is_focused {
+                return event::Status::Ignored;
+            }
+
+            match key_code {
+                keyboard::KeyCode::Space | keyboard::KeyCode::Enter => {
+                    if state.is_open {
+                        if let Some(option) = state
+                            .hovered_option
+                            .and_then(|index| options.get(index))
+                        {
+                            shell.publish((on_selected)(option.clone()));
+                        }
+
+                        state.is_open = false;
+                    } else {
+                        state.is_open = true;
+                        state.hovered_option = options
+                            .iter()
+                            .position(|option| Some(option) == selected);
+                    }
+
+                    event::Status::Captured
+                }
+                keyboard::KeyCode::Up | keyboard::KeyCode::Down
+                    if state.is_open && !options.is_empty() =>
+                {
+                    let last = options.len() - 1;
+
+                    state.hovered_option =
+                        Some(match (key_code, state.hovered_option) {
+                            (keyboard::KeyCode::Up, Some(index)) => {
+                                index.saturating_sub(1)
+                            }
+                            (keyboard::KeyCode::Up, None) => last,
+                            (_, Some(index)) => (index + 1).min(last),
+                            (_, None) => 0,
+                        });
+
+                    event::Status::Captured
+                }
+                keyboard::KeyCode::Escape if state.is_open => {
+                    state.is_open = false;
+
+                    event::Status::Captured
+                }
+                _ => event::Status::Ignored,
+            }
+        }
         Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
             let state = state();
 
@@ -505,7 +597,7 @@ where
 }
 
 /// Draws a [`PickList`].
-pub fn draw<T, Renderer>(
+pub fn draw<'a, T, Renderer>(
     renderer: &mut Renderer,
     theme: &Renderer::Theme,
     layout: Layout<'_>,
@@ -516,16 +608,19 @@ pub fn draw<T, Renderer>(
     placeholder: Option<&str>,
     selected: Option<&T>,
     style: &<Renderer::Theme as StyleSheet>::Style,
+    state: impl FnOnce() -> &'a State<T>,
 ) where
     Renderer: text::Renderer,
     Renderer::Theme: StyleSheet,
-    T: ToString,
+    T: ToString + 'a,
 {
     let bounds = layout.bounds();
     let is_mouse_over = bounds.contains(cursor_position);
     let is_selected = selected.is_some();
 
-    let style = if is_mouse_over {
+    let style = if state().is_focused {
+        theme.focused(style)
+    } else if is_mouse_over {
         theme.hovered(style)
     } else {
         theme.active(style)
@@ -1,12 +1,15 @@
 //! Create choices using radio buttons.
 use crate::alignment;
 use crate::event::{self, Event};
+use crate::keyboard;
 use crate::layout;
 use crate::mouse;
 use crate::renderer;
 use crate::text;
 use crate::touch;
-use crate::widget::{self, Row, Text, Tree};
+use crate::widget::operation::{self, Operation};
+use crate::widget::tree::{self, Tree};
+use crate::widget::{self, Row, Text};
 use crate::{
     Alignment, Clipboard, Color, Element, Layout, Length, Point, Rectangle,
     Shell, Widget,
@@ -146,6 +149,14 @@ where
     Renderer: text::Renderer,
     Renderer::Theme: StyleSheet + widget::text::StyleSheet,
 {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
     fn width(&self) -> Length {
         self.width
     }
@@ -174,9 +185,18 @@ where
             .layout(renderer, limits)
     }
 
+    fn operate(
+        &self,
+        state: &mut Tree,
+        _layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.focusable(state.state.downcast_mut::<State>(), None);
+    }
+
     fn on_event(
         &mut self,
-        _state: &mut Tree,
+        state: &mut Tree,
         event: Event,
         layout: Layout<'_>,
         cursor_position: Point,
@@ -184,6 +204,15 @@ where
         _clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
+        let state = state.state.downcast_mut::<State>();
+
+        // Using the mouse dismisses the keyboard focus
+        if let Event::Mouse(mouse::Event::ButtonPressed(_))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            state.is_focused = false;
+        }
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
@@ -193,6 +222,14 @@ where
                     return event::Status::Captured;
                 }
             }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Space | keyboard::KeyCode::Enter,
+                ..
+            }) if state.is_focused => {
+                shell.publish(self.on_click.clone());
+
+                return event::Status::Captured;
+            }
             _ => {}
         }
 
@@ -216,7 +253,7 @@ where
 
     fn draw(
         &self,
-        _state: &Tree,
+        state: &Tree,
         renderer: &mut Renderer,
         theme: &Renderer::Theme,
         style: &renderer::Style,
@@ -229,7 +266,9 @@ where
 
         let mut children = layout.children();
 
-        let custom_style = if is_mouse_over {
+        let custom_style = if state.state.downcast_ref::<State>().is_focused {
+            theme.focused(&self.style, self.is_selected)
+        } else if is_mouse_over {
             theme.hovered(&self.style, self.is_selected)
         } else {
             theme.active(&self.style, self.is_selected)
@@ -301,3 +340,23 @@ where
         Element::new(radio)
     }
 }
+
+/// The local state of a [`Radio`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+struct State {
+    is_focused: bool,
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
@@ -6,6 +6,7 @@ use crate::overlay;
 use crate::renderer;
 use crate::touch;
 use crate::widget;
+use crate::widget::inspect;
 use crate::widget::operation::{self, Operation};
 use crate::widget::tree::{self, Tree};
 use crate::{
@@ -160,6 +161,26 @@ where
         )
     }
 
+    fn inspect(&self, tree: &Tree, layout: Layout<'_>) -> inspect::Node {
+        let state = tree.state.downcast_ref::<State>();
+
+        let bounds = layout.bounds();
+        let content_layout = layout.children().next().unwrap();
+        let offset = state.offset(bounds, content_layout.bounds());
+
+        inspect::Node::new::<Self>(
+            bounds,
+            self.content.as_widget().width(),
+            self.height,
+        )
+        .id(self.id.as_ref().map(|id| &id.0))
+        .children([self
+            .content
+            .as_widget()
+            .inspect(&tree.children[0], content_layout)
+            .translate(Vector::new(0.0, -(offset as f32)))])
+    }
+
     fn operate(
         &self,
         tree: &mut Tree,
@@ -168,12 +189,20 @@ where
     ) {
         let state = tree.state.downcast_mut::<State>();
 
-        operation.scrollable(state, self.id.as_ref().map(|id| &id.0));
+        let bounds = layout.bounds();
+        let content_layout = layout.children().next().unwrap();
+
+        operation.scrollable(
+            state,
+            self.id.as_ref().map(|id| &id.0),
+            bounds,
+            content_layout.bounds(),
+        );
 
-        operation.container(None, &mut |operation| {
+        operation.container(None, bounds, &mut |operation| {
             self.content.as_widget().operate(
                 &mut tree.children[0],
-                layout.children().next().unwrap(),
+                content_layout,
                 operation,
             );
         });
@@ -318,7 +347,7 @@ where
 
 /// The identifier of a [`Scrollable`].
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Id(widget::Id);
+pub struct Id(pub(crate) widget::Id);
 
 impl Id {
     /// Creates a custom [`Id`].
@@ -346,6 +375,31 @@ pub fn snap_to<Message: 'static>(id: Id, percentage: f32) -> Command<Message> {
     Command::widget(operation::scrollable::snap_to(id.0, percentage))
 }
 
+/// Produces a [`Command`] that scrolls the [`Scrollable`] with the given [`Id`]
+/// to the provided absolute `offset`, in pixels.
+///
+/// Unlike [`snap_to`], the offset is kept when the contents of the
+/// [`Scrollable`] grow.
+pub fn scroll_to<Message: 'static>(id: Id, offset: f32) -> Command<Message> {
+    Command::widget(operation::scrollable::scroll_to(id.0, offset))
+}
+
+/// Produces a [`Command`] that scrolls the [`Scrollable`] with the given [`Id`]
+/// by the provided `delta`, in pixels.
+///
+/// A positive `delta` scrolls towards the bottom of the contents.
+pub fn scroll_by<Message: 'static>(id: Id, delta: f32) -> Command<Message> {
+    Command::widget(operation::scrollable::scroll_by(id.0, delta))
+}
+
+/// Produces a [`Command`] that scrolls the nearest [`Scrollable`] containing
+/// the widget with the given [`widget::Id`] until the widget is visible.
+pub fn scroll_into_view<Message: 'static>(
+    target: impl Into<widget::Id>,
+) -> Command<Message> {
+    Command::widget(operation::scrollable::scroll_into_view(target.into()))
+}
+
 /// Computes the layout of a [`Scrollable`].
 pub fn layout<Renderer>(
     renderer: &Renderer,
@@ -836,6 +890,27 @@ impl operation::Scrollable for State {
     fn snap_to(&mut self, percentage: f32) {
         State::snap_to(self, percentage);
     }
+
+    fn scroll_to(&mut self, offset: f32) {
+        State::scroll_to_absolute(self, offset);
+    }
+
+    fn scroll_by(
+        &mut self,
+        delta: f32,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        State::scroll_by(self, delta, bounds, content_bounds);
+    }
+
+    fn absolute_offset(
+        &self,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> f32 {
+        self.offset.absolute(bounds, content_bounds)
+    }
 }
 
 /// The local state of a [`Scrollable`].
@@ -889,7 +964,8 @@ impl State {
     /// Scrolls the [`Scrollable`] to a relative amount.
     ///
     /// `0` represents scrollbar at the top, while `1` represents scrollbar at
-    /// the bottom.
+    /// the bottom. Use [`State::scroll_to_absolute`] to scroll to an offset in
+    /// pixels instead.
     pub fn scroll_to(
         &mut self,
         percentage: f32,
@@ -908,6 +984,28 @@ impl State {
         self.offset = Offset::Relative(percentage.max(0.0).min(1.0));
     }
 
+    /// Scrolls the [`Scrollable`] to an absolute `offset`, in pixels.
+    ///
+    /// Unlike [`State::scroll_to`], the offset is not relative to the size of
+    /// the contents. It is clamped to the scrollable area once the bounds of
+    /// the contents are known.
+    pub fn scroll_to_absolute(&mut self, offset: f32) {
+        self.offset = Offset::Absolute(offset.max(0.0));
+    }
+
+    /// Scrolls the [`Scrollable`] by the given `delta`, in pixels, given the
+    /// bounds of the [`Scrollable`] and its contents.
+    ///
+    /// A positive `delta` scrolls towards the bottom of the contents.
+    pub fn scroll_by(
+        &mut self,
+        delta: f32,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        self.scroll(-delta, bounds, content_bounds);
+    }
+
     /// Unsnaps the current scroll position, if snapped, given the bounds of the
     /// [`Scrollable`] and its contents.
     pub fn unsnap(&mut self, bounds: Rectangle, content_bounds: Rectangle) {
@@ -2,10 +2,12 @@
 //!
 //! A [`Slider`] has some local [`State`].
 use crate::event::{self, Event};
+use crate::keyboard;
 use crate::layout;
 use crate::mouse;
 use crate::renderer;
 use crate::touch;
+use crate::widget::operation::{self, Operation};
 use crate::widget::tree::{self, Tree};
 use crate::{
     Background, Clipboard, Color, Element, Layout, Length, Point, Rectangle,
@@ -180,6 +182,15 @@ where
         layout::Node::new(size)
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.focusable(tree.state.downcast_mut::<State>(), None);
+    }
+
     fn on_event(
         &mut self,
         tree: &mut Tree,
@@ -277,6 +288,54 @@ where
 {
     let is_dragging = state.is_dragging;
 
+    // Using the mouse dismisses the keyboard focus
+    if let Event::Mouse(mouse::Event::ButtonPressed(_))
+    | Event::Touch(touch::Event::FingerPressed { .. }) = event
+    {
+        state.is_focused = false;
+    }
+
+    if let Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) = event
+    {
+        if state.is_focused {
+            let current = (*value).into();
+            let step = step.into();
+            let start = (*range.start()).into();
+            let end = (*range.end()).into();
+
+            let new_value = match key_code {
+                keyboard::KeyCode::Left | keyboard::KeyCode::Down => {
+                    Some(current - step)
+                }
+                keyboard::KeyCode::Right | keyboard::KeyCode::Up => {
+                    Some(current + step)
+                }
+                keyboard::KeyCode::Home => Some(start),
+                keyboard::KeyCode::End => Some(end),
+                _ => None,
+            };
+
+            if let Some(new_value) = new_value {
+                if let Some(new_value) =
+                    T::from_f64(new_value.clamp(start, end))
+                {
+                    if (current - new_value.into()).abs() > f64::EPSILON {
+                        shell.publish((on_change)(new_value));
+
+                        *value = new_value;
+
+                        // Every key press is a complete interaction
+                        if let Some(on_release) = on_release.clone() {
+                            shell.publish(on_release);
+                        }
+                    }
+                }
+
+                return event::Status::Captured;
+            }
+        }
+    }
+
     let mut change = || {
         let bounds = layout.bounds();
         let new_value = if cursor_position.x <= bounds.x {
@@ -364,6 +423,8 @@ pub fn draw<T, R>(
 
     let style = if state.is_dragging {
         style_sheet.dragging(style)
+    } else if state.is_focused {
+        style_sheet.focused(style)
     } else if is_mouse_over {
         style_sheet.hovered(style)
     } else {
@@ -465,6 +526,7 @@ pub fn mouse_interaction(
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct State {
     is_dragging: bool,
+    is_focused: bool,
 }
 
 impl State {
@@ -473,3 +535,17 @@ impl State {
         State::default()
     }
 }
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
@@ -0,0 +1,1026 @@
+//! Display rows of data aligned in columns.
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::touch;
+use crate::widget::inspect;
+use crate::widget::tree::{self, Tree};
+use crate::widget::{Operation, Space};
+use crate::{
+    Background, Clipboard, Color, Element, Layout, Length, Padding, Point,
+    Rectangle, Shell, Size, Widget,
+};
+
+pub use iced_style::table::{Appearance, Line, RowAppearance, StyleSheet};
+
+/// The space reserved in a header cell for the indicator of a sorted column.
+const SORT_INDICATOR_SIZE: f32 = 8.0;
+
+/// A widget that displays rows of data aligned in columns, with a header row.
+///
+/// A [`Table`] supports:
+///
+/// * Fixed, filling, and shrinking column widths
+/// * Mouse-based resizing of columns
+/// * Sort indicators in the header row
+/// * Row selection
+///
+/// Like [`PaneGrid`], a [`Table`] does not keep the width of its columns, the
+/// sort order, or the selected rows. Instead, it produces messages that can be
+/// used to update them.
+///
+/// [`PaneGrid`]: crate::widget::PaneGrid
+#[allow(missing_debug_implementations)]
+pub struct Table<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    columns: Vec<Column<'a, Message, Renderer>>,
+    rows: Vec<Vec<Element<'a, Message, Renderer>>>,
+    width: Length,
+    height: Length,
+    padding: Padding,
+    selected: Vec<usize>,
+    on_select: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_sort: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_resize: Option<(u16, Box<dyn Fn(ResizeEvent) -> Message + 'a>)>,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Table<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: crate::Renderer + 'a,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`Table`] with the given columns and no rows.
+    pub fn new(columns: Vec<Column<'a, Message, Renderer>>) -> Self {
+        Table {
+            columns,
+            rows: Vec::new(),
+            width: Length::Shrink,
+            height: Length::Shrink,
+            padding: Padding::new(5),
+            selected: Vec::new(),
+            on_select: None,
+            on_sort: None,
+            on_resize: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Adds a row of cells to the [`Table`].
+    ///
+    /// Missing cells are left empty, while cells beyond the amount of columns
+    /// of the [`Table`] are ignored.
+    pub fn push(mut self, cells: Vec<Element<'a, Message, Renderer>>) -> Self {
+        let mut cells = cells;
+
+        cells.truncate(self.columns.len());
+        cells.resize_with(self.columns.len(), || {
+            Space::new(Length::Shrink, Length::Shrink).into()
+        });
+
+        self.rows.push(cells);
+        self
+    }
+
+    /// Sets the width of the [`Table`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Table`].
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the [`Padding`] of every cell of the [`Table`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the indices of the selected rows of the [`Table`].
+    pub fn selected(mut self, rows: impl IntoIterator<Item = usize>) -> Self {
+        self.selected = rows.into_iter().collect();
+        self
+    }
+
+    /// Sets the message that will be produced when a row of the [`Table`] is
+    /// clicked.
+    ///
+    /// The message receives the index of the clicked row.
+    pub fn on_select(mut self, f: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_select = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message that will be produced when a header of the [`Table`]
+    /// is clicked.
+    ///
+    /// The message receives the index of the clicked column.
+    pub fn on_sort(mut self, f: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_sort = Some(Box::new(f));
+        self
+    }
+
+    /// Enables the resize interactions of the [`Table`], which will
+    /// use the provided function to produce messages.
+    ///
+    /// The `leeway` describes the amount of space around a column divider of
+    /// the header row that can be used to grab it.
+    pub fn on_resize(
+        mut self,
+        leeway: u16,
+        f: impl Fn(ResizeEvent) -> Message + 'a,
+    ) -> Self {
+        self.on_resize = Some((leeway, Box::new(f)));
+        self
+    }
+
+    /// Sets the style of the [`Table`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Table<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn cells(&self) -> impl Iterator<Item = &Element<'a, Message, Renderer>> {
+        self.columns
+            .iter()
+            .map(|column| &column.header)
+            .chain(self.rows.iter().flatten())
+    }
+
+    fn cells_mut(
+        &mut self,
+    ) -> impl Iterator<Item = &mut Element<'a, Message, Renderer>> {
+        self.columns
+            .iter_mut()
+            .map(|column| &mut column.header)
+            .chain(self.rows.iter_mut().flatten())
+    }
+}
+
+/// A column of a [`Table`].
+#[allow(missing_debug_implementations)]
+pub struct Column<'a, Message, Renderer> {
+    header: Element<'a, Message, Renderer>,
+    width: Length,
+    sort: Option<Sort>,
+}
+
+impl<'a, Message, Renderer> Column<'a, Message, Renderer> {
+    /// Creates a new [`Column`] with the given header.
+    pub fn new(header: impl Into<Element<'a, Message, Renderer>>) -> Self {
+        Self {
+            header: header.into(),
+            width: Length::Shrink,
+            sort: None,
+        }
+    }
+
+    /// Sets the width of the [`Column`].
+    ///
+    /// [`Length::Units`] produces a fixed width, [`Length::Fill`] and
+    /// [`Length::FillPortion`] share the remaining space of the [`Table`],
+    /// and [`Length::Shrink`] fits the widest cell of the [`Column`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the [`Sort`] order indicated in the header of the [`Column`].
+    pub fn sort(mut self, sort: Option<Sort>) -> Self {
+        self.sort = sort;
+        self
+    }
+}
+
+/// The sort order of a [`Column`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sort {
+    /// The rows are sorted in ascending order.
+    Ascending,
+
+    /// The rows are sorted in descending order.
+    Descending,
+}
+
+/// An event produced during a resize interaction of a [`Table`].
+#[derive(Debug, Clone, Copy)]
+pub struct ResizeEvent {
+    /// The index of the [`Column`] being resized.
+    pub column: usize,
+
+    /// The new width of the [`Column`], in pixels.
+    pub width: f32,
+}
+
+/// The local state of a [`Table`].
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    resizing: Option<Resizing>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Resizing {
+    column: usize,
+    origin: f32,
+    width: f32,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Table<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.cells().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.cells().collect::<Vec<_>>())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+        let padding = self.padding;
+
+        let widths = column_widths(
+            renderer,
+            &limits,
+            padding,
+            &self.columns,
+            &self.rows,
+        );
+
+        let header = layout_row(
+            renderer,
+            &limits,
+            padding,
+            &widths,
+            self.columns.iter().map(|column| {
+                let reserved = if column.sort.is_some() {
+                    SORT_INDICATOR_SIZE + f32::from(padding.right)
+                } else {
+                    0.0
+                };
+
+                (&column.header, reserved)
+            }),
+            0.0,
+        );
+
+        let mut y = header.size().height;
+        let mut rows = vec![header];
+
+        for cells in &self.rows {
+            let row = layout_row(
+                renderer,
+                &limits,
+                padding,
+                &widths,
+                cells.iter().map(|cell| (cell, 0.0)),
+                y,
+            );
+
+            y += row.size().height;
+            rows.push(row);
+        }
+
+        let size = limits.resolve(Size::new(widths.iter().sum(), y));
+
+        layout::Node::with_children(size, rows)
+    }
+
+    fn inspect(&self, tree: &Tree, layout: Layout<'_>) -> inspect::Node {
+        inspect::Node::new::<Self>(layout.bounds(), self.width, self.height)
+            .children(
+                self.cells().zip(&tree.children).zip(contents(layout)).map(
+                    |((cell, state), layout)| {
+                        cell.as_widget().inspect(state, layout)
+                    },
+                ),
+            )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.cells()
+                .zip(&mut tree.children)
+                .zip(contents(layout))
+                .for_each(|((cell, state), layout)| {
+                    cell.as_widget().operate(state, layout, operation);
+                })
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        if let Some(resizing) = state.resizing {
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { .. })
+                | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                    if let Some((_, on_resize)) = &self.on_resize {
+                        let width = (resizing.width + cursor_position.x
+                            - resizing.origin)
+                            .max(f32::from(self.padding.horizontal()) + 1.0);
+
+                        shell.publish(on_resize(ResizeEvent {
+                            column: resizing.column,
+                            width,
+                        }));
+                    }
+
+                    return event::Status::Captured;
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(
+                    mouse::Button::Left,
+                ))
+                | Event::Touch(touch::Event::FingerLifted { .. })
+                | Event::Touch(touch::Event::FingerLost { .. }) => {
+                    state.resizing = None;
+
+                    return event::Status::Captured;
+                }
+                _ => {}
+            }
+        }
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            let divider = self.on_resize.as_ref().and_then(|(leeway, _)| {
+                hovered_divider(layout, cursor_position, *leeway)
+            });
+
+            if let Some((column, width)) = divider {
+                state.resizing = Some(Resizing {
+                    column,
+                    origin: cursor_position.x,
+                    width,
+                });
+
+                return event::Status::Captured;
+            }
+        }
+
+        let status = self
+            .cells_mut()
+            .zip(&mut tree.children)
+            .zip(contents(layout))
+            .map(|((cell, state), layout)| {
+                cell.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    cursor_position,
+                    renderer,
+                    clipboard,
+                    shell,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                match hovered_cell(layout, cursor_position) {
+                    Some((0, column)) => {
+                        if let Some(on_sort) = &self.on_sort {
+                            shell.publish(on_sort(column));
+
+                            return event::Status::Captured;
+                        }
+                    }
+                    Some((row, _)) => {
+                        if let Some(on_select) = &self.on_select {
+                            shell.publish(on_select(row - 1));
+
+                            return event::Status::Captured;
+                        }
+                    }
+                    None => {}
+                }
+
+                status
+            }
+            _ => status,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        let is_resizing = state.resizing.is_some()
+            || self.on_resize.as_ref().is_some_and(|(leeway, _)| {
+                hovered_divider(layout, cursor_position, *leeway).is_some()
+            });
+
+        if is_resizing {
+            return mouse::Interaction::ResizingHorizontally;
+        }
+
+        let interaction = self
+            .cells()
+            .zip(&tree.children)
+            .zip(contents(layout))
+            .map(|((cell, state), layout)| {
+                cell.as_widget().mouse_interaction(
+                    state,
+                    layout,
+                    cursor_position,
+                    viewport,
+                    renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default();
+
+        if interaction != mouse::Interaction::Idle {
+            return interaction;
+        }
+
+        match hovered_cell(layout, cursor_position) {
+            Some((0, _)) if self.on_sort.is_some() => {
+                mouse::Interaction::Pointer
+            }
+            Some((row, _)) if row > 0 && self.on_select.is_some() => {
+                mouse::Interaction::Pointer
+            }
+            _ => interaction,
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let appearance = theme.appearance(&self.style);
+
+        if appearance.background.is_some() || appearance.border_width > 0.0 {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: appearance.border_radius.into(),
+                    border_width: appearance.border_width,
+                    border_color: appearance.border_color,
+                },
+                appearance
+                    .background
+                    .unwrap_or(Background::Color(Color::TRANSPARENT)),
+            );
+        }
+
+        let mut cells = self.cells().zip(&tree.children);
+
+        for (index, row) in layout.children().enumerate() {
+            let row_bounds = row.bounds();
+
+            let (background, text_color) = if index == 0 {
+                (appearance.header_background, appearance.header_text_color)
+            } else {
+                let is_selected = self.selected.contains(&(index - 1));
+                let is_hovered = self.on_select.is_some()
+                    && state.resizing.is_none()
+                    && row_bounds.contains(cursor_position);
+
+                let row_appearance = if is_hovered {
+                    theme.hovered_row(&self.style, index - 1, is_selected)
+                } else {
+                    theme.row(&self.style, index - 1, is_selected)
+                };
+
+                (row_appearance.background, row_appearance.text_color)
+            };
+
+            if let Some(background) = background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: row_bounds,
+                        border_radius: 0.0.into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    background,
+                );
+            }
+
+            let style = renderer::Style {
+                text_color: text_color.unwrap_or(style.text_color),
+            };
+
+            for (cell_layout, (cell, state)) in row.children().zip(&mut cells) {
+                cell.as_widget().draw(
+                    state,
+                    renderer,
+                    theme,
+                    &style,
+                    cell_layout.children().next().unwrap(),
+                    cursor_position,
+                    viewport,
+                );
+            }
+
+            if index > 0 && appearance.divider_width > 0.0 {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            height: appearance.divider_width,
+                            y: row_bounds.y - appearance.divider_width / 2.0,
+                            ..row_bounds
+                        },
+                        border_radius: 0.0.into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    appearance.divider_color,
+                );
+            }
+        }
+
+        if let Some(header) = layout.children().next() {
+            let columns = header.children().zip(&self.columns);
+
+            for (index, (cell, column)) in columns.enumerate() {
+                let cell_bounds = cell.bounds();
+
+                if let Some(sort) = column.sort {
+                    draw_sort_indicator(
+                        renderer,
+                        Rectangle {
+                            x: cell_bounds.x + cell_bounds.width
+                                - f32::from(self.padding.right)
+                                - SORT_INDICATOR_SIZE,
+                            y: cell_bounds.center_y()
+                                - SORT_INDICATOR_SIZE / 2.0,
+                            width: SORT_INDICATOR_SIZE,
+                            height: SORT_INDICATOR_SIZE,
+                        },
+                        sort,
+                        appearance.sort_indicator_color,
+                    );
+                }
+
+                if index + 1 < self.columns.len()
+                    && appearance.divider_width > 0.0
+                {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: cell_bounds.x + cell_bounds.width
+                                    - appearance.divider_width / 2.0,
+                                y: bounds.y,
+                                width: appearance.divider_width,
+                                height: bounds.height,
+                            },
+                            border_radius: 0.0.into(),
+                            border_width: 0.0,
+                            border_color: Color::TRANSPARENT,
+                        },
+                        appearance.divider_color,
+                    );
+                }
+            }
+        }
+
+        let highlighted_divider = match state.resizing {
+            Some(resizing) => Some((resizing.column, true)),
+            None => self.on_resize.as_ref().and_then(|(leeway, _)| {
+                hovered_divider(layout, cursor_position, *leeway)
+                    .map(|(column, _)| (column, false))
+            }),
+        };
+
+        if let Some((column, is_picked)) = highlighted_divider {
+            let line = if is_picked {
+                theme.picked_divider(&self.style)
+            } else {
+                theme.hovered_divider(&self.style)
+            };
+
+            let cell = layout
+                .children()
+                .next()
+                .and_then(|header| header.children().nth(column));
+
+            if let (Some(line), Some(cell)) = (line, cell) {
+                let cell_bounds = cell.bounds();
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: (cell_bounds.x + cell_bounds.width
+                                - line.width / 2.0)
+                                .round(),
+                            y: bounds.y,
+                            width: line.width,
+                            height: bounds.height,
+                        },
+                        border_radius: 0.0.into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    line.color,
+                );
+            }
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        self.cells_mut()
+            .zip(&mut tree.children)
+            .zip(contents(layout))
+            .find_map(|((cell, state), layout)| {
+                cell.as_widget_mut().overlay(state, layout, renderer)
+            })
+    }
+}
+
+impl<'a, Message, Renderer> From<Table<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: crate::Renderer + 'a,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(table: Table<'a, Message, Renderer>) -> Self {
+        Self::new(table)
+    }
+}
+
+/// Computes the width of every column of a [`Table`].
+fn column_widths<Message, Renderer>(
+    renderer: &Renderer,
+    limits: &layout::Limits,
+    padding: Padding,
+    columns: &[Column<'_, Message, Renderer>],
+    rows: &[Vec<Element<'_, Message, Renderer>>],
+) -> Vec<f32>
+where
+    Renderer: crate::Renderer,
+{
+    let max_width = limits.max().width;
+    let content_limits = layout::Limits::new(
+        Size::ZERO,
+        Size::new(max_width, limits.max().height),
+    );
+
+    let mut widths = vec![0.0; columns.len()];
+    let mut available = max_width;
+    let mut fill_sum = 0;
+
+    for (index, column) in columns.iter().enumerate() {
+        let width = match column.width {
+            Length::Units(units) => f32::from(units),
+            Length::Shrink => {
+                let sort_indicator = if column.sort.is_some() {
+                    SORT_INDICATOR_SIZE + f32::from(padding.right)
+                } else {
+                    0.0
+                };
+
+                let header = column
+                    .header
+                    .as_widget()
+                    .layout(renderer, &content_limits)
+                    .size()
+                    .width
+                    + sort_indicator;
+
+                rows.iter()
+                    .filter_map(|cells| cells.get(index))
+                    .map(|cell| {
+                        cell.as_widget()
+                            .layout(renderer, &content_limits)
+                            .size()
+                            .width
+                    })
+                    .fold(header, f32::max)
+                    + f32::from(padding.horizontal())
+            }
+            Length::Fill | Length::FillPortion(_) => {
+                fill_sum += column.width.fill_factor();
+
+                continue;
+            }
+        };
+
+        widths[index] = width;
+        available -= width;
+    }
+
+    if fill_sum > 0 {
+        let remaining = available.max(0.0);
+
+        for (index, column) in columns.iter().enumerate() {
+            let fill_factor = column.width.fill_factor();
+
+            if fill_factor > 0 && remaining.is_finite() {
+                widths[index] =
+                    remaining * f32::from(fill_factor) / f32::from(fill_sum);
+            }
+        }
+    }
+
+    widths
+}
+
+/// Lays out a row of cells with the given column widths at the given height.
+///
+/// Every cell can reserve some horizontal space at its end.
+fn layout_row<'a, 'b, Message, Renderer>(
+    renderer: &Renderer,
+    limits: &layout::Limits,
+    padding: Padding,
+    widths: &[f32],
+    cells: impl Iterator<Item = (&'b Element<'a, Message, Renderer>, f32)>,
+    y: f32,
+) -> layout::Node
+where
+    'a: 'b,
+    Message: 'b,
+    Renderer: crate::Renderer + 'b,
+{
+    let contents: Vec<_> = cells
+        .zip(widths)
+        .map(|((cell, reserved), width)| {
+            let limits = layout::Limits::new(
+                Size::ZERO,
+                Size::new((width - reserved).max(0.0), limits.max().height),
+            )
+            .pad(padding);
+
+            let mut content = cell.as_widget().layout(renderer, &limits);
+            content.move_to(Point::new(
+                f32::from(padding.left),
+                f32::from(padding.top),
+            ));
+
+            content
+        })
+        .collect();
+
+    let height = contents
+        .iter()
+        .map(|content| content.size().height)
+        .fold(0.0, f32::max)
+        + f32::from(padding.vertical());
+
+    let mut x = 0.0;
+
+    let cells = contents
+        .into_iter()
+        .zip(widths)
+        .map(|(content, width)| {
+            let mut cell = layout::Node::with_children(
+                Size::new(*width, height),
+                vec![content],
+            );
+            cell.move_to(Point::new(x, 0.0));
+
+            x += width;
+
+            cell
+        })
+        .collect();
+
+    let mut row = layout::Node::with_children(Size::new(x, height), cells);
+    row.move_to(Point::new(0.0, y));
+
+    row
+}
+
+/// Returns the layouts of the contents of every cell of a [`Table`], starting
+/// with the header row.
+fn contents(layout: Layout<'_>) -> impl Iterator<Item = Layout<'_>> {
+    layout
+        .children()
+        .flat_map(Layout::children)
+        .map(|cell| cell.children().next().unwrap())
+}
+
+/// Returns the row and column of the cell under the cursor, if any.
+///
+/// The header row has index `0`.
+fn hovered_cell(
+    layout: Layout<'_>,
+    cursor_position: Point,
+) -> Option<(usize, usize)> {
+    layout
+        .children()
+        .enumerate()
+        .filter(|(_, row)| row.bounds().contains(cursor_position))
+        .find_map(|(row, layout)| {
+            layout
+                .children()
+                .position(|cell| cell.bounds().contains(cursor_position))
+                .map(|column| (row, column))
+        })
+}
+
+/// Returns the column whose divider in the header row is under the cursor,
+/// together with its current width.
+fn hovered_divider(
+    layout: Layout<'_>,
+    cursor_position: Point,
+    leeway: u16,
+) -> Option<(usize, f32)> {
+    let header = layout.children().next()?;
+    let bounds = header.bounds();
+    let leeway = f32::from(leeway.max(1)) / 2.0;
+
+    if cursor_position.y < bounds.y
+        || cursor_position.y > bounds.y + bounds.height
+    {
+        return None;
+    }
+
+    header.children().enumerate().find_map(|(column, cell)| {
+        let cell_bounds = cell.bounds();
+        let divider = cell_bounds.x + cell_bounds.width;
+
+        if (cursor_position.x - divider).abs() <= leeway {
+            Some((column, cell_bounds.width))
+        } else {
+            None
+        }
+    })
+}
+
+/// Draws the indicator of a sorted column as a stepped triangle.
+fn draw_sort_indicator<Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    sort: Sort,
+    color: Color,
+) where
+    Renderer: crate::Renderer,
+{
+    const STEPS: usize = 4;
+
+    let step_height = bounds.height / STEPS as f32;
+
+    for step in 0..STEPS {
+        let width = bounds.width * (step + 1) as f32 / STEPS as f32;
+
+        let y = match sort {
+            Sort::Ascending => bounds.y + step as f32 * step_height,
+            Sort::Descending => {
+                bounds.y + bounds.height - (step + 1) as f32 * step_height
+            }
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: bounds.center_x() - width / 2.0,
+                    y,
+                    width,
+                    height: step_height,
+                },
+                border_radius: 0.0.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            color,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Simulator;
+    use crate::widget::text;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Resize(usize, f32),
+    }
+
+    fn files() -> Simulator<'static, Message, renderer::Null> {
+        let table = Table::new(vec![
+            Column::new(text("Name")).width(Length::Units(100)),
+            Column::new(text("Size")).width(Length::Fill),
+        ])
+        .push(vec![text("a.txt").into(), text("1 KB").into()])
+        .push(vec![text("b.txt").into()])
+        .width(Length::Units(400))
+        .on_resize(10, |event| Message::Resize(event.column, event.width));
+
+        Simulator::with_size(table, Size::new(800.0, 600.0))
+    }
+
+    #[test]
+    fn cells_are_aligned_in_columns() {
+        let mut simulator = files();
+
+        // Text is 20 units tall, and every cell is padded by 5 units
+        assert_eq!(
+            simulator.find("1 KB"),
+            Ok(Rectangle::new(
+                Point::new(105.0, 35.0),
+                Size::new(0.0, 20.0)
+            ))
+        );
+        assert_eq!(
+            simulator.find("b.txt"),
+            Ok(Rectangle::new(Point::new(5.0, 65.0), Size::new(0.0, 20.0)))
+        );
+    }
+
+    #[test]
+    fn dragging_a_divider_resizes_its_column() {
+        let mut simulator = files();
+
+        let _ = simulator.point_at(Point::new(102.0, 15.0));
+        let _ = simulator.simulate([Event::Mouse(
+            mouse::Event::ButtonPressed(mouse::Button::Left),
+        )]);
+        let _ = simulator.point_at(Point::new(152.0, 15.0));
+        let _ = simulator.simulate([Event::Mouse(
+            mouse::Event::ButtonReleased(mouse::Button::Left),
+        )]);
+
+        assert_eq!(
+            simulator.messages().collect::<Vec<_>>(),
+            [Message::Resize(0, 150.0)]
+        );
+    }
+}
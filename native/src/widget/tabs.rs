@@ -0,0 +1,926 @@
+//! Switch between different views using a bar of tabs.
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::text::{self, Text};
+use crate::touch;
+use crate::widget::inspect;
+use crate::widget::pane_grid::drag;
+use crate::widget::tree::{self, Tree};
+use crate::widget::Operation;
+use crate::{
+    Background, Clipboard, Color, Element, Layout, Length, Padding, Point,
+    Rectangle, Shell, Size, Vector, Widget,
+};
+
+pub use iced_style::tabs::{Appearance, StyleSheet, TabAppearance};
+
+/// The glyph used to draw the close button of a tab.
+const CLOSE_ICON: &str = "×";
+
+/// A widget that displays a bar of tabs and the contents of the active one.
+///
+/// A [`Tabs`] widget supports:
+///
+/// * Closing tabs with a close button
+/// * Reordering tabs with drag and drop
+/// * Scrolling the tab bar when the tabs do not fit
+///
+/// Like a [`PaneGrid`], the [`Tabs`] widget does not keep the active tab nor
+/// the order of the tabs. Instead, it produces messages that can be used to
+/// update them.
+///
+/// [`PaneGrid`]: crate::widget::PaneGrid
+#[allow(missing_debug_implementations)]
+pub struct Tabs<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    tabs: Vec<Tab<'a, Message, Renderer>>,
+    active: usize,
+    width: Length,
+    height: Length,
+    padding: Padding,
+    spacing: u16,
+    on_select: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_close: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_drag: Option<Box<dyn Fn(DragEvent) -> Message + 'a>>,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+struct Tab<'a, Message, Renderer> {
+    label: Element<'a, Message, Renderer>,
+    content: Element<'a, Message, Renderer>,
+}
+
+impl<'a, Message, Renderer> Tabs<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`Tabs`] widget with no tabs, where the tab with the
+    /// given index is active.
+    pub fn new(active: usize) -> Self {
+        Tabs {
+            tabs: Vec::new(),
+            active,
+            width: Length::Fill,
+            height: Length::Fill,
+            padding: Padding::from([5, 10]),
+            spacing: 2,
+            on_select: None,
+            on_close: None,
+            on_drag: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Adds a tab with the given label and content to the [`Tabs`].
+    pub fn push(
+        mut self,
+        label: impl Into<Element<'a, Message, Renderer>>,
+        content: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        self.tabs.push(Tab {
+            label: label.into(),
+            content: content.into(),
+        });
+        self
+    }
+
+    /// Sets the width of the [`Tabs`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Tabs`].
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the [`Padding`] of every tab in the tab bar.
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the spacing between the tabs of the tab bar.
+    pub fn spacing(mut self, units: u16) -> Self {
+        self.spacing = units;
+        self
+    }
+
+    /// Sets the message that will be produced when a tab is clicked.
+    ///
+    /// The message receives the index of the clicked tab.
+    pub fn on_select(mut self, f: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_select = Some(Box::new(f));
+        self
+    }
+
+    /// Shows a close button in every tab, which will produce the message
+    /// returned by the given function when clicked.
+    ///
+    /// The message receives the index of the tab to close.
+    pub fn on_close(mut self, f: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_close = Some(Box::new(f));
+        self
+    }
+
+    /// Enables the drag and drop interactions of the [`Tabs`], which will
+    /// use the provided function to produce messages.
+    pub fn on_drag(mut self, f: impl Fn(DragEvent) -> Message + 'a) -> Self {
+        self.on_drag = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the style of the [`Tabs`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// An event produced during a drag and drop interaction of a [`Tabs`] widget.
+#[derive(Debug, Clone, Copy)]
+pub enum DragEvent {
+    /// A tab was picked for dragging.
+    Picked {
+        /// The index of the picked tab.
+        tab: usize,
+    },
+
+    /// A tab was dropped on top of another tab.
+    Dropped {
+        /// The index of the picked tab.
+        tab: usize,
+
+        /// The index of the tab where the picked one was dropped on.
+        target: usize,
+    },
+
+    /// A tab was picked and then dropped outside of other tabs.
+    Canceled {
+        /// The index of the picked tab.
+        tab: usize,
+    },
+}
+
+/// The local state of a [`Tabs`] widget.
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    offset: f32,
+    action: Action,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+enum Action {
+    #[default]
+    Idle,
+    Dragging {
+        tab: usize,
+        origin: Point,
+    },
+}
+
+impl State {
+    fn picked_tab(&self) -> Option<(usize, Point)> {
+        match self.action {
+            Action::Dragging { tab, origin } => Some((tab, origin)),
+            Action::Idle => None,
+        }
+    }
+
+    /// Returns the scroll offset of the given tab bar.
+    ///
+    /// The stored offset may be out of bounds if the tab bar has shrunk since
+    /// it was scrolled (e.g. after closing some tabs).
+    fn offset(&self, bar: Layout<'_>) -> f32 {
+        self.offset.min(max_offset(bar)).max(0.0)
+    }
+
+    /// Returns the position of the cursor relative to the scrolled tabs of
+    /// the given tab bar.
+    fn bar_cursor(&self, bar: Layout<'_>, cursor_position: Point) -> Point {
+        if bar.bounds().contains(cursor_position) {
+            cursor_position + Vector::new(self.offset(bar), 0.0)
+        } else {
+            Point::new(-1.0, -1.0)
+        }
+    }
+
+    fn scroll(&mut self, delta: f32, bar: Layout<'_>) {
+        self.offset = (self.offset(bar) - delta).min(max_offset(bar)).max(0.0);
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Tabs<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.tabs
+            .iter()
+            .flat_map(|tab| [&tab.label, &tab.content])
+            .map(Tree::new)
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        // The trees of every tab are kept next to each other, so the state
+        // of a label never ends up in a content when tabs are added or
+        // removed.
+        tree.diff_children(
+            &self
+                .tabs
+                .iter()
+                .flat_map(|tab| [&tab.label, &tab.content])
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+        let padding = self.padding;
+        let spacing = f32::from(self.spacing);
+        let close_size = f32::from(renderer.default_size());
+
+        let label_limits = layout::Limits::new(
+            Size::ZERO,
+            Size::new(f32::INFINITY, limits.max().height),
+        )
+        .pad(padding);
+
+        let labels: Vec<_> = self
+            .tabs
+            .iter()
+            .map(|tab| tab.label.as_widget().layout(renderer, &label_limits))
+            .collect();
+
+        let bar_height = labels
+            .iter()
+            .map(|label| label.size().height)
+            .fold(0.0, f32::max)
+            .max(if self.on_close.is_some() {
+                close_size
+            } else {
+                0.0
+            })
+            + f32::from(padding.vertical());
+
+        let mut x = 0.0;
+
+        let tabs: Vec<_> = labels
+            .into_iter()
+            .map(|mut label| {
+                let label_size = label.size();
+
+                label.move_to(Point::new(
+                    f32::from(padding.left),
+                    (bar_height - label_size.height) / 2.0,
+                ));
+
+                let mut width =
+                    label_size.width + f32::from(padding.horizontal());
+                let mut children = vec![label];
+
+                if self.on_close.is_some() {
+                    let mut close =
+                        layout::Node::new(Size::new(close_size, close_size));
+
+                    close.move_to(Point::new(
+                        width - f32::from(padding.right) + spacing,
+                        (bar_height - close_size) / 2.0,
+                    ));
+
+                    width += close_size + spacing;
+                    children.push(close);
+                }
+
+                let mut tab = layout::Node::with_children(
+                    Size::new(width, bar_height),
+                    children,
+                );
+                tab.move_to(Point::new(x, 0.0));
+
+                x += width + spacing;
+
+                tab
+            })
+            .collect();
+
+        let tabs_width = (x - spacing).max(0.0);
+
+        let content_limits = limits.shrink(Size::new(0.0, bar_height));
+
+        let mut content = match self.tabs.get(self.active) {
+            Some(tab) => {
+                tab.content.as_widget().layout(renderer, &content_limits)
+            }
+            None => layout::Node::new(Size::ZERO),
+        };
+        content.move_to(Point::new(0.0, bar_height));
+
+        let size = limits.resolve(Size::new(
+            tabs_width.max(content.size().width),
+            bar_height + content.size().height,
+        ));
+
+        let bar = layout::Node::with_children(
+            Size::new(size.width, bar_height),
+            tabs,
+        );
+
+        layout::Node::with_children(size, vec![bar, content])
+    }
+
+    fn inspect(&self, tree: &Tree, layout: Layout<'_>) -> inspect::Node {
+        inspect::Node::new::<Self>(layout.bounds(), self.width, self.height)
+            .children({
+                let (bar, content) = split(layout);
+
+                let labels = self
+                    .tabs
+                    .iter()
+                    .zip(tree.children.chunks(2))
+                    .zip(bar.children())
+                    .map(|((tab, states), layout)| {
+                        tab.label.as_widget().inspect(
+                            &states[0],
+                            layout.children().next().unwrap(),
+                        )
+                    });
+
+                let content = self
+                    .tabs
+                    .get(self.active)
+                    .zip(tree.children.get(2 * self.active + 1))
+                    .map(|(tab, state)| {
+                        tab.content.as_widget().inspect(state, content)
+                    });
+
+                labels.chain(content).collect::<Vec<_>>()
+            })
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let (bar, content) = split(layout);
+
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.tabs
+                .iter()
+                .zip(tree.children.chunks_mut(2))
+                .zip(bar.children())
+                .for_each(|((tab, states), layout)| {
+                    tab.label.as_widget().operate(
+                        &mut states[0],
+                        layout.children().next().unwrap(),
+                        operation,
+                    );
+                });
+
+            if let (Some(tab), Some(state)) = (
+                self.tabs.get(self.active),
+                tree.children.get_mut(2 * self.active + 1),
+            ) {
+                tab.content.as_widget().operate(state, content, operation);
+            }
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let (bar, content) = split(layout);
+        let state = tree.state.downcast_mut::<State>();
+
+        // Keep the offset within the tab bar, which may have shrunk since the
+        // last scroll.
+        state.offset = state.offset(bar);
+
+        let bar_cursor = state.bar_cursor(bar, cursor_position);
+
+        match event {
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if bar.bounds().contains(cursor_position) =>
+            {
+                let delta = match delta {
+                    mouse::ScrollDelta::Lines { x, y } => {
+                        // TODO: Configurable speed (?)
+                        (if x == 0.0 { y } else { x }) * 60.0
+                    }
+                    mouse::ScrollDelta::Pixels { x, y } => {
+                        if x == 0.0 {
+                            y
+                        } else {
+                            x
+                        }
+                    }
+                };
+
+                let offset = state.offset;
+
+                state.scroll(delta, bar);
+
+                // Let a parent scroll when the tab bar cannot
+                if state.offset != offset {
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let clicked =
+                    drag::hovered(bar.children().enumerate(), bar_cursor);
+
+                if let Some((index, tab)) = clicked {
+                    let is_over_close =
+                        tab.children().nth(1).is_some_and(|close| {
+                            close.bounds().contains(bar_cursor)
+                        });
+
+                    if is_over_close {
+                        if let Some(on_close) = &self.on_close {
+                            shell.publish(on_close(index));
+                        }
+
+                        return event::Status::Captured;
+                    }
+
+                    if index != self.active {
+                        if let Some(on_select) = &self.on_select {
+                            shell.publish(on_select(index));
+                        }
+                    }
+
+                    if let Some(on_drag) = &self.on_drag {
+                        state.action = Action::Dragging {
+                            tab: index,
+                            origin: drag::origin(tab, bar_cursor),
+                        };
+
+                        shell
+                            .publish(on_drag(DragEvent::Picked { tab: index }));
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                if let Some((tab, _)) = state.picked_tab() {
+                    if let Some(on_drag) = &self.on_drag {
+                        let target = drag::target(
+                            &tab,
+                            bar.children().enumerate(),
+                            bar_cursor,
+                        );
+
+                        let event = match target {
+                            Some(target) => DragEvent::Dropped { tab, target },
+                            None => DragEvent::Canceled { tab },
+                        };
+
+                        shell.publish(on_drag(event));
+                    }
+
+                    state.action = Action::Idle;
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. })
+                if state.picked_tab().is_some() =>
+            {
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        let status = self
+            .tabs
+            .iter_mut()
+            .zip(tree.children.chunks_mut(2))
+            .zip(bar.children())
+            .map(|((tab, states), layout)| {
+                tab.label.as_widget_mut().on_event(
+                    &mut states[0],
+                    event.clone(),
+                    layout.children().next().unwrap(),
+                    bar_cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        match (
+            self.tabs.get_mut(self.active),
+            tree.children.get_mut(2 * self.active + 1),
+        ) {
+            (Some(tab), Some(state)) => {
+                status.merge(tab.content.as_widget_mut().on_event(
+                    state,
+                    event,
+                    content,
+                    cursor_position,
+                    renderer,
+                    clipboard,
+                    shell,
+                ))
+            }
+            _ => status,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let (bar, content) = split(layout);
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.picked_tab().is_some() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let bar_cursor = state.bar_cursor(bar, cursor_position);
+
+        if let Some((_, tab)) =
+            drag::hovered(bar.children().enumerate(), bar_cursor)
+        {
+            let is_over_close = tab
+                .children()
+                .nth(1)
+                .is_some_and(|close| close.bounds().contains(bar_cursor));
+
+            return if !is_over_close && self.on_drag.is_some() {
+                mouse::Interaction::Grab
+            } else {
+                mouse::Interaction::Pointer
+            };
+        }
+
+        match (
+            self.tabs.get(self.active),
+            tree.children.get(2 * self.active + 1),
+        ) {
+            (Some(tab), Some(state)) => {
+                tab.content.as_widget().mouse_interaction(
+                    state,
+                    content,
+                    cursor_position,
+                    viewport,
+                    renderer,
+                )
+            }
+            _ => mouse::Interaction::default(),
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let (bar, content) = split(layout);
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = theme.appearance(&self.style);
+        let bar_bounds = bar.bounds();
+        let picked_tab = state.picked_tab();
+
+        let bar_cursor = if picked_tab.is_some() {
+            Point::new(-1.0, -1.0)
+        } else {
+            state.bar_cursor(bar, cursor_position)
+        };
+
+        if let Some((tab, state)) = self
+            .tabs
+            .get(self.active)
+            .zip(tree.children.get(2 * self.active + 1))
+        {
+            tab.content.as_widget().draw(
+                state,
+                renderer,
+                theme,
+                style,
+                content,
+                cursor_position,
+                viewport,
+            );
+        }
+
+        if let Some(background) = appearance.background {
+            fill(renderer, bar_bounds, background);
+        }
+
+        if appearance.divider_width > 0.0 {
+            fill(
+                renderer,
+                Rectangle {
+                    y: bar_bounds.y + bar_bounds.height
+                        - appearance.divider_width,
+                    height: appearance.divider_width,
+                    ..bar_bounds
+                },
+                appearance.divider_color,
+            );
+        }
+
+        let draw_tab =
+            |renderer: &mut Renderer, index: usize, tab: Layout<'_>| {
+                let bounds = tab.bounds();
+                let is_active = index == self.active;
+
+                let tab_appearance = if bounds.contains(bar_cursor) {
+                    theme.hovered(&self.style, is_active)
+                } else if is_active {
+                    theme.active(&self.style)
+                } else {
+                    theme.inactive(&self.style)
+                };
+
+                if tab_appearance.background.is_some()
+                    || tab_appearance.border_width > 0.0
+                {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds,
+                            border_radius: tab_appearance.border_radius.into(),
+                            border_width: tab_appearance.border_width,
+                            border_color: tab_appearance.border_color,
+                        },
+                        tab_appearance
+                            .background
+                            .unwrap_or(Background::Color(Color::TRANSPARENT)),
+                    );
+                }
+
+                let mut children = tab.children();
+
+                self.tabs[index].label.as_widget().draw(
+                    &tree.children[2 * index],
+                    renderer,
+                    theme,
+                    &renderer::Style {
+                        text_color: tab_appearance.text_color,
+                    },
+                    children.next().unwrap(),
+                    bar_cursor,
+                    viewport,
+                );
+
+                if let Some(close) = children.next() {
+                    let bounds = close.bounds();
+
+                    renderer.fill_text(Text {
+                        content: CLOSE_ICON,
+                        bounds: Rectangle {
+                            x: bounds.center_x(),
+                            y: bounds.center_y(),
+                            ..bounds
+                        },
+                        size: bounds.height,
+                        color: tab_appearance.close_color,
+                        font: Renderer::Font::default(),
+                        horizontal_alignment: alignment::Horizontal::Center,
+                        vertical_alignment: alignment::Vertical::Center,
+                    });
+                }
+            };
+
+        renderer.with_layer(bar_bounds, |renderer| {
+            renderer.with_translation(
+                Vector::new(-state.offset(bar), 0.0),
+                |renderer| {
+                    for (index, tab) in bar.children().enumerate() {
+                        if picked_tab.map(|(picked, _)| picked) != Some(index) {
+                            draw_tab(renderer, index, tab);
+                        }
+                    }
+                },
+            );
+        });
+
+        // Render picked tab last
+        if let Some((index, origin)) = picked_tab {
+            if let Some(tab) = bar.children().nth(index) {
+                drag::draw_picked(
+                    renderer,
+                    tab.bounds(),
+                    origin,
+                    cursor_position,
+                    |renderer| draw_tab(renderer, index, tab),
+                );
+            }
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let (_, content) = split(layout);
+        let state = tree.children.get_mut(2 * self.active + 1)?;
+        let tab = self.tabs.get_mut(self.active)?;
+
+        tab.content
+            .as_widget_mut()
+            .overlay(state, content, renderer)
+    }
+}
+
+impl<'a, Message, Renderer> From<Tabs<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(tabs: Tabs<'a, Message, Renderer>) -> Self {
+        Self::new(tabs)
+    }
+}
+
+/// Returns the maximum scroll offset of the given tab bar.
+fn max_offset(bar: Layout<'_>) -> f32 {
+    let bounds = bar.bounds();
+    let tabs_width = bar
+        .children()
+        .last()
+        .map(|tab| tab.bounds().x + tab.bounds().width - bounds.x)
+        .unwrap_or(0.0);
+
+    tabs_width - bounds.width
+}
+
+/// Returns the layouts of the tab bar and the content of a [`Tabs`] widget.
+fn split(layout: Layout<'_>) -> (Layout<'_>, Layout<'_>) {
+    let mut children = layout.children();
+
+    (children.next().unwrap(), children.next().unwrap())
+}
+
+fn fill<Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    background: impl Into<Background>,
+) where
+    Renderer: crate::Renderer,
+{
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border_radius: 0.0.into(),
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        },
+        background,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Simulator;
+    use crate::widget::Text;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Close(usize),
+        Drag(String),
+        Scroll(f32),
+    }
+
+    fn tabs() -> Tabs<'static, Message, renderer::Null> {
+        Tabs::new(0)
+            .push(Text::new("A"), Text::new("Content of A"))
+            .push(Text::new("B"), Text::new("Content of B"))
+            .on_close(Message::Close)
+    }
+
+    #[test]
+    fn clicking_a_close_button_closes_its_tab() {
+        let mut simulator =
+            Simulator::with_size(tabs(), Size::new(400.0, 400.0));
+
+        // Every tab is padded by 10 units horizontally, and has a close
+        // button 20 units wide after 2 units of spacing
+        assert_eq!(
+            simulator.find("B"),
+            Ok(Rectangle::new(Point::new(54.0, 5.0), Size::new(0.0, 20.0)))
+        );
+        assert!(simulator.find("Content of B").is_err());
+
+        let _ = simulator.point_at(Point::new(66.0, 15.0));
+        let _ = simulator.simulate([
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+        ]);
+
+        assert_eq!(
+            simulator.messages().collect::<Vec<_>>(),
+            [Message::Close(1)]
+        );
+    }
+
+    #[test]
+    fn scrolling_a_tab_bar_without_overflow_scrolls_its_parent() {
+        let tabs = tabs().height(Length::Units(1000));
+        let scrollable =
+            crate::widget::Scrollable::new(tabs).on_scroll(Message::Scroll);
+
+        let mut simulator =
+            Simulator::with_size(scrollable, Size::new(400.0, 400.0));
+
+        let _ = simulator.point_at(Point::new(10.0, 15.0));
+        let _ =
+            simulator.scroll(mouse::ScrollDelta::Pixels { x: 0.0, y: -300.0 });
+
+        assert_eq!(
+            simulator.messages().collect::<Vec<_>>(),
+            [Message::Scroll(0.5)]
+        );
+    }
+
+    #[test]
+    fn dragging_a_tab_onto_another_drops_it() {
+        let tabs =
+            tabs().on_drag(|event| Message::Drag(format!("{:?}", event)));
+        let mut simulator = Simulator::with_size(tabs, Size::new(400.0, 400.0));
+
+        let _ = simulator.point_at(Point::new(10.0, 15.0));
+        let _ = simulator.simulate([Event::Mouse(
+            mouse::Event::ButtonPressed(mouse::Button::Left),
+        )]);
+        let _ = simulator.point_at(Point::new(60.0, 15.0));
+        let _ = simulator.simulate([Event::Mouse(
+            mouse::Event::ButtonReleased(mouse::Button::Left),
+        )]);
+
+        assert_eq!(
+            simulator.messages().collect::<Vec<_>>(),
+            [
+                Message::Drag(String::from("Picked { tab: 0 }")),
+                Message::Drag(String::from("Dropped { tab: 0, target: 1 }")),
+            ]
+        );
+    }
+}
@@ -3,7 +3,7 @@ use crate::alignment;
 use crate::layout;
 use crate::renderer;
 use crate::text;
-use crate::widget::Tree;
+use crate::widget::{Operation, Tree};
 use crate::{Element, Layout, Length, Point, Rectangle, Size, Widget};
 
 use std::borrow::Cow;
@@ -146,6 +146,15 @@ where
         layout::Node::new(size)
     }
 
+    fn operate(
+        &self,
+        _state: &mut Tree,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.text(None, layout.bounds(), &self.content);
+    }
+
     fn draw(
         &self,
         _state: &Tree,
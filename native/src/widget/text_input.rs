@@ -20,6 +20,7 @@ use crate::renderer;
 use crate::text::{self, Text};
 use crate::touch;
 use crate::widget;
+use crate::widget::inspect;
 use crate::widget::operation::{self, Operation};
 use crate::widget::tree::{self, Tree};
 use crate::{
@@ -224,16 +225,31 @@ where
         layout(renderer, limits, self.width, self.padding, self.size)
     }
 
+    fn inspect(&self, _tree: &Tree, layout: Layout<'_>) -> inspect::Node {
+        inspect::Node::new::<Self>(layout.bounds(), self.width, Length::Shrink)
+            .id(self.id.as_ref().map(|id| &id.0))
+            .padding(self.padding)
+    }
+
     fn operate(
         &self,
         tree: &mut Tree,
-        _layout: Layout<'_>,
+        layout: Layout<'_>,
         operation: &mut dyn Operation<Message>,
     ) {
         let state = tree.state.downcast_mut::<State>();
+        let id = self.id.as_ref().map(|id| &id.0);
+
+        operation.focusable(state, id);
+        operation.text_input(state, id);
+
+        let value = if self.is_secure {
+            self.value.secure()
+        } else {
+            self.value.clone()
+        };
 
-        operation.focusable(state, self.id.as_ref().map(|id| &id.0));
-        operation.text_input(state, self.id.as_ref().map(|id| &id.0));
+        operation.text(id, layout.bounds(), &value.to_string());
     }
 
     fn on_event(
@@ -487,6 +503,12 @@ where
                     }
                 }
 
+                if !state.is_dragging {
+                    write_selection_to_primary(
+                        clipboard, state, value, is_secure,
+                    );
+                }
+
                 state.last_click = Some(click);
 
                 return event::Status::Captured;
@@ -495,7 +517,67 @@ where
         Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
         | Event::Touch(touch::Event::FingerLifted { .. })
         | Event::Touch(touch::Event::FingerLost { .. }) => {
-            state().is_dragging = false;
+            let state = state();
+
+            if state.is_dragging {
+                state.is_dragging = false;
+
+                write_selection_to_primary(clipboard, state, value, is_secure);
+            }
+        }
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Middle)) => {
+            let state = state();
+
+            if layout.bounds().contains(cursor_position) {
+                let content: String = clipboard
+                    .read_primary()
+                    .unwrap_or_default()
+                    .chars()
+                    .filter(|c| !c.is_control())
+                    .collect();
+
+                if content.is_empty() {
+                    return event::Status::Ignored;
+                }
+
+                let text_layout = layout.children().next().unwrap();
+                let target = cursor_position.x - text_layout.bounds().x;
+
+                let position = if target > 0.0 {
+                    let value = if is_secure {
+                        value.secure()
+                    } else {
+                        value.clone()
+                    };
+
+                    find_cursor_position(
+                        renderer,
+                        text_layout.bounds(),
+                        font.clone(),
+                        size,
+                        &value,
+                        state,
+                        target,
+                    )
+                } else {
+                    None
+                };
+
+                state.is_focused = true;
+                state.cursor.move_to(position.unwrap_or(0));
+
+                let mut editor = Editor::new(value, &mut state.cursor);
+                editor.paste(Value::new(&content));
+
+                let message = if let Some(paste) = &on_paste {
+                    (paste)(editor.contents())
+                } else {
+                    (on_change)(editor.contents())
+                };
+                shell.publish(message);
+
+                return event::Status::Captured;
+            }
         }
         Event::Mouse(mouse::Event::CursorMoved { position })
         | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
@@ -552,6 +634,7 @@ where
 
             if state.is_focused {
                 let modifiers = state.keyboard_modifiers;
+                let selection = state.cursor.selection(value);
 
                 match key_code {
                     keyboard::KeyCode::Enter
@@ -727,6 +810,12 @@ where
                     _ => {}
                 }
 
+                if state.cursor.selection(value) != selection {
+                    write_selection_to_primary(
+                        clipboard, state, value, is_secure,
+                    );
+                }
+
                 return event::Status::Captured;
             }
         }
@@ -1147,3 +1236,22 @@ where
         )
         .map(text::Hit::cursor)
 }
+
+/// Writes the current selection of a [`TextInput`] to the primary selection
+/// of the [`Clipboard`].
+///
+/// The contents of secure inputs are never exposed.
+fn write_selection_to_primary(
+    clipboard: &mut dyn Clipboard,
+    state: &State,
+    value: &Value,
+    is_secure: bool,
+) {
+    if is_secure {
+        return;
+    }
+
+    if let Some((start, end)) = state.cursor.selection(value) {
+        clipboard.write_primary(value.select(start, end).to_string());
+    }
+}
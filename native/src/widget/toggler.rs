@@ -1,11 +1,17 @@
 //! Show toggle controls using togglers.
 use crate::alignment;
+use crate::animation;
+use crate::animation::Interpolate;
 use crate::event;
+use crate::keyboard;
 use crate::layout;
 use crate::mouse;
 use crate::renderer;
 use crate::text;
-use crate::widget::{self, Row, Text, Tree};
+use crate::touch;
+use crate::widget::operation::{self, Operation};
+use crate::widget::tree::{self, Tree};
+use crate::widget::{self, Row, Text};
 use crate::{
     Alignment, Clipboard, Element, Event, Layout, Length, Point, Rectangle,
     Shell, Widget,
@@ -138,6 +144,21 @@ where
     Renderer: text::Renderer,
     Renderer::Theme: StyleSheet + widget::text::StyleSheet,
 {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            is_focused: false,
+            animation: animation::State::new(Status {
+                is_hovered: false,
+                is_active: self.is_active,
+                is_focused: false,
+            }),
+        })
+    }
+
     fn width(&self) -> Length {
         self.width
     }
@@ -178,9 +199,18 @@ where
         row.layout(renderer, limits)
     }
 
+    fn operate(
+        &self,
+        state: &mut Tree,
+        _layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.focusable(state.state.downcast_mut::<State>(), None);
+    }
+
     fn on_event(
         &mut self,
-        _state: &mut Tree,
+        state: &mut Tree,
         event: Event,
         layout: Layout<'_>,
         cursor_position: Point,
@@ -188,20 +218,60 @@ where
         _clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
-        match event {
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
-                let mouse_over = layout.bounds().contains(cursor_position);
+        let state = state.state.downcast_mut::<State>();
+        let mut is_active = self.is_active;
+
+        // Using the mouse dismisses the keyboard focus
+        if let Event::Mouse(mouse::Event::ButtonPressed(_))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            state.is_focused = false;
+        }
+
+        let status = match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                if layout.bounds().contains(cursor_position) =>
+            {
+                shell.publish((self.on_toggle)(!self.is_active));
+
+                // Start transitioning right away, expecting the toggle to
+                // be accepted
+                is_active = !is_active;
 
-                if mouse_over {
-                    shell.publish((self.on_toggle)(!self.is_active));
+                event::Status::Captured
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Space | keyboard::KeyCode::Enter,
+                ..
+            }) if state.is_focused => {
+                shell.publish((self.on_toggle)(!self.is_active));
+
+                is_active = !is_active;
 
-                    event::Status::Captured
-                } else {
-                    event::Status::Ignored
-                }
+                event::Status::Captured
             }
             _ => event::Status::Ignored,
-        }
+        };
+
+        let is_hovered = layout
+            .children()
+            .last()
+            .map(|toggler_layout| {
+                toggler_layout.bounds().contains(cursor_position)
+            })
+            .unwrap_or(false);
+
+        state.animation.update(
+            Status {
+                is_hovered,
+                is_active,
+                is_focused: state.is_focused,
+            },
+            &event,
+            shell,
+        );
+
+        status
     }
 
     fn mouse_interaction(
@@ -221,7 +291,7 @@ where
 
     fn draw(
         &self,
-        _state: &Tree,
+        state: &Tree,
         renderer: &mut Renderer,
         theme: &Renderer::Theme,
         style: &renderer::Style,
@@ -259,10 +329,46 @@ where
 
         let is_mouse_over = bounds.contains(cursor_position);
 
-        let style = if is_mouse_over {
-            theme.hovered(&self.style, self.is_active)
+        let state = state.state.downcast_ref::<State>();
+
+        let status = Status {
+            is_hovered: is_mouse_over,
+            is_active: self.is_active,
+            is_focused: state.is_focused,
+        };
+
+        let appearance = |status: Status| {
+            if status.is_focused {
+                theme.focused(&self.style, status.is_active)
+            } else if status.is_hovered {
+                theme.hovered(&self.style, status.is_active)
+            } else {
+                theme.active(&self.style, status.is_active)
+            }
+        };
+
+        // The position of the foreground Quad, from `0.0` (inactive) to
+        // `1.0` (active)
+        let position = |status: Status| {
+            if status.is_active {
+                1.0
+            } else {
+                0.0
+            }
+        };
+
+        let animation = &state.animation;
+
+        // The animation may lag behind until the next event is processed
+        let (style, position) = if animation.status() == status {
+            let transition = theme.transition(&self.style);
+
+            (
+                animation.interpolate(transition, appearance),
+                animation.interpolate(transition, position),
+            )
         } else {
-            theme.active(&self.style, self.is_active)
+            (appearance(status), position(status))
         };
 
         let border_radius = bounds.height as f32 / BORDER_RADIUS_RATIO;
@@ -289,11 +395,12 @@ where
 
         let toggler_foreground_bounds = Rectangle {
             x: bounds.x
-                + if self.is_active {
-                    bounds.width - 2.0 * space - (bounds.height - (4.0 * space))
-                } else {
-                    2.0 * space
-                },
+                + (2.0 * space).interpolate(
+                    &(bounds.width
+                        - 2.0 * space
+                        - (bounds.height - (4.0 * space))),
+                    position,
+                ),
             y: bounds.y + (2.0 * space),
             width: bounds.height - (4.0 * space),
             height: bounds.height - (4.0 * space),
@@ -326,3 +433,32 @@ where
         Element::new(toggler)
     }
 }
+
+/// The local state of a [`Toggler`].
+#[derive(Debug, Clone, PartialEq, Default)]
+struct State {
+    is_focused: bool,
+    animation: animation::State<Status>,
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+/// The status of a [`Toggler`], which determines its [`Appearance`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+struct Status {
+    is_hovered: bool,
+    is_active: bool,
+    is_focused: bool,
+}
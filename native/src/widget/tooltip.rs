@@ -2,39 +2,41 @@
 use crate::event;
 use crate::layout;
 use crate::mouse;
+use crate::overlay;
 use crate::renderer;
-use crate::text;
-use crate::widget;
+use crate::time::{Duration, Instant};
 use crate::widget::container;
-use crate::widget::overlay;
-use crate::widget::{Text, Tree};
+use crate::widget::inspect;
+use crate::widget::tree::{self, Tree};
+use crate::widget::Operation;
 use crate::{
     Clipboard, Element, Event, Layout, Length, Padding, Point, Rectangle,
-    Shell, Size, Vector, Widget,
+    Shell, Size, Widget,
 };
 
-use std::borrow::Cow;
-
 /// An element to display a widget over another.
 #[allow(missing_debug_implementations)]
-pub struct Tooltip<'a, Message, Renderer: text::Renderer>
+pub struct Tooltip<'a, Message, Renderer>
 where
-    Renderer: text::Renderer,
-    Renderer::Theme: container::StyleSheet + widget::text::StyleSheet,
+    Renderer: crate::Renderer,
+    Renderer::Theme: container::StyleSheet,
 {
     content: Element<'a, Message, Renderer>,
-    tooltip: Text<'a, Renderer>,
+    tooltip: Element<'a, Message, Renderer>,
     position: Position,
     gap: u16,
     padding: u16,
     snap_within_viewport: bool,
+    show_delay: Duration,
+    hide_delay: Duration,
+    is_interactive: bool,
     style: <Renderer::Theme as container::StyleSheet>::Style,
 }
 
 impl<'a, Message, Renderer> Tooltip<'a, Message, Renderer>
 where
-    Renderer: text::Renderer,
-    Renderer::Theme: container::StyleSheet + widget::text::StyleSheet,
+    Renderer: crate::Renderer,
+    Renderer::Theme: container::StyleSheet,
 {
     /// The default padding of a [`Tooltip`] drawn by this renderer.
     const DEFAULT_PADDING: u16 = 5;
@@ -44,34 +46,23 @@ where
     /// [`Tooltip`]: struct.Tooltip.html
     pub fn new(
         content: impl Into<Element<'a, Message, Renderer>>,
-        tooltip: impl Into<Cow<'a, str>>,
+        tooltip: impl Into<Element<'a, Message, Renderer>>,
         position: Position,
     ) -> Self {
         Tooltip {
             content: content.into(),
-            tooltip: Text::new(tooltip),
+            tooltip: tooltip.into(),
             position,
             gap: 0,
             padding: Self::DEFAULT_PADDING,
             snap_within_viewport: true,
+            show_delay: Duration::ZERO,
+            hide_delay: Duration::ZERO,
+            is_interactive: false,
             style: Default::default(),
         }
     }
 
-    /// Sets the size of the text of the [`Tooltip`].
-    pub fn size(mut self, size: u16) -> Self {
-        self.tooltip = self.tooltip.size(size);
-        self
-    }
-
-    /// Sets the font of the [`Tooltip`].
-    ///
-    /// [`Font`]: Renderer::Font
-    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
-        self.tooltip = self.tooltip.font(font);
-        self
-    }
-
     /// Sets the gap between the content and its [`Tooltip`].
     pub fn gap(mut self, gap: u16) -> Self {
         self.gap = gap;
@@ -90,6 +81,30 @@ where
         self
     }
 
+    /// Sets the time the content must be hovered before the [`Tooltip`] is
+    /// shown.
+    pub fn show_delay(mut self, delay: Duration) -> Self {
+        self.show_delay = delay;
+        self
+    }
+
+    /// Sets the time the [`Tooltip`] stays open after the cursor leaves the
+    /// content.
+    pub fn hide_delay(mut self, delay: Duration) -> Self {
+        self.hide_delay = delay;
+        self
+    }
+
+    /// Sets whether the [`Tooltip`] stays open while it is hovered and
+    /// forwards events to its contents, so it can contain links or buttons.
+    ///
+    /// A [`hide_delay`](Self::hide_delay) gives the user time to move the
+    /// cursor from the content to an interactive [`Tooltip`].
+    pub fn interactive(mut self, is_interactive: bool) -> Self {
+        self.is_interactive = is_interactive;
+        self
+    }
+
     /// Sets the style of the [`Tooltip`].
     pub fn style(
         mut self,
@@ -100,18 +115,78 @@ where
     }
 }
 
+/// The local state of a [`Tooltip`].
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    visibility: Visibility,
+    cursor_position: Point,
+    is_over_tooltip: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+enum Visibility {
+    #[default]
+    Hidden,
+    Showing(Instant),
+    Shown,
+    Hiding(Instant),
+}
+
+impl Visibility {
+    fn is_visible(self) -> bool {
+        matches!(self, Visibility::Shown | Visibility::Hiding(_))
+    }
+
+    fn update(
+        self,
+        is_hovered: bool,
+        show_delay: Duration,
+        hide_delay: Duration,
+        now: Instant,
+    ) -> Self {
+        match (self, is_hovered) {
+            (Visibility::Hidden, true) if show_delay.is_zero() => {
+                Visibility::Shown
+            }
+            (Visibility::Hidden, true) => Visibility::Showing(now + show_delay),
+            (Visibility::Showing(at), true) if now >= at => Visibility::Shown,
+            (Visibility::Showing(at), true) => Visibility::Showing(at),
+            (Visibility::Shown | Visibility::Hiding(_), true) => {
+                Visibility::Shown
+            }
+            (Visibility::Hidden | Visibility::Showing(_), false) => {
+                Visibility::Hidden
+            }
+            (Visibility::Shown, false) if hide_delay.is_zero() => {
+                Visibility::Hidden
+            }
+            (Visibility::Shown, false) => Visibility::Hiding(now + hide_delay),
+            (Visibility::Hiding(at), false) if now >= at => Visibility::Hidden,
+            (Visibility::Hiding(at), false) => Visibility::Hiding(at),
+        }
+    }
+}
+
 impl<'a, Message, Renderer> Widget<Message, Renderer>
     for Tooltip<'a, Message, Renderer>
 where
-    Renderer: text::Renderer,
-    Renderer::Theme: container::StyleSheet + widget::text::StyleSheet,
+    Renderer: crate::Renderer,
+    Renderer::Theme: container::StyleSheet,
 {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
     fn children(&self) -> Vec<Tree> {
-        vec![Tree::new(&self.content)]
+        vec![Tree::new(&self.content), Tree::new(&self.tooltip)]
     }
 
     fn diff(&self, tree: &mut Tree) {
-        tree.diff_children(std::slice::from_ref(&self.content))
+        tree.diff_children(&[&self.content, &self.tooltip])
     }
 
     fn width(&self) -> Length {
@@ -130,6 +205,28 @@ where
         self.content.as_widget().layout(renderer, limits)
     }
 
+    fn inspect(&self, tree: &Tree, layout: Layout<'_>) -> inspect::Node {
+        inspect::Node::new::<Self>(
+            layout.bounds(),
+            self.content.as_widget().width(),
+            self.content.as_widget().height(),
+        )
+        .children([self.content.as_widget().inspect(&tree.children[0], layout)])
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            operation,
+        );
+    }
+
     fn on_event(
         &mut self,
         tree: &mut Tree,
@@ -140,7 +237,7 @@ where
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
-        self.content.as_widget_mut().on_event(
+        let status = self.content.as_widget_mut().on_event(
             &mut tree.children[0],
             event,
             layout,
@@ -148,7 +245,48 @@ where
             renderer,
             clipboard,
             shell,
-        )
+        );
+
+        let state = tree.state.downcast_mut::<State>();
+        let was_visible = state.visibility.is_visible();
+        let is_over_content = layout.bounds().contains(cursor_position);
+
+        if is_over_content {
+            if was_visible
+                && self.position == Position::FollowCursor
+                && state.cursor_position != cursor_position
+            {
+                shell.invalidate_overlay();
+            }
+
+            state.cursor_position = cursor_position;
+        }
+
+        let is_hovered = is_over_content
+            || (self.is_interactive && was_visible && state.is_over_tooltip);
+
+        state.visibility = state.visibility.update(
+            is_hovered,
+            self.show_delay,
+            self.hide_delay,
+            Instant::now(),
+        );
+
+        match state.visibility {
+            Visibility::Showing(at) | Visibility::Hiding(at) => {
+                shell.request_redraw(at);
+            }
+            Visibility::Hidden => {
+                state.is_over_tooltip = false;
+            }
+            Visibility::Shown => {}
+        }
+
+        if state.visibility.is_visible() != was_visible {
+            shell.invalidate_overlay();
+        }
+
+        status
     }
 
     fn mouse_interaction(
@@ -187,37 +325,6 @@ where
             cursor_position,
             viewport,
         );
-
-        let tooltip = &self.tooltip;
-
-        draw(
-            renderer,
-            theme,
-            inherited_style,
-            layout,
-            cursor_position,
-            viewport,
-            self.position,
-            self.gap,
-            self.padding,
-            self.snap_within_viewport,
-            &self.style,
-            |renderer, limits| {
-                Widget::<(), Renderer>::layout(tooltip, renderer, limits)
-            },
-            |renderer, defaults, layout, cursor_position, viewport| {
-                Widget::<(), Renderer>::draw(
-                    tooltip,
-                    &Tree::empty(),
-                    renderer,
-                    theme,
-                    defaults,
-                    layout,
-                    cursor_position,
-                    viewport,
-                );
-            },
-        );
     }
 
     fn overlay<'b>(
@@ -226,11 +333,36 @@ where
         layout: Layout<'_>,
         renderer: &Renderer,
     ) -> Option<overlay::Element<'b, Message, Renderer>> {
-        self.content.as_widget_mut().overlay(
-            &mut tree.children[0],
+        let state = tree.state.downcast_mut::<State>();
+        let (content, tooltip) = tree.children.split_at_mut(1);
+
+        if let Some(overlay) = self.content.as_widget_mut().overlay(
+            &mut content[0],
             layout,
             renderer,
-        )
+        ) {
+            return Some(overlay);
+        }
+
+        if !state.visibility.is_visible() {
+            return None;
+        }
+
+        Some(overlay::Element::new(
+            Point::ORIGIN,
+            Box::new(Overlay {
+                tooltip: &mut self.tooltip,
+                tree: &mut tooltip[0],
+                state,
+                content_bounds: layout.bounds(),
+                position: self.position,
+                gap: self.gap,
+                padding: self.padding,
+                snap_within_viewport: self.snap_within_viewport,
+                is_interactive: self.is_interactive,
+                style: &self.style,
+            }),
+        ))
     }
 }
 
@@ -238,8 +370,8 @@ impl<'a, Message, Renderer> From<Tooltip<'a, Message, Renderer>>
     for Element<'a, Message, Renderer>
 where
     Message: 'a,
-    Renderer: 'a + text::Renderer,
-    Renderer::Theme: container::StyleSheet + widget::text::StyleSheet,
+    Renderer: 'a + crate::Renderer,
+    Renderer::Theme: container::StyleSheet,
 {
     fn from(
         tooltip: Tooltip<'a, Message, Renderer>,
@@ -249,6 +381,9 @@ where
 }
 
 /// The position of the tooltip. Defaults to following the cursor.
+///
+/// When the tooltip does not fit in the viewport at the given position, it
+/// is flipped to the opposite side if it fits there.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Position {
     /// The tooltip will follow the cursor.
@@ -263,127 +398,272 @@ pub enum Position {
     Right,
 }
 
-/// Draws a [`Tooltip`].
-pub fn draw<Renderer>(
-    renderer: &mut Renderer,
-    theme: &Renderer::Theme,
-    inherited_style: &renderer::Style,
-    layout: Layout<'_>,
-    cursor_position: Point,
-    viewport: &Rectangle,
+struct Overlay<'a, 'b, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: container::StyleSheet,
+{
+    tooltip: &'b mut Element<'a, Message, Renderer>,
+    tree: &'b mut Tree,
+    state: &'b mut State,
+    content_bounds: Rectangle,
     position: Position,
     gap: u16,
     padding: u16,
     snap_within_viewport: bool,
-    style: &<Renderer::Theme as container::StyleSheet>::Style,
-    layout_text: impl FnOnce(&Renderer, &layout::Limits) -> layout::Node,
-    draw_text: impl FnOnce(
-        &mut Renderer,
-        &renderer::Style,
-        Layout<'_>,
-        Point,
-        &Rectangle,
-    ),
-) where
+    is_interactive: bool,
+    style: &'b <Renderer::Theme as container::StyleSheet>::Style,
+}
+
+impl<'a, 'b, Message, Renderer> Overlay<'a, 'b, Message, Renderer>
+where
     Renderer: crate::Renderer,
     Renderer::Theme: container::StyleSheet,
 {
-    use container::StyleSheet;
+    fn origin(&self, position: Position, size: Size, flipped: bool) -> Point {
+        let bounds = self.content_bounds;
+        let cursor = self.state.cursor_position;
+        let gap = f32::from(self.gap);
+
+        let x_center = bounds.center_x() - size.width / 2.0;
+        let y_center = bounds.center_y() - size.height / 2.0;
+
+        match position {
+            Position::Top => Point::new(x_center, bounds.y - gap - size.height),
+            Position::Bottom => {
+                Point::new(x_center, bounds.y + bounds.height + gap)
+            }
+            Position::Left => Point::new(bounds.x - gap - size.width, y_center),
+            Position::Right => {
+                Point::new(bounds.x + bounds.width + gap, y_center)
+            }
+            Position::FollowCursor if flipped => {
+                Point::new(cursor.x, cursor.y + gap)
+            }
+            Position::FollowCursor => {
+                Point::new(cursor.x, cursor.y - gap - size.height)
+            }
+        }
+    }
+}
+
+impl<'a, 'b, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for Overlay<'a, 'b, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: container::StyleSheet,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let viewport = Rectangle::with_size(bounds);
+
+        // The content bounds and the cursor position are relative to the
+        // widget, while the overlay is translated by its ancestors (e.g. a
+        // scrolled `Scrollable`).
+        let translation = position - Point::ORIGIN;
+        let padding = f32::from(self.padding);
+
+        let limits = layout::Limits::new(
+            Size::ZERO,
+            if self.snap_within_viewport {
+                bounds
+            } else {
+                Size::INFINITY
+            },
+        )
+        .pad(Padding::new(self.padding));
+
+        let mut content = self.tooltip.as_widget().layout(renderer, &limits);
+        content.move_to(Point::new(padding, padding));
+
+        let size = content.size().pad(Padding::new(self.padding));
+
+        let fits = |position: Position, origin: Point| match position {
+            Position::Left | Position::Right => {
+                origin.x >= viewport.x
+                    && origin.x + size.width <= viewport.x + viewport.width
+            }
+            Position::Top | Position::Bottom | Position::FollowCursor => {
+                origin.y >= viewport.y
+                    && origin.y + size.height <= viewport.y + viewport.height
+            }
+        };
+
+        let opposite = match self.position {
+            Position::Top => Position::Bottom,
+            Position::Bottom => Position::Top,
+            Position::Left => Position::Right,
+            Position::Right => Position::Left,
+            Position::FollowCursor => Position::FollowCursor,
+        };
+
+        let mut origin = self.origin(self.position, size, false) + translation;
 
-    let bounds = layout.bounds();
+        if !fits(self.position, origin) {
+            let flipped = self.origin(opposite, size, true) + translation;
 
-    if bounds.contains(cursor_position) {
-        let gap = f32::from(gap);
-        let style = theme.appearance(style);
+            if fits(opposite, flipped) {
+                origin = flipped;
+            }
+        }
+
+        if self.snap_within_viewport {
+            origin.x = origin
+                .x
+                .min(viewport.x + viewport.width - size.width)
+                .max(viewport.x);
+
+            origin.y = origin
+                .y
+                .min(viewport.y + viewport.height - size.height)
+                .max(viewport.y);
+        }
+
+        let mut node = layout::Node::with_children(size, vec![content]);
+        node.move_to(origin);
+
+        node
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.tooltip.as_widget().operate(
+            self.tree,
+            layout.children().next().unwrap(),
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if let Event::Mouse(mouse::Event::CursorMoved { .. }) = event {
+            self.state.is_over_tooltip =
+                layout.bounds().contains(cursor_position);
+        }
+
+        if !self.is_interactive {
+            return event::Status::Ignored;
+        }
+
+        self.tooltip.as_widget_mut().on_event(
+            self.tree,
+            event,
+            layout.children().next().unwrap(),
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if !self.is_interactive {
+            return mouse::Interaction::default();
+        }
+
+        self.tooltip.as_widget().mouse_interaction(
+            self.tree,
+            layout.children().next().unwrap(),
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        inherited_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) {
+        use container::StyleSheet;
+
+        let bounds = layout.bounds();
+        let appearance = theme.appearance(self.style);
 
         let defaults = renderer::Style {
-            text_color: style.text_color.unwrap_or(inherited_style.text_color),
+            text_color: appearance
+                .text_color
+                .unwrap_or(inherited_style.text_color),
         };
 
-        let text_layout = layout_text(
+        container::draw_background(renderer, &appearance, bounds);
+
+        self.tooltip.as_widget().draw(
+            self.tree,
             renderer,
-            &layout::Limits::new(
-                Size::ZERO,
-                snap_within_viewport
-                    .then(|| viewport.size())
-                    .unwrap_or(Size::INFINITY),
-            )
-            .pad(Padding::new(padding)),
+            theme,
+            &defaults,
+            layout.children().next().unwrap(),
+            cursor_position,
+            &bounds,
         );
+    }
+}
 
-        let padding = f32::from(padding);
-        let text_bounds = text_layout.bounds();
-        let x_center = bounds.x + (bounds.width - text_bounds.width) / 2.0;
-        let y_center = bounds.y + (bounds.height - text_bounds.height) / 2.0;
-
-        let mut tooltip_bounds = {
-            let offset = match position {
-                Position::Top => Vector::new(
-                    x_center,
-                    bounds.y - text_bounds.height - gap - padding,
-                ),
-                Position::Bottom => Vector::new(
-                    x_center,
-                    bounds.y + bounds.height + gap + padding,
-                ),
-                Position::Left => Vector::new(
-                    bounds.x - text_bounds.width - gap - padding,
-                    y_center,
-                ),
-                Position::Right => Vector::new(
-                    bounds.x + bounds.width + gap + padding,
-                    y_center,
-                ),
-                Position::FollowCursor => Vector::new(
-                    cursor_position.x,
-                    cursor_position.y - text_bounds.height,
-                ),
-            };
-
-            Rectangle {
-                x: offset.x - padding,
-                y: offset.y - padding,
-                width: text_bounds.width + padding * 2.0,
-                height: text_bounds.height + padding * 2.0,
-            }
-        };
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer;
+    use crate::testing::Simulator;
+    use crate::widget::operation::scrollable::scroll_to;
+    use crate::widget::{container, scrollable, Column, Container, Scrollable};
+    use crate::widget::{Id, Space};
+
+    #[test]
+    fn tooltips_follow_their_content_when_scrolled() {
+        let tooltip = Tooltip::new(
+            Space::new(Length::Units(100), Length::Units(20)),
+            Container::new(Space::new(Length::Units(10), Length::Units(10)))
+                .id(container::Id::new("tip")),
+            Position::Bottom,
+        )
+        .padding(0);
 
-        if snap_within_viewport {
-            if tooltip_bounds.x < viewport.x {
-                tooltip_bounds.x = viewport.x;
-            } else if viewport.x + viewport.width
-                < tooltip_bounds.x + tooltip_bounds.width
-            {
-                tooltip_bounds.x =
-                    viewport.x + viewport.width - tooltip_bounds.width;
-            }
+        let content = Column::new()
+            .push(Space::with_height(Length::Units(300)))
+            .push(tooltip)
+            .push(Space::with_height(Length::Units(300)));
 
-            if tooltip_bounds.y < viewport.y {
-                tooltip_bounds.y = viewport.y;
-            } else if viewport.y + viewport.height
-                < tooltip_bounds.y + tooltip_bounds.height
-            {
-                tooltip_bounds.y =
-                    viewport.y + viewport.height - tooltip_bounds.height;
-            }
-        }
+        let mut simulator = Simulator::with_size(
+            Scrollable::<(), renderer::Null>::new(content)
+                .id(scrollable::Id::new("scrollable")),
+            Size::new(100.0, 200.0),
+        );
 
-        renderer.with_layer(Rectangle::with_size(Size::INFINITY), |renderer| {
-            container::draw_background(renderer, &style, tooltip_bounds);
-
-            draw_text(
-                renderer,
-                &defaults,
-                Layout::with_offset(
-                    Vector::new(
-                        tooltip_bounds.x + padding,
-                        tooltip_bounds.y + padding,
-                    ),
-                    &text_layout,
-                ),
-                cursor_position,
-                viewport,
-            )
-        });
+        simulator.operate(&mut scroll_to(Id::new("scrollable"), 250.0));
+        let _ = simulator.point_at(Point::new(50.0, 60.0));
+
+        // The content is drawn at y = 50 after scrolling, so the tooltip
+        // starts right below it.
+        assert_eq!(
+            simulator.find(Id::new("tip")),
+            Ok(Rectangle::new(
+                Point::new(45.0, 70.0),
+                Size::new(10.0, 10.0)
+            ))
+        );
     }
 }
@@ -0,0 +1,846 @@
+//! Display hierarchical data as a tree of collapsible nodes.
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::touch;
+use crate::widget;
+use crate::widget::inspect;
+use crate::widget::operation::{self, Operation};
+use crate::widget::tree::{self, Tree};
+use crate::{
+    Background, Clipboard, Color, Command, Element, Layout, Length, Padding,
+    Point, Rectangle, Shell, Size, Widget,
+};
+
+pub use iced_style::tree_view::{Appearance, RowAppearance, StyleSheet};
+
+/// The size of the expand/collapse toggle of a [`Node`].
+const TOGGLE_SIZE: f32 = 8.0;
+
+/// A widget that displays a hierarchy of [`Node`]s, which can be expanded,
+/// collapsed, and selected.
+///
+/// Like a [`Table`], a [`TreeView`] does not keep which nodes are expanded or
+/// selected. Instead, it produces messages with the path of the node that
+/// should be toggled or selected. A path contains the index of the node in
+/// every level of the hierarchy, starting from the root.
+///
+/// Once focused, a [`TreeView`] can be navigated with the arrow keys.
+///
+/// [`Table`]: crate::widget::Table
+#[allow(missing_debug_implementations)]
+pub struct TreeView<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    id: Option<Id>,
+    rows: Vec<Row<'a, Message, Renderer>>,
+    width: Length,
+    height: Length,
+    indent: u16,
+    padding: Padding,
+    on_toggle: Option<Box<dyn Fn(Vec<usize>) -> Message + 'a>>,
+    on_select: Option<Box<dyn Fn(Vec<usize>) -> Message + 'a>>,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+/// A visible [`Node`] of a [`TreeView`].
+struct Row<'a, Message, Renderer> {
+    path: Vec<usize>,
+    label: Element<'a, Message, Renderer>,
+    has_children: bool,
+    is_expanded: bool,
+    is_selected: bool,
+}
+
+impl<'a, Message, Renderer> Row<'a, Message, Renderer> {
+    fn depth(&self) -> usize {
+        self.path.len() - 1
+    }
+}
+
+impl<'a, Message, Renderer> TreeView<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: crate::Renderer + 'a,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`TreeView`] with the given root [`Node`]s.
+    ///
+    /// The children of collapsed nodes are never built.
+    pub fn new(nodes: Vec<Node<'a, Message, Renderer>>) -> Self {
+        let mut rows = Vec::new();
+
+        flatten(nodes, &mut Vec::new(), &mut rows);
+
+        TreeView {
+            id: None,
+            rows,
+            width: Length::Fill,
+            height: Length::Shrink,
+            indent: 16,
+            padding: Padding::new(2),
+            on_toggle: None,
+            on_select: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the [`Id`] of the [`TreeView`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the width of the [`TreeView`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`TreeView`].
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the horizontal space added for every level of the hierarchy.
+    pub fn indent(mut self, indent: u16) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Sets the [`Padding`] of every row of the [`TreeView`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the message that will be produced when a [`Node`] is expanded or
+    /// collapsed.
+    ///
+    /// The message receives the path of the [`Node`].
+    pub fn on_toggle(mut self, f: impl Fn(Vec<usize>) -> Message + 'a) -> Self {
+        self.on_toggle = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message that will be produced when a [`Node`] is selected.
+    ///
+    /// The message receives the path of the [`Node`].
+    pub fn on_select(mut self, f: impl Fn(Vec<usize>) -> Message + 'a) -> Self {
+        self.on_select = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the style of the [`TreeView`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+fn flatten<'a, Message, Renderer>(
+    nodes: Vec<Node<'a, Message, Renderer>>,
+    path: &mut Vec<usize>,
+    rows: &mut Vec<Row<'a, Message, Renderer>>,
+) {
+    for (index, node) in nodes.into_iter().enumerate() {
+        path.push(index);
+
+        let has_children = node.children.is_some();
+        let is_expanded = has_children && node.is_expanded;
+
+        rows.push(Row {
+            path: path.clone(),
+            label: node.label,
+            has_children,
+            is_expanded,
+            is_selected: node.is_selected,
+        });
+
+        if let Some(children) = node.children.filter(|_| is_expanded) {
+            flatten(children(), path, rows);
+        }
+
+        let _ = path.pop();
+    }
+}
+
+/// A node of a [`TreeView`].
+#[allow(missing_debug_implementations)]
+pub struct Node<'a, Message, Renderer> {
+    label: Element<'a, Message, Renderer>,
+    children:
+        Option<Box<dyn FnOnce() -> Vec<Node<'a, Message, Renderer>> + 'a>>,
+    is_expanded: bool,
+    is_selected: bool,
+}
+
+impl<'a, Message, Renderer> Node<'a, Message, Renderer> {
+    /// Creates a new leaf [`Node`] with the given label.
+    pub fn new(label: impl Into<Element<'a, Message, Renderer>>) -> Self {
+        Self {
+            label: label.into(),
+            children: None,
+            is_expanded: false,
+            is_selected: false,
+        }
+    }
+
+    /// Sets the function that builds the children of the [`Node`].
+    ///
+    /// The function is only called when the [`Node`] is expanded.
+    pub fn children(
+        mut self,
+        f: impl FnOnce() -> Vec<Node<'a, Message, Renderer>> + 'a,
+    ) -> Self {
+        self.children = Some(Box::new(f));
+        self
+    }
+
+    /// Sets whether the [`Node`] is expanded or not.
+    pub fn expanded(mut self, is_expanded: bool) -> Self {
+        self.is_expanded = is_expanded;
+        self
+    }
+
+    /// Sets whether the [`Node`] is selected or not.
+    pub fn selected(mut self, is_selected: bool) -> Self {
+        self.is_selected = is_selected;
+        self
+    }
+}
+
+/// The identifier of a [`TreeView`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Id(widget::Id);
+
+impl Id {
+    /// Creates a custom [`Id`].
+    pub fn new(id: impl Into<std::borrow::Cow<'static, str>>) -> Self {
+        Self(widget::Id::new(id))
+    }
+
+    /// Creates a unique [`Id`].
+    ///
+    /// This function produces a different [`Id`] every time it is called.
+    pub fn unique() -> Self {
+        Self(widget::Id::unique())
+    }
+}
+
+impl From<Id> for widget::Id {
+    fn from(id: Id) -> Self {
+        id.0
+    }
+}
+
+/// Produces a [`Command`] that focuses the [`TreeView`] with the given [`Id`].
+pub fn focus<Message: 'static>(id: Id) -> Command<Message> {
+    Command::widget(operation::focusable::focus(id.0))
+}
+
+/// The local state of a [`TreeView`].
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    is_focused: bool,
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for TreeView<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.rows.iter().map(|row| Tree::new(&row.label)).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(
+            &self.rows.iter().map(|row| &row.label).collect::<Vec<_>>(),
+        )
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+        let max = limits.max();
+        let padding = self.padding;
+        let indent = f32::from(self.indent);
+
+        let mut y = 0.0;
+        let mut intrinsic_width: f32 = 0.0;
+
+        let labels: Vec<_> = self
+            .rows
+            .iter()
+            .map(|row| {
+                let offset = (row.depth() + 1) as f32 * indent;
+
+                let limits = layout::Limits::new(
+                    Size::ZERO,
+                    Size::new((max.width - offset).max(0.0), max.height),
+                )
+                .pad(padding);
+
+                let mut label = row.label.as_widget().layout(renderer, &limits);
+                label.move_to(Point::new(
+                    offset + f32::from(padding.left),
+                    f32::from(padding.top),
+                ));
+
+                let size = label.size();
+                let height = (size.height + f32::from(padding.vertical()))
+                    .max(TOGGLE_SIZE);
+
+                intrinsic_width = intrinsic_width
+                    .max(offset + size.width + f32::from(padding.horizontal()));
+
+                let row_y = y;
+                y += height;
+
+                (label, row_y, height)
+            })
+            .collect();
+
+        let size = limits.resolve(Size::new(intrinsic_width, y));
+
+        let rows = labels
+            .into_iter()
+            .map(|(label, y, height)| {
+                let mut row = layout::Node::with_children(
+                    Size::new(size.width, height),
+                    vec![label],
+                );
+                row.move_to(Point::new(0.0, y));
+
+                row
+            })
+            .collect();
+
+        layout::Node::with_children(size, rows)
+    }
+
+    fn inspect(&self, tree: &Tree, layout: Layout<'_>) -> inspect::Node {
+        inspect::Node::new::<Self>(layout.bounds(), self.width, self.height)
+            .id(self.id.as_ref().map(|id| &id.0))
+            .children(
+                self.rows
+                    .iter()
+                    .zip(&tree.children)
+                    .zip(labels(layout))
+                    .map(|((row, state), layout)| {
+                        row.label.as_widget().inspect(state, layout)
+                    }),
+            )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.focusable(state, self.id.as_ref().map(|id| &id.0));
+
+        operation.container(
+            self.id.as_ref().map(|id| &id.0),
+            layout.bounds(),
+            &mut |operation| {
+                self.rows
+                    .iter()
+                    .zip(&mut tree.children)
+                    .zip(labels(layout))
+                    .for_each(|((row, state), layout)| {
+                        row.label.as_widget().operate(state, layout, operation);
+                    })
+            },
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let status = self
+            .rows
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(labels(layout))
+            .map(|((row, state), layout)| {
+                row.label.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    cursor_position,
+                    renderer,
+                    clipboard,
+                    shell,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                state.is_focused = layout.bounds().contains(cursor_position);
+
+                if status == event::Status::Captured {
+                    return status;
+                }
+
+                if let Some((index, is_over_toggle)) =
+                    self.hovered_row(layout, cursor_position)
+                {
+                    let row = &self.rows[index];
+
+                    if is_over_toggle {
+                        if let Some(on_toggle) = &self.on_toggle {
+                            shell.publish(on_toggle(row.path.clone()));
+
+                            return event::Status::Captured;
+                        }
+                    }
+
+                    if let Some(on_select) = &self.on_select {
+                        shell.publish(on_select(row.path.clone()));
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) if state.is_focused && status == event::Status::Ignored => {
+                if key_code == keyboard::KeyCode::Escape {
+                    state.is_focused = false;
+
+                    return event::Status::Captured;
+                }
+
+                if let Some(message) = self.navigate(key_code) {
+                    shell.publish(message);
+
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let interaction = self
+            .rows
+            .iter()
+            .zip(&tree.children)
+            .zip(labels(layout))
+            .map(|((row, state), layout)| {
+                row.label.as_widget().mouse_interaction(
+                    state,
+                    layout,
+                    cursor_position,
+                    viewport,
+                    renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default();
+
+        if interaction != mouse::Interaction::Idle {
+            return interaction;
+        }
+
+        match self.hovered_row(layout, cursor_position) {
+            Some((_, true)) if self.on_toggle.is_some() => {
+                mouse::Interaction::Pointer
+            }
+            Some(_) if self.on_select.is_some() => mouse::Interaction::Pointer,
+            _ => interaction,
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = theme.appearance(&self.style);
+        let indent = f32::from(self.indent);
+
+        if let Some(background) = appearance.background {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: layout.bounds(),
+                    border_radius: 0.0.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                background,
+            );
+        }
+
+        for ((row, label_state), row_layout) in
+            self.rows.iter().zip(&tree.children).zip(layout.children())
+        {
+            let bounds = row_layout.bounds();
+
+            let row_appearance = if bounds.contains(cursor_position) {
+                theme.hovered_row(
+                    &self.style,
+                    row.is_selected,
+                    state.is_focused,
+                )
+            } else {
+                theme.row(&self.style, row.is_selected, state.is_focused)
+            };
+
+            if let Some(background) = row_appearance.background {
+                fill(renderer, bounds, background);
+            }
+
+            if appearance.guide_width > 0.0 {
+                for level in 0..row.depth() {
+                    fill(
+                        renderer,
+                        Rectangle {
+                            x: bounds.x + level as f32 * indent + indent / 2.0
+                                - appearance.guide_width / 2.0,
+                            width: appearance.guide_width,
+                            ..bounds
+                        },
+                        appearance.guide_color,
+                    );
+                }
+            }
+
+            if row.has_children {
+                draw_toggle(
+                    renderer,
+                    Rectangle {
+                        x: bounds.x
+                            + row.depth() as f32 * indent
+                            + (indent - TOGGLE_SIZE) / 2.0,
+                        y: bounds.center_y() - TOGGLE_SIZE / 2.0,
+                        width: TOGGLE_SIZE,
+                        height: TOGGLE_SIZE,
+                    },
+                    row.is_expanded,
+                    appearance.toggle_color,
+                );
+            }
+
+            row.label.as_widget().draw(
+                label_state,
+                renderer,
+                theme,
+                &renderer::Style {
+                    text_color: row_appearance
+                        .text_color
+                        .unwrap_or(style.text_color),
+                },
+                row_layout.children().next().unwrap(),
+                cursor_position,
+                viewport,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        self.rows
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(labels(layout))
+            .find_map(|((row, state), layout)| {
+                row.label.as_widget_mut().overlay(state, layout, renderer)
+            })
+    }
+}
+
+impl<'a, Message, Renderer> TreeView<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Returns the index of the row under the cursor, if any, and whether
+    /// the cursor is over its toggle.
+    fn hovered_row(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Option<(usize, bool)> {
+        let indent = f32::from(self.indent);
+
+        layout
+            .children()
+            .position(|row| row.bounds().contains(cursor_position))
+            .map(|index| {
+                let row = &self.rows[index];
+                let toggle_x = layout.bounds().x + row.depth() as f32 * indent;
+
+                let is_over_toggle = row.has_children
+                    && cursor_position.x >= toggle_x
+                    && cursor_position.x < toggle_x + indent;
+
+                (index, is_over_toggle)
+            })
+    }
+
+    /// Produces the message resulting from pressing the given key while the
+    /// [`TreeView`] is focused, if any.
+    fn navigate(&self, key_code: keyboard::KeyCode) -> Option<Message> {
+        let last = self.rows.len().checked_sub(1)?;
+        let current = self.rows.iter().position(|row| row.is_selected);
+
+        let select = |index: usize| {
+            self.on_select
+                .as_ref()
+                .filter(|_| Some(index) != current)
+                .map(|on_select| on_select(self.rows[index].path.clone()))
+        };
+
+        let toggle = |index: usize| {
+            self.on_toggle
+                .as_ref()
+                .filter(|_| self.rows[index].has_children)
+                .map(|on_toggle| on_toggle(self.rows[index].path.clone()))
+        };
+
+        match key_code {
+            keyboard::KeyCode::Up => {
+                select(current.map_or(last, |index| index.saturating_sub(1)))
+            }
+            keyboard::KeyCode::Down => {
+                select(current.map_or(0, |index| (index + 1).min(last)))
+            }
+            keyboard::KeyCode::Home => select(0),
+            keyboard::KeyCode::End => select(last),
+            keyboard::KeyCode::Right => {
+                let index = current?;
+                let row = &self.rows[index];
+
+                if row.is_expanded {
+                    self.rows
+                        .get(index + 1)
+                        .filter(|child| child.depth() > row.depth())
+                        .and_then(|_| select(index + 1))
+                } else {
+                    toggle(index)
+                }
+            }
+            keyboard::KeyCode::Left => {
+                let index = current?;
+                let row = &self.rows[index];
+
+                if row.is_expanded {
+                    toggle(index)
+                } else {
+                    let parent = &row.path[..row.depth()];
+
+                    self.rows[..index]
+                        .iter()
+                        .rposition(|row| row.path == parent)
+                        .and_then(select)
+                }
+            }
+            keyboard::KeyCode::Enter
+            | keyboard::KeyCode::NumpadEnter
+            | keyboard::KeyCode::Space => toggle(current?),
+            _ => None,
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<TreeView<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: crate::Renderer + 'a,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(tree_view: TreeView<'a, Message, Renderer>) -> Self {
+        Self::new(tree_view)
+    }
+}
+
+/// Returns the layouts of the labels of every row of a [`TreeView`].
+fn labels(layout: Layout<'_>) -> impl Iterator<Item = Layout<'_>> {
+    layout.children().map(|row| row.children().next().unwrap())
+}
+
+fn fill<Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    background: impl Into<Background>,
+) where
+    Renderer: crate::Renderer,
+{
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border_radius: 0.0.into(),
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        },
+        background,
+    );
+}
+
+/// Draws the expand/collapse toggle of a [`Node`] as a stepped triangle,
+/// pointing down when expanded and right otherwise.
+fn draw_toggle<Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    is_expanded: bool,
+    color: Color,
+) where
+    Renderer: crate::Renderer,
+{
+    const STEPS: usize = 4;
+
+    for step in 0..STEPS {
+        let length = bounds.width * (STEPS - step) as f32 / STEPS as f32;
+        let offset = step as f32 * bounds.width / STEPS as f32;
+        let thickness = bounds.width / STEPS as f32;
+
+        let step_bounds = if is_expanded {
+            Rectangle {
+                x: bounds.center_x() - length / 2.0,
+                y: bounds.y + offset,
+                width: length,
+                height: thickness,
+            }
+        } else {
+            Rectangle {
+                x: bounds.x + offset,
+                y: bounds.center_y() - length / 2.0,
+                width: thickness,
+                height: length,
+            }
+        };
+
+        fill(renderer, step_bounds, color);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Simulator;
+    use crate::widget::text;
+
+    fn nodes() -> Vec<Node<'static, Vec<usize>, renderer::Null>> {
+        vec![
+            Node::new(text("docs"))
+                .children(|| vec![Node::new(text("a.md"))])
+                .expanded(true),
+            Node::new(text("src")).children(|| vec![Node::new(text("b.rs"))]),
+        ]
+    }
+
+    #[test]
+    fn children_are_indented_below_expanded_parents() {
+        let mut simulator = Simulator::with_size(
+            TreeView::new(nodes()),
+            Size::new(400.0, 400.0),
+        );
+
+        // Text is 20 units tall, and every row is padded by 2 units
+        assert_eq!(
+            simulator.find("a.md"),
+            Ok(Rectangle::new(Point::new(34.0, 26.0), Size::new(0.0, 20.0)))
+        );
+        assert_eq!(
+            simulator.find("src"),
+            Ok(Rectangle::new(Point::new(18.0, 50.0), Size::new(0.0, 20.0)))
+        );
+        assert!(simulator.find("b.rs").is_err());
+    }
+
+    #[test]
+    fn focused_tree_view_is_navigated_with_the_keyboard() {
+        let tree_view = TreeView::new(nodes())
+            .id(Id::new("tree"))
+            .on_select(|path| path);
+
+        let mut simulator =
+            Simulator::with_size(tree_view, Size::new(400.0, 400.0));
+
+        let _ = simulator.tap_key(keyboard::KeyCode::Down);
+        simulator.focus(widget::Id::new("tree"));
+        let _ = simulator.tap_key(keyboard::KeyCode::End);
+
+        assert_eq!(simulator.messages().collect::<Vec<_>>(), [vec![1]]);
+    }
+}
@@ -0,0 +1,975 @@
+//! Display a large amount of rows by only building the visible ones.
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::widget::inspect;
+use crate::widget::operation::Operation;
+use crate::widget::scrollable::{self, StyleSheet};
+use crate::widget::tree::{self, Tree};
+use crate::{
+    Clipboard, Element, Layout, Length, Point, Rectangle, Shell, Size, Vector,
+    Widget,
+};
+
+use std::cell::RefCell;
+use std::ops::Range;
+
+/// A scrollable list of rows that only builds, lays out, and draws the rows
+/// that are currently visible.
+///
+/// A [`VirtualList`] can handle a huge amount of rows, as long as the height
+/// of every row is either fixed or can be measured once the row is visible.
+///
+/// It behaves like a [`Scrollable`] and can be controlled with the same
+/// commands, like [`scrollable::scroll_to`].
+///
+/// The visible rows can only be laid out where a [`Renderer`] is available,
+/// which is the case when processing events and drawing, but not when
+/// operating. Therefore, operations only reach the rows laid out by the last
+/// event or frame, and none at all before the [`VirtualList`] has processed
+/// an event or has been drawn for the first time.
+///
+/// [`Renderer`]: crate::Renderer
+///
+/// [`Scrollable`]: crate::widget::Scrollable
+#[allow(missing_debug_implementations)]
+pub struct VirtualList<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    id: Option<scrollable::Id>,
+    metrics: Metrics,
+    width: Length,
+    height: Length,
+    scrollbar_width: u16,
+    scrollbar_margin: u16,
+    scroller_width: u16,
+    view_row: Box<dyn Fn(usize) -> Element<'a, Message, Renderer> + 'a>,
+    on_scroll: Option<Box<dyn Fn(f32) -> Message + 'a>>,
+    style: <Renderer::Theme as StyleSheet>::Style,
+    content: RefCell<Content<'a, Message, Renderer>>,
+}
+
+impl<'a, Message, Renderer> VirtualList<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`VirtualList`] with the given amount of rows, the
+    /// [`RowHeight`] of every row, and a closure that produces the row with
+    /// the given index.
+    pub fn new(
+        len: usize,
+        row_height: RowHeight,
+        view_row: impl Fn(usize) -> Element<'a, Message, Renderer> + 'a,
+    ) -> Self {
+        VirtualList {
+            id: None,
+            metrics: Metrics {
+                len,
+                row_height,
+                spacing: 0.0,
+            },
+            width: Length::Fill,
+            height: Length::Fill,
+            scrollbar_width: 10,
+            scrollbar_margin: 0,
+            scroller_width: 10,
+            view_row: Box::new(view_row),
+            on_scroll: None,
+            style: Default::default(),
+            content: RefCell::new(Content {
+                rows: None,
+                is_laid_out: false,
+                elements: Vec::new(),
+            }),
+        }
+    }
+
+    /// Sets the [`scrollable::Id`] of the [`VirtualList`].
+    pub fn id(mut self, id: scrollable::Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the vertical spacing _between_ rows.
+    pub fn spacing(mut self, units: u16) -> Self {
+        self.metrics.spacing = f32::from(units);
+        self
+    }
+
+    /// Sets the width of the [`VirtualList`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`VirtualList`].
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the scrollbar width of the [`VirtualList`].
+    ///
+    /// It silently enforces a minimum value of 1.
+    pub fn scrollbar_width(mut self, scrollbar_width: u16) -> Self {
+        self.scrollbar_width = scrollbar_width.max(1);
+        self
+    }
+
+    /// Sets the scrollbar margin of the [`VirtualList`].
+    pub fn scrollbar_margin(mut self, scrollbar_margin: u16) -> Self {
+        self.scrollbar_margin = scrollbar_margin;
+        self
+    }
+
+    /// Sets the scroller width of the [`VirtualList`].
+    ///
+    /// It silently enforces a minimum value of 1.
+    pub fn scroller_width(mut self, scroller_width: u16) -> Self {
+        self.scroller_width = scroller_width.max(1);
+        self
+    }
+
+    /// Sets a function to call when the [`VirtualList`] is scrolled.
+    ///
+    /// The function takes the new relative offset of the [`VirtualList`]
+    /// (e.g. `0` means top, while `1` means bottom).
+    pub fn on_scroll(mut self, f: impl Fn(f32) -> Message + 'a) -> Self {
+        self.on_scroll = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the style of the [`VirtualList`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// The height of the rows of a [`VirtualList`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RowHeight {
+    /// Every row has the same height, in pixels.
+    ///
+    /// This is the most efficient mode, since the position of any row can be
+    /// computed directly.
+    Fixed(u16),
+
+    /// Every row is measured when it becomes visible.
+    ///
+    /// Rows that have never been visible are assumed to have the `estimate`
+    /// height, in pixels.
+    Measured {
+        /// The estimated height of a row that has not been measured yet.
+        estimate: u16,
+    },
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for VirtualList<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        // The measured heights live in the widget tree, which is not
+        // available here. A shrinking list uses the estimated heights.
+        let size = limits.resolve(Size::new(
+            limits.max().width,
+            self.metrics.estimated_content_height(),
+        ));
+
+        layout::Node::new(size)
+    }
+
+    fn inspect(&self, tree: &Tree, layout: Layout<'_>) -> inspect::Node {
+        let state = tree.state.downcast_ref::<State>();
+        let mut content = self.content.borrow_mut();
+        let mut rows = state.rows.borrow_mut();
+
+        // Like operations, the rows laid out by the last frame are inspected.
+        let range = rows.range();
+        content.build(range, &mut rows, &self.view_row);
+
+        let bounds = layout.bounds();
+        let content_layout =
+            rows.layout(layout.position()).children().next().unwrap();
+        let offset = state.scrollable.offset(bounds, content_layout.bounds());
+
+        inspect::Node::new::<Self>(bounds, self.width, self.height)
+            .id(self.id.as_ref().map(|id| &id.0))
+            .children(
+                content
+                    .elements
+                    .iter()
+                    .zip(rows.trees.iter())
+                    .zip(content_layout.children())
+                    .map(|((element, tree), layout)| {
+                        element
+                            .as_widget()
+                            .inspect(tree, layout)
+                            .translate(Vector::new(0.0, -(offset as f32)))
+                    }),
+            )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let State {
+            scrollable,
+            heights,
+            rows,
+        } = tree.state.downcast_mut::<State>();
+        let heights = heights.get_mut();
+        let rows = rows.get_mut();
+        let mut content = self.content.borrow_mut();
+
+        heights.resize(self.metrics);
+
+        // Rows can only be laid out where a `Renderer` is available, so the
+        // rows laid out by the last frame are reached instead.
+        content.build(rows.range(), rows, &self.view_row);
+
+        let bounds = layout.bounds();
+        let content_bounds = Rectangle {
+            height: self.metrics.content_height(heights),
+            ..bounds
+        };
+
+        operation.scrollable(
+            scrollable,
+            self.id.as_ref().map(|id| &id.0),
+            bounds,
+            content_bounds,
+        );
+
+        operation.container(None, bounds, &mut |operation| {
+            let Rows {
+                trees,
+                layout: node,
+                ..
+            } = &mut *rows;
+
+            let layout =
+                Layout::with_offset(layout.position() - Point::ORIGIN, node);
+            let content_layout = layout.children().next().unwrap();
+
+            for ((element, tree), layout) in content
+                .elements
+                .iter()
+                .zip(trees.iter_mut())
+                .zip(content_layout.children())
+            {
+                element.as_widget().operate(tree, layout, operation);
+            }
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let content = self.content.get_mut();
+
+        content.resolve(
+            state,
+            renderer,
+            self.metrics,
+            &self.view_row,
+            layout.bounds().size(),
+        );
+
+        let State {
+            scrollable, rows, ..
+        } = state;
+        let Rows {
+            trees,
+            layout: node,
+            ..
+        } = rows.get_mut();
+
+        scrollable::update(
+            scrollable,
+            event,
+            Layout::with_offset(layout.position() - Point::ORIGIN, node),
+            cursor_position,
+            clipboard,
+            shell,
+            self.scrollbar_width,
+            self.scrollbar_margin,
+            self.scroller_width,
+            &self.on_scroll,
+            |event, layout, cursor_position, clipboard, shell| {
+                content
+                    .elements
+                    .iter_mut()
+                    .zip(trees.iter_mut())
+                    .zip(layout.children())
+                    .map(|((element, tree), layout)| {
+                        element.as_widget_mut().on_event(
+                            tree,
+                            event.clone(),
+                            layout,
+                            cursor_position,
+                            renderer,
+                            clipboard,
+                            shell,
+                        )
+                    })
+                    .fold(event::Status::Ignored, event::Status::merge)
+            },
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let mut content = self.content.borrow_mut();
+
+        content.resolve(
+            state,
+            renderer,
+            self.metrics,
+            &self.view_row,
+            layout.bounds().size(),
+        );
+
+        let rows = state.rows.borrow();
+
+        scrollable::draw(
+            &state.scrollable,
+            renderer,
+            theme,
+            rows.layout(layout.position()),
+            cursor_position,
+            self.scrollbar_width,
+            self.scrollbar_margin,
+            self.scroller_width,
+            &self.style,
+            |renderer, layout, cursor_position, viewport| {
+                for ((element, tree), layout) in content
+                    .elements
+                    .iter()
+                    .zip(rows.trees.iter())
+                    .zip(layout.children())
+                {
+                    element.as_widget().draw(
+                        tree,
+                        renderer,
+                        theme,
+                        style,
+                        layout,
+                        cursor_position,
+                        viewport,
+                    );
+                }
+            },
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let mut content = self.content.borrow_mut();
+
+        content.resolve(
+            state,
+            renderer,
+            self.metrics,
+            &self.view_row,
+            layout.bounds().size(),
+        );
+
+        let rows = state.rows.borrow();
+
+        scrollable::mouse_interaction(
+            &state.scrollable,
+            rows.layout(layout.position()),
+            cursor_position,
+            self.scrollbar_width,
+            self.scrollbar_margin,
+            self.scroller_width,
+            |layout, cursor_position, viewport| {
+                content
+                    .elements
+                    .iter()
+                    .zip(rows.trees.iter())
+                    .zip(layout.children())
+                    .map(|((element, tree), layout)| {
+                        element.as_widget().mouse_interaction(
+                            tree,
+                            layout,
+                            cursor_position,
+                            viewport,
+                            renderer,
+                        )
+                    })
+                    .max()
+                    .unwrap_or_default()
+            },
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+        let content = self.content.get_mut();
+
+        content.resolve(
+            state,
+            renderer,
+            self.metrics,
+            &self.view_row,
+            layout.bounds().size(),
+        );
+
+        let State {
+            scrollable, rows, ..
+        } = state;
+        let Rows {
+            trees,
+            layout: node,
+            ..
+        } = rows.get_mut();
+
+        let layout =
+            Layout::with_offset(layout.position() - Point::ORIGIN, node);
+        let content_layout = layout.children().next().unwrap();
+        let offset =
+            scrollable.offset(layout.bounds(), content_layout.bounds());
+
+        content
+            .elements
+            .iter_mut()
+            .zip(trees.iter_mut())
+            .zip(content_layout.children())
+            .find_map(|((element, tree), layout)| {
+                element.as_widget_mut().overlay(tree, layout, renderer)
+            })
+            .map(|overlay| {
+                overlay.translate(Vector::new(0.0, -(offset as f32)))
+            })
+    }
+}
+
+impl<'a, Message, Renderer> From<VirtualList<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(
+        virtual_list: VirtualList<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(virtual_list)
+    }
+}
+
+/// The local state of a [`VirtualList`].
+#[derive(Debug, Default)]
+struct State {
+    scrollable: scrollable::State,
+    heights: RefCell<Heights>,
+    rows: RefCell<Rows>,
+}
+
+/// The persistent state of the visible rows of a [`VirtualList`].
+#[derive(Debug)]
+struct Rows {
+    /// The index of the first visible row.
+    first: usize,
+
+    /// The widget trees of the visible rows.
+    trees: Vec<Tree>,
+
+    /// The layout of the visible rows, as laid out by the last frame.
+    layout: layout::Node,
+}
+
+impl Rows {
+    fn range(&self) -> Range<usize> {
+        self.first..self.first + self.trees.len()
+    }
+
+    fn layout(&self, position: Point) -> Layout<'_> {
+        Layout::with_offset(position - Point::ORIGIN, &self.layout)
+    }
+}
+
+impl Default for Rows {
+    fn default() -> Self {
+        Self {
+            first: 0,
+            trees: Vec::new(),
+            layout: layout::Node::with_children(
+                Size::ZERO,
+                vec![layout::Node::new(Size::ZERO)],
+            ),
+        }
+    }
+}
+
+/// The heights of the rows of a [`VirtualList`] with measured rows.
+#[derive(Debug, Default)]
+struct Heights {
+    /// The measured height of every row, if measured.
+    measured: Vec<Option<f32>>,
+
+    /// The sum of the heights of the rows before every row, followed by the
+    /// sum of the heights of all the rows.
+    sums: Vec<f32>,
+
+    /// The estimated height of a row that has not been measured yet.
+    estimate: f32,
+}
+
+impl Heights {
+    fn get(&self, index: usize) -> f32 {
+        self.measured
+            .get(index)
+            .copied()
+            .flatten()
+            .unwrap_or(self.estimate)
+    }
+
+    fn set(&mut self, index: usize, height: f32) -> bool {
+        let is_changed = self.measured[index] != Some(height);
+
+        self.measured[index] = Some(height);
+
+        is_changed
+    }
+
+    fn resize(&mut self, metrics: Metrics) {
+        if let RowHeight::Measured { estimate } = metrics.row_height {
+            let estimate = f32::from(estimate);
+
+            if self.measured.len() != metrics.len || self.estimate != estimate {
+                self.measured.resize(metrics.len, None);
+                self.estimate = estimate;
+                self.update_sums(0);
+            }
+        }
+    }
+
+    /// Recomputes the sums of the heights from the row with the given index.
+    fn update_sums(&mut self, from: usize) {
+        let len = self.measured.len();
+
+        self.sums.resize(len + 1, 0.0);
+
+        for index in from..len {
+            self.sums[index + 1] = self.sums[index] + self.get(index);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Metrics {
+    len: usize,
+    row_height: RowHeight,
+    spacing: f32,
+}
+
+impl Metrics {
+    fn row_height(&self, heights: &Heights, index: usize) -> f32 {
+        match self.row_height {
+            RowHeight::Fixed(height) => f32::from(height),
+            RowHeight::Measured { .. } => heights.get(index),
+        }
+    }
+
+    /// Returns the vertical position of the row with the given index.
+    fn row_y(&self, heights: &Heights, index: usize) -> f32 {
+        let spacing = self.spacing * index as f32;
+
+        let heights = match self.row_height {
+            RowHeight::Fixed(height) => f32::from(height) * index as f32,
+            RowHeight::Measured { .. } => heights.sums[index],
+        };
+
+        heights + spacing
+    }
+
+    fn content_height(&self, heights: &Heights) -> f32 {
+        if self.len == 0 {
+            return 0.0;
+        }
+
+        self.row_y(heights, self.len - 1)
+            + self.row_height(heights, self.len - 1)
+    }
+
+    /// Returns the height of the content, assuming that no row has been
+    /// measured yet.
+    fn estimated_content_height(&self) -> f32 {
+        if self.len == 0 {
+            return 0.0;
+        }
+
+        let row_height = match self.row_height {
+            RowHeight::Fixed(height) => height,
+            RowHeight::Measured { estimate } => estimate,
+        };
+
+        (f32::from(row_height) + self.spacing) * self.len as f32 - self.spacing
+    }
+
+    /// Returns the rows visible in the given viewport, together with the
+    /// vertical position of the first one.
+    fn visible_rows(
+        &self,
+        heights: &Heights,
+        offset: f32,
+        viewport_height: f32,
+    ) -> (Range<usize>, f32) {
+        match self.row_height {
+            RowHeight::Fixed(height) => {
+                let step = (f32::from(height) + self.spacing).max(1.0);
+
+                let first = ((offset / step).floor() as usize).min(self.len);
+                let last = (((offset + viewport_height) / step).ceil()
+                    as usize)
+                    .min(self.len);
+
+                (first..last, first as f32 * step)
+            }
+            RowHeight::Measured { .. } => {
+                let first = partition_point(0..self.len, |index| {
+                    self.row_y(heights, index) + self.row_height(heights, index)
+                        <= offset
+                });
+
+                let last = partition_point(first..self.len, |index| {
+                    self.row_y(heights, index) < offset + viewport_height
+                });
+
+                (first..last, self.row_y(heights, first))
+            }
+        }
+    }
+}
+
+/// Returns the index of the first element of the `range` that does not
+/// satisfy the `predicate`, assuming the `range` is partitioned by it.
+fn partition_point(
+    range: Range<usize>,
+    predicate: impl Fn(usize) -> bool,
+) -> usize {
+    let Range { mut start, mut end } = range;
+
+    while start < end {
+        let middle = start + (end - start) / 2;
+
+        if predicate(middle) {
+            start = middle + 1;
+        } else {
+            end = middle;
+        }
+    }
+
+    start
+}
+
+struct Content<'a, Message, Renderer> {
+    rows: Option<Range<usize>>,
+    is_laid_out: bool,
+    elements: Vec<Element<'a, Message, Renderer>>,
+}
+
+impl<'a, Message, Renderer> Content<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    /// Builds the rows with the given indices, unless they are already built,
+    /// and reconciliates their widget trees, reusing the trees of the rows
+    /// that were already visible.
+    fn build(
+        &mut self,
+        range: Range<usize>,
+        rows: &mut Rows,
+        view_row: &dyn Fn(usize) -> Element<'a, Message, Renderer>,
+    ) {
+        if self.rows.as_ref() == Some(&range) {
+            return;
+        }
+
+        let elements: Vec<_> = range.clone().map(view_row).collect();
+
+        let mut old_trees = std::mem::take(&mut rows.trees);
+        let old_rows = rows.first..rows.first + old_trees.len();
+
+        rows.first = range.start;
+        rows.trees = range
+            .clone()
+            .zip(&elements)
+            .map(|(index, element)| {
+                if old_rows.contains(&index) {
+                    let mut tree = std::mem::replace(
+                        &mut old_trees[index - old_rows.start],
+                        Tree::empty(),
+                    );
+
+                    tree.diff(element.as_widget());
+                    tree
+                } else {
+                    Tree::new(element.as_widget())
+                }
+            })
+            .collect();
+
+        self.rows = Some(range);
+        self.is_laid_out = false;
+        self.elements = elements;
+    }
+
+    /// Builds and lays out the rows that are visible in a [`VirtualList`] of
+    /// the given size, reusing the current ones if they are still visible.
+    fn resolve(
+        &mut self,
+        state: &State,
+        renderer: &Renderer,
+        metrics: Metrics,
+        view_row: &dyn Fn(usize) -> Element<'a, Message, Renderer>,
+        size: Size,
+    ) {
+        let mut heights = state.heights.borrow_mut();
+        let mut rows = state.rows.borrow_mut();
+
+        heights.resize(metrics);
+
+        let bounds = Rectangle::new(Point::ORIGIN, size);
+        let content_bounds = Rectangle::new(
+            Point::ORIGIN,
+            Size::new(size.width, metrics.content_height(&heights)),
+        );
+
+        let offset = state.scrollable.offset(bounds, content_bounds) as f32;
+        let (visible, mut y) =
+            metrics.visible_rows(&heights, offset, size.height);
+
+        if self.is_laid_out
+            && rows.layout.size() == size
+            && self.rows.as_ref() == Some(&visible)
+        {
+            return;
+        }
+
+        self.build(visible.clone(), &mut rows, view_row);
+
+        let mut changed = None;
+
+        let nodes = visible
+            .zip(&self.elements)
+            .map(|(index, element)| {
+                let limits = match metrics.row_height {
+                    RowHeight::Fixed(height) => {
+                        let size = Size::new(size.width, f32::from(height));
+
+                        layout::Limits::new(size, size)
+                    }
+                    RowHeight::Measured { .. } => layout::Limits::new(
+                        Size::new(size.width, 0.0),
+                        Size::new(size.width, f32::INFINITY),
+                    ),
+                };
+
+                let mut node = element.as_widget().layout(renderer, &limits);
+
+                if let RowHeight::Measured { .. } = metrics.row_height {
+                    if heights.set(index, node.size().height) {
+                        let _ = changed.get_or_insert(index);
+                    }
+                }
+
+                node.move_to(Point::new(0.0, y));
+                y += metrics.row_height(&heights, index) + metrics.spacing;
+
+                node
+            })
+            .collect();
+
+        if let Some(index) = changed {
+            heights.update_sums(index);
+        }
+
+        let content = layout::Node::with_children(
+            Size::new(size.width, metrics.content_height(&heights)),
+            nodes,
+        );
+
+        self.is_laid_out = true;
+        rows.layout = layout::Node::with_children(size, vec![content]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Simulator;
+    use crate::theme::Theme;
+    use crate::widget::operation::scrollable::scroll_to;
+    use crate::widget::{container, Button, Container, Space};
+
+    fn list_view(
+        row_height: RowHeight,
+    ) -> VirtualList<'static, (), renderer::Null> {
+        VirtualList::new(100, row_height, |index| {
+            let height = 10 + 10 * (index as u16 % 3);
+
+            Container::new(Space::with_height(Length::Units(height)))
+                .id(container::Id::new(format!("row-{}", index)))
+                .into()
+        })
+        .id(scrollable::Id::new("list"))
+    }
+
+    fn list(row_height: RowHeight) -> Simulator<'static, (), renderer::Null> {
+        Simulator::with_size(list_view(row_height), Size::new(100.0, 100.0))
+    }
+
+    fn row(index: usize) -> crate::widget::Id {
+        container::Id::new(format!("row-{}", index)).into()
+    }
+
+    #[test]
+    fn operate_reaches_the_rows_of_the_last_frame() {
+        let mut simulator = list(RowHeight::Fixed(10));
+
+        assert!(simulator.find(row(0)).is_err());
+
+        let _ = simulator.draw(&Theme::Light);
+        let (_, cache) = simulator.into_messages_and_cache();
+
+        // The list is rebuilt, but its rows are not laid out again yet.
+        let mut simulator = Simulator::with_cache(
+            list_view(RowHeight::Fixed(10)),
+            Size::new(100.0, 100.0),
+            cache,
+        );
+
+        assert_eq!(
+            simulator.find(row(9)),
+            Ok(Rectangle::new(Point::new(0.0, 90.0), Size::new(0.0, 10.0)))
+        );
+        assert!(simulator.find(row(10)).is_err());
+    }
+
+    #[test]
+    fn events_reach_the_rows_before_the_first_draw() {
+        let list: VirtualList<'_, _, renderer::Null> =
+            VirtualList::new(100, RowHeight::Fixed(10), |index| {
+                Button::new(Space::new(Length::Fill, Length::Units(10)))
+                    .on_press(index)
+                    .into()
+            });
+
+        let mut simulator = Simulator::with_size(list, Size::new(100.0, 100.0));
+
+        let _ = simulator.point_at(Point::new(50.0, 15.0));
+        let _ = simulator.simulate([
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+        ]);
+
+        assert_eq!(simulator.messages().collect::<Vec<_>>(), [1]);
+    }
+
+    #[test]
+    fn measured_rows_are_positioned_after_scrolling() {
+        let mut simulator = list(RowHeight::Measured { estimate: 10 });
+
+        // The first 10 rows are measured by the first frame: 3 groups of
+        // 10 + 20 + 30, plus 10. The rest keep the estimate.
+        let _ = simulator.draw(&Theme::Light);
+        simulator
+            .operate(&mut scroll_to(crate::widget::Id::new("list"), 600.0));
+        let _ = simulator.draw(&Theme::Light);
+
+        // Row 51 starts at 600, so it is drawn at the top of the list.
+        assert_eq!(simulator.find(row(51)).map(|bounds| bounds.y), Ok(0.0));
+        assert!(simulator.find(row(50)).is_err());
+    }
+
+    #[test]
+    fn scrolling_the_wheel_reveals_later_rows() {
+        let list: VirtualList<'_, _, renderer::Null> =
+            VirtualList::new(100, RowHeight::Fixed(10), |index| {
+                Container::new(Space::with_height(Length::Units(10)))
+                    .id(container::Id::new(format!("row-{}", index)))
+                    .into()
+            })
+            .on_scroll(|offset| offset);
+
+        let mut simulator = Simulator::with_size(list, Size::new(100.0, 100.0));
+
+        let _ = simulator.point_at(Point::new(50.0, 50.0));
+        let _ =
+            simulator.scroll(mouse::ScrollDelta::Pixels { x: 0.0, y: -450.0 });
+        let _ = simulator.draw(&Theme::Light);
+
+        assert_eq!(simulator.find(row(45)).map(|bounds| bounds.y), Ok(0.0));
+        assert!(simulator.find(row(40)).is_err());
+        assert_eq!(simulator.messages().collect::<Vec<_>>(), [0.5]);
+    }
+}
@@ -1,8 +1,44 @@
 //! Build window-based GUI applications.
 mod action;
+mod cursor_grab;
 mod event;
+mod id;
 mod mode;
+mod monitor;
+mod position;
+mod screenshot;
+mod settings;
+mod user_attention;
+
+pub mod icon;
 
 pub use action::Action;
+pub use cursor_grab::CursorGrab;
 pub use event::Event;
+pub use icon::Icon;
+pub use id::Id;
 pub use mode::Mode;
+pub use monitor::Monitor;
+pub use position::Position;
+pub use screenshot::Screenshot;
+pub use settings::Settings;
+pub use user_attention::UserAttention;
+
+use crate::subscription::{self, Subscription};
+use crate::time::Instant;
+
+/// Subscribes to the frames of the windows of the running application.
+///
+/// The resulting [`Subscription`] produces the [`Instant`] at which each
+/// frame was presented, at a rate equal to the refresh rate of the display.
+/// Frames are only presented while the application is redrawing, so the
+/// [`Subscription`] naturally stops producing items when nothing changes.
+///
+/// This is useful to drive animations smoothly, without missing frames nor
+/// redrawing faster than the display can show.
+pub fn frames() -> Subscription<Instant> {
+    subscription::events_with(|event, _status| match event {
+        crate::Event::Window(_, Event::RedrawRequested(at)) => Some(at),
+        _ => None,
+    })
+}
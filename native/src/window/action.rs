@@ -1,10 +1,22 @@
-use crate::window::Mode;
+use crate::mouse;
+use crate::window::{
+    CursorGrab, Icon, Mode, Monitor, Screenshot, Settings, UserAttention,
+};
+use crate::{Rectangle, Size};
 
 use iced_futures::MaybeSend;
 use std::fmt;
 
 /// An operation to be performed on some window.
 pub enum Action<T> {
+    /// Opens a new window with the given [`Settings`].
+    ///
+    /// Only supported by multi-window applications.
+    Spawn(Settings),
+    /// Closes the window.
+    ///
+    /// Only supported by multi-window applications.
+    Close,
     /// Moves the window with the left mouse button until the button is
     /// released.
     ///
@@ -37,6 +49,55 @@ pub enum Action<T> {
     ToggleMaximize,
     /// Fetch the current [`Mode`] of the window.
     FetchMode(Box<dyn FnOnce(Mode) -> T + 'static>),
+    /// Changes the title of the window.
+    ///
+    /// The title will be replaced again once the title of the application
+    /// changes.
+    SetTitle(String),
+    /// Changes the [`Icon`] of the window.
+    SetIcon(Icon),
+    /// Sets whether the window should have a border, a title bar, etc.
+    SetDecorations(bool),
+    /// Sets whether the window should be resizable or not.
+    SetResizable(bool),
+    /// Sets whether the window will always be on top of other windows.
+    SetAlwaysOnTop(bool),
+    /// Requests user attention to the window, or cancels a previous request
+    /// with `None`.
+    ///
+    /// Has no effect if the application is already focused.
+    RequestUserAttention(Option<UserAttention>),
+    /// Overrides the icon of the cursor while it is over the window.
+    ///
+    /// The icon replaces the one of the [`mouse::Interaction`] of the user
+    /// interface until it is cleared with `None`.
+    SetCursorIcon(Option<mouse::Interaction>),
+    /// Sets whether the cursor is visible while it is over the window.
+    SetCursorVisible(bool),
+    /// Grabs or releases the cursor with the given [`CursorGrab`] mode.
+    SetCursorGrab(CursorGrab),
+    /// Fetch the current logical size of the client area of the window.
+    FetchInnerSize(Box<dyn FnOnce(Size<u32>) -> T + 'static>),
+    /// Fetch the current logical size of the whole window, including its
+    /// decorations.
+    FetchOuterSize(Box<dyn FnOnce(Size<u32>) -> T + 'static>),
+    /// Fetch the current logical position of the top-left corner of the
+    /// window, including its decorations.
+    ///
+    /// Produces `None` on platforms where it is unsupported, like Wayland.
+    FetchPosition(Box<dyn FnOnce(Option<(i32, i32)>) -> T + 'static>),
+    /// Fetch the current scale factor of the window.
+    FetchScaleFactor(Box<dyn FnOnce(f64) -> T + 'static>),
+    /// Fetch whether the window is currently focused.
+    FetchFocused(Box<dyn FnOnce(bool) -> T + 'static>),
+    /// Fetch the list of [`Monitor`]s connected to the system.
+    FetchMonitors(Box<dyn FnOnce(Vec<Monitor>) -> T + 'static>),
+    /// Captures a [`Screenshot`] of the window once the next frame is
+    /// presented, optionally cropped to a region in logical coordinates.
+    Screenshot(
+        Option<Rectangle>,
+        Box<dyn FnOnce(Screenshot) -> T + 'static>,
+    ),
 }
 
 impl<T> Action<T> {
@@ -49,6 +110,8 @@ impl<T> Action<T> {
         T: 'static,
     {
         match self {
+            Self::Spawn(settings) => Action::Spawn(settings),
+            Self::Close => Action::Close,
             Self::Drag => Action::Drag,
             Self::Resize { width, height } => Action::Resize { width, height },
             Self::Maximize(bool) => Action::Maximize(bool),
@@ -57,6 +120,40 @@ impl<T> Action<T> {
             Self::SetMode(mode) => Action::SetMode(mode),
             Self::ToggleMaximize => Action::ToggleMaximize,
             Self::FetchMode(o) => Action::FetchMode(Box::new(move |s| f(o(s)))),
+            Self::SetTitle(title) => Action::SetTitle(title),
+            Self::SetIcon(icon) => Action::SetIcon(icon),
+            Self::SetDecorations(value) => Action::SetDecorations(value),
+            Self::SetResizable(value) => Action::SetResizable(value),
+            Self::SetAlwaysOnTop(value) => Action::SetAlwaysOnTop(value),
+            Self::RequestUserAttention(attention) => {
+                Action::RequestUserAttention(attention)
+            }
+            Self::SetCursorIcon(interaction) => {
+                Action::SetCursorIcon(interaction)
+            }
+            Self::SetCursorVisible(value) => Action::SetCursorVisible(value),
+            Self::SetCursorGrab(grab) => Action::SetCursorGrab(grab),
+            Self::FetchInnerSize(o) => {
+                Action::FetchInnerSize(Box::new(move |s| f(o(s))))
+            }
+            Self::FetchOuterSize(o) => {
+                Action::FetchOuterSize(Box::new(move |s| f(o(s))))
+            }
+            Self::FetchPosition(o) => {
+                Action::FetchPosition(Box::new(move |s| f(o(s))))
+            }
+            Self::FetchScaleFactor(o) => {
+                Action::FetchScaleFactor(Box::new(move |s| f(o(s))))
+            }
+            Self::FetchFocused(o) => {
+                Action::FetchFocused(Box::new(move |s| f(o(s))))
+            }
+            Self::FetchMonitors(o) => {
+                Action::FetchMonitors(Box::new(move |s| f(o(s))))
+            }
+            Self::Screenshot(region, o) => {
+                Action::Screenshot(region, Box::new(move |s| f(o(s))))
+            }
         }
     }
 }
@@ -64,6 +161,10 @@ impl<T> Action<T> {
 impl<T> fmt::Debug for Action<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            Self::Spawn(settings) => {
+                write!(f, "Action::Spawn({:?})", settings)
+            }
+            Self::Close => write!(f, "Action::Close"),
             Self::Drag => write!(f, "Action::Drag"),
             Self::Resize { width, height } => write!(
                 f,
@@ -78,6 +179,38 @@ impl<T> fmt::Debug for Action<T> {
             Self::SetMode(mode) => write!(f, "Action::SetMode({:?})", mode),
             Self::ToggleMaximize => write!(f, "Action::ToggleMaximize"),
             Self::FetchMode(_) => write!(f, "Action::FetchMode"),
+            Self::SetTitle(title) => write!(f, "Action::SetTitle({:?})", title),
+            Self::SetIcon(_) => write!(f, "Action::SetIcon"),
+            Self::SetDecorations(value) => {
+                write!(f, "Action::SetDecorations({})", value)
+            }
+            Self::SetResizable(value) => {
+                write!(f, "Action::SetResizable({})", value)
+            }
+            Self::SetAlwaysOnTop(value) => {
+                write!(f, "Action::SetAlwaysOnTop({})", value)
+            }
+            Self::RequestUserAttention(attention) => {
+                write!(f, "Action::RequestUserAttention({:?})", attention)
+            }
+            Self::SetCursorIcon(interaction) => {
+                write!(f, "Action::SetCursorIcon({:?})", interaction)
+            }
+            Self::SetCursorVisible(value) => {
+                write!(f, "Action::SetCursorVisible({})", value)
+            }
+            Self::SetCursorGrab(grab) => {
+                write!(f, "Action::SetCursorGrab({:?})", grab)
+            }
+            Self::FetchInnerSize(_) => write!(f, "Action::FetchInnerSize"),
+            Self::FetchOuterSize(_) => write!(f, "Action::FetchOuterSize"),
+            Self::FetchPosition(_) => write!(f, "Action::FetchPosition"),
+            Self::FetchScaleFactor(_) => write!(f, "Action::FetchScaleFactor"),
+            Self::FetchFocused(_) => write!(f, "Action::FetchFocused"),
+            Self::FetchMonitors(_) => write!(f, "Action::FetchMonitors"),
+            Self::Screenshot(region, _) => {
+                write!(f, "Action::Screenshot({:?})", region)
+            }
         }
     }
 }
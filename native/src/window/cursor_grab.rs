@@ -0,0 +1,16 @@
+/// The way the cursor is grabbed by a window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CursorGrab {
+    /// The cursor can move freely.
+    None,
+
+    /// The cursor is confined to the window area.
+    ///
+    /// Unsupported on macOS.
+    Confined,
+
+    /// The cursor is locked inside the window area to a certain position.
+    ///
+    /// Unsupported on Windows and X11.
+    Locked,
+}
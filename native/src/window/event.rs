@@ -1,3 +1,5 @@
+use crate::time::Instant;
+
 use std::path::PathBuf;
 
 /// A window-related event.
@@ -19,6 +21,16 @@ pub enum Event {
         height: u32,
     },
 
+    /// A window is about to be redrawn at the given [`Instant`], as
+    /// requested with [`Shell::request_redraw`].
+    ///
+    /// Subscriptions receive this event once per presented frame instead,
+    /// with the [`Instant`] of the presentation. See [`frames`].
+    ///
+    /// [`Shell::request_redraw`]: crate::Shell::request_redraw
+    /// [`frames`]: crate::window::frames
+    RedrawRequested(Instant),
+
     /// The user has requested for the window to close.
     ///
     /// Usually, you will want to terminate the execution whenever this event
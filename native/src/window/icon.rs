@@ -0,0 +1,113 @@
+//! Attach an icon to a window.
+use crate::Size;
+
+use std::fmt;
+
+/// The icon of a window.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Icon {
+    rgba: Vec<u8>,
+    size: Size<u32>,
+}
+
+impl Icon {
+    /// Creates an [`Icon`] from 32bpp RGBA data.
+    pub fn from_rgba(
+        rgba: Vec<u8>,
+        width: u32,
+        height: u32,
+    ) -> Result<Self, Error> {
+        if !rgba.len().is_multiple_of(4) {
+            return Err(Error::InvalidData {
+                byte_count: rgba.len(),
+            });
+        }
+
+        let pixel_count = rgba.len() / 4;
+
+        if pixel_count != (width * height) as usize {
+            return Err(Error::DimensionsMismatch {
+                width,
+                height,
+                pixel_count,
+            });
+        }
+
+        Ok(Icon {
+            rgba,
+            size: Size::new(width, height),
+        })
+    }
+
+    /// Returns the 32bpp RGBA data of the [`Icon`].
+    pub fn rgba(&self) -> &[u8] {
+        &self.rgba
+    }
+
+    /// Returns the size of the [`Icon`], in pixels.
+    pub fn size(&self) -> Size<u32> {
+        self.size
+    }
+
+    /// Consumes the [`Icon`] and returns its 32bpp RGBA data and size.
+    pub fn into_raw(self) -> (Vec<u8>, Size<u32>) {
+        (self.rgba, self.size)
+    }
+}
+
+impl fmt::Debug for Icon {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Icon")
+            .field("rgba", &format_args!("[{} bytes]", self.rgba.len()))
+            .field("size", &self.size)
+            .finish()
+    }
+}
+
+/// An error produced when using [`Icon::from_rgba`] with invalid arguments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// The provided RGBA data isn't divisible by 4.
+    ///
+    /// Therefore, it cannot be safely interpreted as 32bpp RGBA pixels.
+    InvalidData {
+        /// The length of the provided RGBA data.
+        byte_count: usize,
+    },
+
+    /// The number of RGBA pixels does not match the provided dimensions.
+    DimensionsMismatch {
+        /// The provided width.
+        width: u32,
+        /// The provided height.
+        height: u32,
+        /// The amount of pixels of the provided RGBA data.
+        pixel_count: usize,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidData { byte_count } => write!(
+                f,
+                "The provided RGBA data (with length {:?}) isn't divisible by \
+                4. Therefore, it cannot be safely interpreted as 32bpp RGBA \
+                pixels.",
+                byte_count,
+            ),
+            Error::DimensionsMismatch {
+                width,
+                height,
+                pixel_count,
+            } => write!(
+                f,
+                "The number of RGBA pixels ({:?}) does not match the provided \
+                dimensions ({:?}x{:?}).",
+                pixel_count, width, height,
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
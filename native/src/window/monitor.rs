@@ -0,0 +1,23 @@
+use crate::Size;
+
+/// A monitor connected to the system.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Monitor {
+    /// The human-readable name of the [`Monitor`], if available.
+    pub name: Option<String>,
+
+    /// The physical size of the [`Monitor`], in pixels.
+    pub size: Size<u32>,
+
+    /// The physical position of the top-left corner of the [`Monitor`]
+    /// within the desktop.
+    pub position: (i32, i32),
+
+    /// The scale factor of the [`Monitor`].
+    ///
+    /// Divide [`size`] and [`position`] by it to obtain logical coordinates.
+    ///
+    /// [`size`]: Self::size
+    /// [`position`]: Self::position
+    pub scale_factor: f64,
+}
@@ -0,0 +1,129 @@
+use crate::{Rectangle, Size};
+
+use std::fmt;
+use std::sync::Arc;
+
+/// The contents of a window, captured right after presenting a frame.
+///
+/// Shells do not read back the presented frame itself. Instead, they render
+/// the primitives of the frame again and read back the result.
+#[derive(Clone)]
+pub struct Screenshot {
+    /// The RGBA bytes of the [`Screenshot`], row by row.
+    pub bytes: Arc<Vec<u8>>,
+
+    /// The physical size of the [`Screenshot`], in pixels.
+    pub size: Size<u32>,
+
+    /// The scale factor of the window when the [`Screenshot`] was taken.
+    ///
+    /// Divide [`size`] by it to obtain the logical size.
+    ///
+    /// [`size`]: Self::size
+    pub scale_factor: f64,
+}
+
+impl Screenshot {
+    /// Creates a new [`Screenshot`] from its RGBA bytes, physical size and
+    /// scale factor.
+    pub fn new(bytes: Vec<u8>, size: Size<u32>, scale_factor: f64) -> Self {
+        Self {
+            bytes: Arc::new(bytes),
+            size,
+            scale_factor,
+        }
+    }
+
+    /// Crops the [`Screenshot`] to the given region, in logical coordinates.
+    ///
+    /// The region is clamped to the bounds of the [`Screenshot`], so the
+    /// result may be smaller than requested, or even empty.
+    pub fn crop(&self, region: Rectangle) -> Self {
+        let scale_factor = self.scale_factor as f32;
+
+        let left = (region.x * scale_factor).round().max(0.0) as u32;
+        let top = (region.y * scale_factor).round().max(0.0) as u32;
+        let right =
+            ((region.x + region.width) * scale_factor).round().max(0.0) as u32;
+        let bottom =
+            ((region.y + region.height) * scale_factor).round().max(0.0) as u32;
+
+        let left = left.min(self.size.width);
+        let top = top.min(self.size.height);
+        let right = right.clamp(left, self.size.width);
+        let bottom = bottom.clamp(top, self.size.height);
+
+        let width = (right - left) as usize;
+        let stride = self.size.width as usize * 4;
+
+        let bytes = (top..bottom)
+            .flat_map(|y| {
+                let start = y as usize * stride + left as usize * 4;
+
+                self.bytes[start..start + width * 4].iter().copied()
+            })
+            .collect();
+
+        Self::new(
+            bytes,
+            Size::new(right - left, bottom - top),
+            self.scale_factor,
+        )
+    }
+}
+
+impl fmt::Debug for Screenshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Screenshot {{ size: {}x{}, scale_factor: {} }}",
+            self.size.width, self.size.height, self.scale_factor
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Point;
+
+    fn screenshot(width: u32, height: u32) -> Screenshot {
+        let bytes = (0..width * height)
+            .flat_map(|pixel| [pixel as u8; 4])
+            .collect();
+
+        Screenshot::new(bytes, Size::new(width, height), 2.0)
+    }
+
+    #[test]
+    fn crop_clamps_the_region_to_the_screenshot() {
+        let cropped = screenshot(4, 4)
+            .crop(Rectangle::new(Point::new(1.0, -1.0), Size::new(10.0, 3.0)));
+
+        assert_eq!(cropped.size, Size::new(2, 4));
+        assert_eq!(
+            cropped
+                .bytes
+                .chunks(4)
+                .map(|pixel| pixel[0])
+                .collect::<Vec<_>>(),
+            [2, 3, 6, 7, 10, 11, 14, 15]
+        );
+    }
+
+    #[test]
+    fn crop_outside_of_the_screenshot_is_empty() {
+        let screenshot = screenshot(4, 4);
+
+        for region in [
+            Rectangle::new(Point::new(5.0, 0.0), Size::new(2.0, 2.0)),
+            Rectangle::new(Point::new(-3.0, -3.0), Size::new(1.0, 1.0)),
+            Rectangle::new(Point::new(1.0, 1.0), Size::new(0.0, 0.0)),
+        ] {
+            let cropped = screenshot.crop(region);
+
+            assert_eq!(cropped.size.width * cropped.size.height, 0);
+            assert!(cropped.bytes.is_empty());
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use crate::window::{Icon, Position};
+
+/// The settings of a window.
+#[derive(Debug, Clone)]
+pub struct Settings {
+    /// The initial size of the window.
+    pub size: (u32, u32),
+
+    /// The initial position of the window.
+    pub position: Position,
+
+    /// The minimum size of the window.
+    pub min_size: Option<(u32, u32)>,
+
+    /// The maximum size of the window.
+    pub max_size: Option<(u32, u32)>,
+
+    /// Whether the window should be visible or not.
+    pub visible: bool,
+
+    /// Whether the window should be resizable or not.
+    pub resizable: bool,
+
+    /// Whether the window should have a border, a title bar, etc. or not.
+    pub decorations: bool,
+
+    /// Whether the window should be transparent.
+    pub transparent: bool,
+
+    /// Whether the window will always be on top of other windows.
+    pub always_on_top: bool,
+
+    /// The icon of the window.
+    pub icon: Option<Icon>,
+}
+
+impl Default for Settings {
+    fn default() -> Settings {
+        Settings {
+            size: (1024, 768),
+            position: Position::default(),
+            min_size: None,
+            max_size: None,
+            visible: true,
+            resizable: true,
+            decorations: true,
+            transparent: false,
+            always_on_top: false,
+            icon: None,
+        }
+    }
+}
@@ -1,3 +1,7 @@
 //! Access the clipboard.
+pub use iced_native::clipboard::{Content, Format};
+
 #[cfg(not(target_arch = "wasm32"))]
-pub use crate::runtime::clipboard::{read, write};
+pub use crate::runtime::clipboard::{
+    read, read_content, read_primary, write, write_content, write_primary,
+};
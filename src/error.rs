@@ -14,6 +14,10 @@ pub enum Error {
     /// The application graphics context could not be created.
     #[error("the application graphics context could not be created")]
     GraphicsCreationFailed(iced_graphics::Error),
+
+    /// The event session could not be opened for recording or replaying.
+    #[error("the event session could not be opened")]
+    SessionOpenFailed(Box<dyn std::error::Error + Send + Sync>),
 }
 
 impl From<iced_winit::Error> for Error {
@@ -28,6 +32,9 @@ impl From<iced_winit::Error> for Error {
             iced_winit::Error::GraphicsCreationFailed(error) => {
                 Error::GraphicsCreationFailed(error)
             }
+            iced_winit::Error::SessionOpenFailed(error) => {
+                Error::SessionOpenFailed(Box::new(error))
+            }
         }
     }
 }
@@ -181,6 +181,14 @@ pub mod touch;
 pub mod widget;
 pub mod window;
 
+#[cfg(all(
+    feature = "multi-window",
+    not(feature = "glow"),
+    not(target_arch = "wasm32")
+))]
+#[cfg_attr(docsrs, doc(cfg(feature = "multi-window")))]
+pub mod multi_window;
+
 #[cfg(all(not(feature = "glow"), feature = "wgpu"))]
 use iced_winit as runtime;
 
@@ -193,6 +201,8 @@ use iced_wgpu as renderer;
 #[cfg(feature = "glow")]
 use iced_glow as renderer;
 
+pub use iced_native::animation;
+pub use iced_native::dnd;
 pub use iced_native::theme;
 pub use runtime::event;
 pub use runtime::subscription;
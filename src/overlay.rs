@@ -10,7 +10,9 @@ pub type Element<'a, Message, Renderer = crate::Renderer> =
 
 pub mod menu {
     //! Build and show dropdown menus.
-    pub use iced_native::overlay::menu::{Appearance, State, StyleSheet};
+    pub use iced_native::overlay::menu::{
+        Appearance, BarAppearance, Item, ItemAppearance, State, StyleSheet,
+    };
 
     /// A widget that produces a message when clicked.
     pub type Menu<'a, Message, Renderer = crate::Renderer> =
@@ -1,6 +1,8 @@
 //! Configure your application.
 use crate::window;
 
+use std::path::PathBuf;
+
 /// The settings of an application.
 #[derive(Debug, Clone)]
 pub struct Settings<Flags> {
@@ -56,6 +58,48 @@ pub struct Settings<Flags> {
     /// [`Application`]: crate::Application
     pub exit_on_close_request: bool,
 
+    /// Whether pressing Tab or Shift+Tab should move the focus to the next
+    /// or previous focusable widget, when no widget handles the key press.
+    ///
+    /// By default, it is disabled, so applications can handle Tab
+    /// themselves; for instance, by producing a
+    /// [`widget::focus_next`](crate::widget::focus_next) command.
+    pub focus_traversal: bool,
+
+    /// Whether the size, position, and maximized state of the window should
+    /// be stored when the [`Application`] exits and restored on startup.
+    ///
+    /// The geometry is stored under the [`id`](Self::id) of the application
+    /// in the configuration directory of the user, so it needs to be
+    /// provided.
+    ///
+    /// By default, it is disabled.
+    ///
+    /// [`Application`]: crate::Application
+    pub persist_window_geometry: bool,
+
+    /// The file where the events received by the [`Application`] should be
+    /// recorded, so they can be replayed later to reproduce a bug.
+    ///
+    /// By default, it is disabled.
+    ///
+    /// [`Application`]: crate::Application
+    pub record_session: Option<PathBuf>,
+
+    /// The recorded session that should be replayed instead of reacting to
+    /// the input of the user.
+    ///
+    /// By default, it is disabled.
+    pub replay_session: Option<PathBuf>,
+
+    /// The file where a performance trace of the [`Application`] should be
+    /// written, in the Chrome trace format.
+    ///
+    /// By default, it is disabled. Requires the `trace` feature.
+    ///
+    /// [`Application`]: crate::Application
+    pub trace_file: Option<PathBuf>,
+
     /// Whether the [`Application`] should try to build the context
     /// using OpenGL ES first then OpenGL.
     ///
@@ -82,6 +126,11 @@ impl<Flags> Settings<Flags> {
             text_multithreading: default_settings.text_multithreading,
             antialiasing: default_settings.antialiasing,
             exit_on_close_request: default_settings.exit_on_close_request,
+            focus_traversal: default_settings.focus_traversal,
+            persist_window_geometry: default_settings.persist_window_geometry,
+            record_session: default_settings.record_session,
+            replay_session: default_settings.replay_session,
+            trace_file: default_settings.trace_file,
             try_opengles_first: default_settings.try_opengles_first,
         }
     }
@@ -101,6 +150,11 @@ where
             text_multithreading: false,
             antialiasing: false,
             exit_on_close_request: true,
+            focus_traversal: false,
+            persist_window_geometry: false,
+            record_session: None,
+            replay_session: None,
+            trace_file: None,
             try_opengles_first: false,
         }
     }
@@ -113,6 +167,11 @@ impl<Flags> From<Settings<Flags>> for iced_winit::Settings<Flags> {
             window: settings.window.into(),
             flags: settings.flags,
             exit_on_close_request: settings.exit_on_close_request,
+            focus_traversal: settings.focus_traversal,
+            persist_window_geometry: settings.persist_window_geometry,
+            record_session: settings.record_session,
+            replay_session: settings.replay_session,
+            trace_file: settings.trace_file,
             try_opengles_first: settings.try_opengles_first,
         }
     }
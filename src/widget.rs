@@ -22,7 +22,7 @@ pub mod text {
 
 pub mod button {
     //! Allow your users to perform actions by pressing a button.
-    pub use iced_native::widget::button::{Appearance, StyleSheet};
+    pub use iced_native::widget::button::{focus, Appearance, Id, StyleSheet};
 
     /// A widget that produces a message when clicked.
     pub type Button<'a, Message, Renderer = crate::Renderer> =
@@ -40,7 +40,7 @@ pub mod checkbox {
 
 pub mod container {
     //! Decorate content and apply alignment.
-    pub use iced_native::widget::container::{Appearance, StyleSheet};
+    pub use iced_native::widget::container::{Appearance, Id, StyleSheet};
 
     /// An element decorating some content.
     pub type Container<'a, Message, Renderer = crate::Renderer> =
@@ -78,6 +78,64 @@ pub mod pane_grid {
         iced_native::widget::pane_grid::TitleBar<'a, Message, Renderer>;
 }
 
+pub mod context_menu {
+    //! Show a menu of actions when right-clicking some content.
+    pub use iced_native::widget::context_menu::{
+        Appearance, Item, ItemAppearance, StyleSheet,
+    };
+
+    /// A widget that shows a menu of items when its content is
+    /// right-clicked.
+    pub type ContextMenu<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::ContextMenu<'a, Message, Renderer>;
+}
+
+pub mod menu_bar {
+    //! Show a bar of menus with nested submenus.
+    pub use iced_native::widget::menu_bar::{
+        Appearance, BarAppearance, Item, ItemAppearance, StyleSheet,
+    };
+
+    /// A horizontal bar of titles that open menus when clicked.
+    pub type MenuBar<Message, Renderer = crate::Renderer> =
+        iced_native::widget::MenuBar<Message, Renderer>;
+}
+
+pub mod draggable {
+    //! Start dragging some content with the mouse or touch.
+
+    /// A widget that starts dragging a payload when its content is pressed and
+    /// moved.
+    pub type Draggable<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::Draggable<'a, Message, Renderer>;
+}
+
+pub mod drop_zone {
+    //! Receive content dropped by a drag and drop operation.
+
+    /// A widget that receives the payload dropped over its content.
+    pub type DropZone<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::DropZone<'a, Message, Renderer>;
+}
+
+pub mod modal {
+    //! Show dialogs on top of some content.
+    pub use iced_native::widget::modal::{Appearance, StyleSheet};
+
+    /// A widget that shows a stack of dialogs on top of some base content.
+    pub type Modal<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::Modal<'a, Message, Renderer>;
+}
+
+pub mod combo_box {
+    //! Search a list of options and select one of them.
+    pub use iced_native::widget::combo_box::{Appearance, StyleSheet};
+
+    /// A text input that filters a list of options as the user types.
+    pub type ComboBox<'a, T, Message, Renderer = crate::Renderer> =
+        iced_native::widget::ComboBox<'a, T, Message, Renderer>;
+}
+
 pub mod pick_list {
     //! Display a dropdown list of selectable values.
     pub use iced_native::widget::pick_list::{Appearance, StyleSheet};
@@ -99,7 +157,8 @@ pub mod radio {
 pub mod scrollable {
     //! Navigate an endless amount of content with a scrollbar.
     pub use iced_native::widget::scrollable::{
-        snap_to, style::Scrollbar, style::Scroller, Id, StyleSheet,
+        scroll_by, scroll_into_view, scroll_to, snap_to, style::Scrollbar,
+        style::Scroller, Id, StyleSheet,
     };
 
     /// A widget that can vertically display an infinite amount of content
@@ -108,6 +167,43 @@ pub mod scrollable {
         iced_native::widget::Scrollable<'a, Message, Renderer>;
 }
 
+pub mod virtual_list {
+    //! Display a large amount of rows by only building the visible ones.
+    pub use iced_native::widget::virtual_list::RowHeight;
+
+    /// A scrollable list of rows that only builds, lays out, and draws the
+    /// rows that are currently visible.
+    pub type VirtualList<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::VirtualList<'a, Message, Renderer>;
+}
+
+pub mod table {
+    //! Display rows of data aligned in columns.
+    pub use iced_native::widget::table::{
+        Appearance, Line, ResizeEvent, RowAppearance, Sort, StyleSheet,
+    };
+
+    /// A column of a [`Table`].
+    pub type Column<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::table::Column<'a, Message, Renderer>;
+
+    /// A widget that displays rows of data aligned in columns.
+    pub type Table<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::Table<'a, Message, Renderer>;
+}
+
+pub mod tabs {
+    //! Switch between different views using a bar of tabs.
+    pub use iced_native::widget::tabs::{
+        Appearance, DragEvent, StyleSheet, TabAppearance,
+    };
+
+    /// A widget that displays a bar of tabs and the contents of the active
+    /// one.
+    pub type Tabs<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::Tabs<'a, Message, Renderer>;
+}
+
 pub mod toggler {
     //! Show toggle controls using togglers.
     pub use iced_native::widget::toggler::{Appearance, StyleSheet};
@@ -117,6 +213,22 @@ pub mod toggler {
         iced_native::widget::Toggler<'a, Message, Renderer>;
 }
 
+pub mod tree_view {
+    //! Display hierarchical data as a tree of collapsible nodes.
+    pub use iced_native::widget::tree_view::{
+        focus, Appearance, Id, RowAppearance, StyleSheet,
+    };
+
+    /// A node of a [`TreeView`].
+    pub type Node<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::tree_view::Node<'a, Message, Renderer>;
+
+    /// A widget that displays a hierarchy of nodes, which can be expanded,
+    /// collapsed, and selected.
+    pub type TreeView<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::TreeView<'a, Message, Renderer>;
+}
+
 pub mod text_input {
     //! Display fields that can be filled with text.
     pub use iced_native::widget::text_input::{
@@ -133,7 +245,7 @@ pub mod tooltip {
     //! Display a widget over another.
     pub use iced_native::widget::tooltip::Position;
 
-    /// A widget allowing the selection of a single value from a list of options.
+    /// An element to display a widget over another.
     pub type Tooltip<'a, Message, Renderer = crate::Renderer> =
         iced_native::widget::Tooltip<'a, Message, Renderer>;
 }
@@ -145,7 +257,13 @@ pub use iced_native::widget::Space;
 
 pub use button::Button;
 pub use checkbox::Checkbox;
+pub use combo_box::ComboBox;
 pub use container::Container;
+pub use context_menu::ContextMenu;
+pub use draggable::Draggable;
+pub use drop_zone::DropZone;
+pub use menu_bar::MenuBar;
+pub use modal::Modal;
 pub use pane_grid::PaneGrid;
 pub use pick_list::PickList;
 pub use progress_bar::ProgressBar;
@@ -153,10 +271,14 @@ pub use radio::Radio;
 pub use rule::Rule;
 pub use scrollable::Scrollable;
 pub use slider::Slider;
+pub use table::Table;
+pub use tabs::Tabs;
 pub use text::Text;
 pub use text_input::TextInput;
 pub use toggler::Toggler;
 pub use tooltip::Tooltip;
+pub use tree_view::TreeView;
+pub use virtual_list::VirtualList;
 
 #[cfg(feature = "canvas")]
 #[cfg_attr(docsrs, doc(cfg(feature = "canvas")))]
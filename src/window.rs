@@ -1,12 +1,31 @@
 //! Configure the window of your application in native platforms.
-mod position;
 mod settings;
 
 pub mod icon;
 
+pub use iced_native::window::Position;
 pub use icon::Icon;
-pub use position::Position;
 pub use settings::Settings;
 
 #[cfg(not(target_arch = "wasm32"))]
 pub use crate::runtime::window::*;
+
+/// Opens a new window with the given [`Id`] and [`Settings`].
+///
+/// Only supported by multi-window applications.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn spawn<Message>(id: Id, settings: Settings) -> crate::Command<Message> {
+    crate::runtime::window::spawn(id, settings.into())
+}
+
+/// Changes the [`Icon`] of the window.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn set_icon<Message>(icon: Icon) -> crate::Command<Message> {
+    set_icon_for(Id::MAIN, icon)
+}
+
+/// Same as [`set_icon`], but for the window with the given [`Id`].
+#[cfg(not(target_arch = "wasm32"))]
+pub fn set_icon_for<Message>(id: Id, icon: Icon) -> crate::Command<Message> {
+    crate::runtime::window::set_icon_for(id, icon.into())
+}
@@ -7,7 +7,7 @@ use std::path::Path;
 
 /// The icon of a window.
 #[derive(Debug, Clone)]
-pub struct Icon(iced_winit::winit::window::Icon);
+pub struct Icon(iced_native::window::Icon);
 
 impl Icon {
     /// Creates an icon from 32bpp RGBA data.
@@ -16,8 +16,7 @@ impl Icon {
         width: u32,
         height: u32,
     ) -> Result<Self, Error> {
-        let raw =
-            iced_winit::winit::window::Icon::from_rgba(rgba, width, height)?;
+        let raw = iced_native::window::Icon::from_rgba(rgba, width, height)?;
 
         Ok(Icon(raw))
     }
@@ -91,30 +90,28 @@ impl From<std::io::Error> for Error {
     }
 }
 
-impl From<iced_winit::winit::window::BadIcon> for Error {
-    fn from(error: iced_winit::winit::window::BadIcon) -> Self {
-        use iced_winit::winit::window::BadIcon;
+impl From<iced_native::window::icon::Error> for Error {
+    fn from(error: iced_native::window::icon::Error) -> Self {
+        use iced_native::window::icon;
 
         match error {
-            BadIcon::ByteCountNotDivisibleBy4 { byte_count } => {
+            icon::Error::InvalidData { byte_count } => {
                 Error::InvalidData { byte_count }
             }
-            BadIcon::DimensionsVsPixelCount {
+            icon::Error::DimensionsMismatch {
                 width,
                 height,
                 pixel_count,
-                ..
             } => Error::DimensionsMismatch {
                 width,
                 height,
                 pixel_count,
             },
-            BadIcon::OsError(os_error) => Error::OsError(os_error),
         }
     }
 }
 
-impl From<Icon> for iced_winit::winit::window::Icon {
+impl From<Icon> for iced_native::window::Icon {
     fn from(icon: Icon) -> Self {
         icon.0
     }
@@ -51,11 +51,11 @@ impl Default for Settings {
     }
 }
 
-impl From<Settings> for iced_winit::settings::Window {
+impl From<Settings> for iced_native::window::Settings {
     fn from(settings: Settings) -> Self {
         Self {
             size: settings.size,
-            position: iced_winit::Position::from(settings.position),
+            position: settings.position,
             min_size: settings.min_size,
             max_size: settings.max_size,
             visible: settings.visible,
@@ -64,7 +64,12 @@ impl From<Settings> for iced_winit::settings::Window {
             transparent: settings.transparent,
             always_on_top: settings.always_on_top,
             icon: settings.icon.map(Icon::into),
-            platform_specific: Default::default(),
         }
     }
 }
+
+impl From<Settings> for iced_winit::settings::Window {
+    fn from(settings: Settings) -> Self {
+        iced_native::window::Settings::from(settings).into()
+    }
+}
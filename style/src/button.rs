@@ -1,4 +1,5 @@
 //! Change the apperance of a button.
+use iced_core::animation::{Interpolate, Transition};
 use iced_core::{Background, Color, Vector};
 
 /// The appearance of a button.
@@ -31,6 +32,27 @@ impl std::default::Default for Appearance {
     }
 }
 
+impl Interpolate for Appearance {
+    fn interpolate(&self, other: &Self, factor: f32) -> Self {
+        Self {
+            shadow_offset: self
+                .shadow_offset
+                .interpolate(&other.shadow_offset, factor),
+            background: self.background.interpolate(&other.background, factor),
+            border_radius: self
+                .border_radius
+                .interpolate(&other.border_radius, factor),
+            border_width: self
+                .border_width
+                .interpolate(&other.border_width, factor),
+            border_color: self
+                .border_color
+                .interpolate(&other.border_color, factor),
+            text_color: self.text_color.interpolate(&other.text_color, factor),
+        }
+    }
+}
+
 /// A set of rules that dictate the style of a button.
 pub trait StyleSheet {
     /// The supported style of the [`StyleSheet`].
@@ -57,6 +79,13 @@ pub trait StyleSheet {
         }
     }
 
+    /// Produces the [`Appearance`] of a button focused with the keyboard.
+    ///
+    /// By default, it is the hovered [`Appearance`].
+    fn focused(&self, style: &Self::Style) -> Appearance {
+        self.hovered(style)
+    }
+
     /// Produces the disabled [`Appearance`] of a button.
     fn disabled(&self, style: &Self::Style) -> Appearance {
         let active = self.active(style);
@@ -76,4 +105,11 @@ pub trait StyleSheet {
             ..active
         }
     }
+
+    /// Produces the [`Transition`] between the appearances of a button.
+    ///
+    /// By default, appearances change instantly.
+    fn transition(&self, _style: &Self::Style) -> Transition {
+        Transition::NONE
+    }
 }
@@ -1,4 +1,5 @@
 //! Change the appearance of a checkbox.
+use iced_core::animation::{Interpolate, Transition};
 use iced_core::{Background, Color};
 
 /// The appearance of a checkbox.
@@ -18,6 +19,27 @@ pub struct Appearance {
     pub text_color: Option<Color>,
 }
 
+impl Interpolate for Appearance {
+    fn interpolate(&self, other: &Self, factor: f32) -> Self {
+        Self {
+            background: self.background.interpolate(&other.background, factor),
+            checkmark_color: self
+                .checkmark_color
+                .interpolate(&other.checkmark_color, factor),
+            border_radius: self
+                .border_radius
+                .interpolate(&other.border_radius, factor),
+            border_width: self
+                .border_width
+                .interpolate(&other.border_width, factor),
+            border_color: self
+                .border_color
+                .interpolate(&other.border_color, factor),
+            text_color: self.text_color.interpolate(&other.text_color, factor),
+        }
+    }
+}
+
 /// A set of rules that dictate the style of a checkbox.
 pub trait StyleSheet {
     /// The supported style of the [`StyleSheet`].
@@ -28,4 +50,18 @@ pub trait StyleSheet {
 
     /// Produces the hovered [`Appearance`] of a checkbox.
     fn hovered(&self, style: &Self::Style, is_checked: bool) -> Appearance;
+
+    /// Produces the [`Appearance`] of a checkbox focused with the keyboard.
+    ///
+    /// By default, it is the hovered [`Appearance`].
+    fn focused(&self, style: &Self::Style, is_checked: bool) -> Appearance {
+        self.hovered(style, is_checked)
+    }
+
+    /// Produces the [`Transition`] between the appearances of a checkbox.
+    ///
+    /// By default, appearances change instantly.
+    fn transition(&self, _style: &Self::Style) -> Transition {
+        Transition::NONE
+    }
 }
@@ -20,6 +20,30 @@ pub struct Appearance {
     pub selected_background: Background,
 }
 
+/// The appearance of the decorations of the items of a menu.
+#[derive(Debug, Clone, Copy)]
+pub struct ItemAppearance {
+    /// The text [`Color`] of a disabled item.
+    pub disabled_text_color: Color,
+    /// The [`Color`] of the keyboard accelerator of an item.
+    pub accelerator_color: Color,
+    /// The [`Color`] of a separator.
+    pub separator_color: Color,
+}
+
+/// The appearance of a menu bar.
+#[derive(Debug, Clone, Copy)]
+pub struct BarAppearance {
+    /// The [`Background`] of the menu bar, if any.
+    pub background: Option<Background>,
+    /// The text [`Color`] of the titles of the menu bar.
+    pub text_color: Color,
+    /// The text [`Color`] of the title of the open menu.
+    pub selected_text_color: Color,
+    /// The [`Background`] of the title of the open menu.
+    pub selected_background: Background,
+}
+
 /// The style sheet of a menu.
 pub trait StyleSheet {
     /// The supported style of the [`StyleSheet`].
@@ -27,4 +51,32 @@ pub trait StyleSheet {
 
     /// Produces the [`Appearance`] of a menu.
     fn appearance(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the [`ItemAppearance`] of a menu.
+    fn item(&self, style: &Self::Style) -> ItemAppearance {
+        let appearance = self.appearance(style);
+
+        let faded = Color {
+            a: appearance.text_color.a * 0.5,
+            ..appearance.text_color
+        };
+
+        ItemAppearance {
+            disabled_text_color: faded,
+            accelerator_color: faded,
+            separator_color: appearance.border_color,
+        }
+    }
+
+    /// Produces the [`BarAppearance`] of a menu bar.
+    fn bar(&self, style: &Self::Style) -> BarAppearance {
+        let appearance = self.appearance(style);
+
+        BarAppearance {
+            background: Some(appearance.background),
+            text_color: appearance.text_color,
+            selected_text_color: appearance.selected_text_color,
+            selected_background: appearance.selected_background,
+        }
+    }
 }
@@ -0,0 +1,18 @@
+//! Change the appearance of a modal.
+use iced_core::Background;
+
+/// The appearance of a modal.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] drawn behind each dialog of the modal.
+    pub backdrop: Background,
+}
+
+/// A set of rules that dictate the style of a modal.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of the modal.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
@@ -30,4 +30,11 @@ pub trait StyleSheet {
 
     /// Produces the hovered [`Appearance`] of a pick list.
     fn hovered(&self, style: &<Self as StyleSheet>::Style) -> Appearance;
+
+    /// Produces the [`Appearance`] of a pick list focused with the keyboard.
+    ///
+    /// By default, it is the hovered [`Appearance`].
+    fn focused(&self, style: &<Self as StyleSheet>::Style) -> Appearance {
+        self.hovered(style)
+    }
 }
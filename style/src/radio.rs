@@ -26,4 +26,12 @@ pub trait StyleSheet {
 
     /// Produces the hovered [`Appearance`] of a radio button.
     fn hovered(&self, style: &Self::Style, is_selected: bool) -> Appearance;
+
+    /// Produces the [`Appearance`] of a radio button focused with the
+    /// keyboard.
+    ///
+    /// By default, it is the hovered [`Appearance`].
+    fn focused(&self, style: &Self::Style, is_selected: bool) -> Appearance {
+        self.hovered(style, is_selected)
+    }
 }
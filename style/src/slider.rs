@@ -53,4 +53,11 @@ pub trait StyleSheet {
 
     /// Produces the style of a slider that is being dragged.
     fn dragging(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the style of a slider focused with the keyboard.
+    ///
+    /// By default, it is the hovered style.
+    fn focused(&self, style: &Self::Style) -> Appearance {
+        self.hovered(style)
+    }
 }
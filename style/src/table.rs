@@ -0,0 +1,67 @@
+//! Change the appearance of a table.
+use iced_core::{Background, Color};
+
+pub use crate::pane_grid::Line;
+
+/// The appearance of a table.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] of the table, if any.
+    pub background: Option<Background>,
+    /// The border radius of the table.
+    pub border_radius: f32,
+    /// The border width of the table.
+    pub border_width: f32,
+    /// The border [`Color`] of the table.
+    pub border_color: Color,
+    /// The [`Background`] of the header row, if any.
+    pub header_background: Option<Background>,
+    /// The text [`Color`] of the header row, if any.
+    pub header_text_color: Option<Color>,
+    /// The [`Color`] of the lines dividing rows and columns.
+    pub divider_color: Color,
+    /// The width of the lines dividing rows and columns.
+    pub divider_width: f32,
+    /// The [`Color`] of the sort indicator of a sorted column.
+    pub sort_indicator_color: Color,
+}
+
+/// The appearance of a row of a table.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RowAppearance {
+    /// The [`Background`] of the row, if any.
+    pub background: Option<Background>,
+    /// The text [`Color`] of the row, if any.
+    pub text_color: Option<Color>,
+}
+
+/// A set of rules that dictate the style of a table.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of a table.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the [`RowAppearance`] of the row with the given index.
+    fn row(
+        &self,
+        style: &Self::Style,
+        index: usize,
+        is_selected: bool,
+    ) -> RowAppearance;
+
+    /// Produces the [`RowAppearance`] of the hovered row with the given index.
+    fn hovered_row(
+        &self,
+        style: &Self::Style,
+        index: usize,
+        is_selected: bool,
+    ) -> RowAppearance;
+
+    /// The [`Line`] to draw when a column divider is picked.
+    fn picked_divider(&self, style: &Self::Style) -> Option<Line>;
+
+    /// The [`Line`] to draw when a column divider is hovered.
+    fn hovered_divider(&self, style: &Self::Style) -> Option<Line>;
+}
@@ -0,0 +1,48 @@
+//! Change the appearance of tabs.
+use iced_core::{Background, Color};
+
+/// The appearance of the tab bar.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] of the tab bar, if any.
+    pub background: Option<Background>,
+    /// The [`Color`] of the line dividing the tab bar from the content.
+    pub divider_color: Color,
+    /// The width of the line dividing the tab bar from the content.
+    pub divider_width: f32,
+}
+
+/// The appearance of a tab.
+#[derive(Debug, Clone, Copy)]
+pub struct TabAppearance {
+    /// The [`Background`] of the tab, if any.
+    pub background: Option<Background>,
+    /// The border radius of the tab.
+    pub border_radius: f32,
+    /// The border width of the tab.
+    pub border_width: f32,
+    /// The border [`Color`] of the tab.
+    pub border_color: Color,
+    /// The text [`Color`] of the tab.
+    pub text_color: Color,
+    /// The [`Color`] of the close button of the tab.
+    pub close_color: Color,
+}
+
+/// A set of rules that dictate the style of tabs.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of the tab bar.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the [`TabAppearance`] of the active tab.
+    fn active(&self, style: &Self::Style) -> TabAppearance;
+
+    /// Produces the [`TabAppearance`] of a hovered tab.
+    fn hovered(&self, style: &Self::Style, is_active: bool) -> TabAppearance;
+
+    /// Produces the [`TabAppearance`] of an inactive tab.
+    fn inactive(&self, style: &Self::Style) -> TabAppearance;
+}
@@ -9,6 +9,7 @@ use crate::button;
 use crate::checkbox;
 use crate::container;
 use crate::menu;
+use crate::modal;
 use crate::pane_grid;
 use crate::pick_list;
 use crate::progress_bar;
@@ -17,9 +18,12 @@ use crate::rule;
 use crate::scrollable;
 use crate::slider;
 use crate::svg;
+use crate::table;
+use crate::tabs;
 use crate::text;
 use crate::text_input;
 use crate::toggler;
+use crate::tree_view;
 
 use iced_core::{Background, Color, Vector};
 
@@ -79,6 +83,14 @@ impl Custom {
     }
 }
 
+/// The border width of the focus ring of widgets focused with the keyboard.
+const FOCUS_RING_WIDTH: f32 = 2.0;
+
+/// The [`Color`] of the focus ring of widgets focused with the keyboard.
+fn focus_ring(palette: &Extended) -> Color {
+    palette.background.base.text
+}
+
 /// The style of an application.
 #[derive(Default)]
 pub enum Application {
@@ -192,6 +204,18 @@ impl button::StyleSheet for Theme {
         }
     }
 
+    fn focused(&self, style: &Self::Style) -> button::Appearance {
+        if let Button::Custom(custom) = style {
+            return custom.focused(self);
+        }
+
+        button::Appearance {
+            border_width: FOCUS_RING_WIDTH,
+            border_color: focus_ring(self.extended_palette()),
+            ..self.hovered(style)
+        }
+    }
+
     fn pressed(&self, style: &Self::Style) -> button::Appearance {
         if let Button::Custom(custom) = style {
             return custom.pressed(self);
@@ -317,6 +341,22 @@ impl checkbox::StyleSheet for Theme {
             Checkbox::Custom(custom) => custom.hovered(self, is_checked),
         }
     }
+
+    fn focused(
+        &self,
+        style: &Self::Style,
+        is_checked: bool,
+    ) -> checkbox::Appearance {
+        if let Checkbox::Custom(custom) = style {
+            return custom.focused(self, is_checked);
+        }
+
+        checkbox::Appearance {
+            border_width: FOCUS_RING_WIDTH,
+            border_color: focus_ring(self.extended_palette()),
+            ..self.hovered(style, is_checked)
+        }
+    }
 }
 
 fn checkbox_appearance(
@@ -466,6 +506,24 @@ impl slider::StyleSheet for Theme {
             Slider::Custom(custom) => custom.dragging(self),
         }
     }
+
+    fn focused(&self, style: &Self::Style) -> slider::Appearance {
+        match style {
+            Slider::Default => {
+                let hovered = self.hovered(style);
+
+                slider::Appearance {
+                    handle: slider::Handle {
+                        border_width: FOCUS_RING_WIDTH,
+                        border_color: focus_ring(self.extended_palette()),
+                        ..hovered.handle
+                    },
+                    ..hovered
+                }
+            }
+            Slider::Custom(custom) => custom.focused(self),
+        }
+    }
 }
 
 /// The style of a menu.
@@ -499,6 +557,37 @@ impl menu::StyleSheet for Theme {
             Menu::Custom(custom) => custom.appearance(self),
         }
     }
+
+    fn item(&self, style: &Self::Style) -> menu::ItemAppearance {
+        match style {
+            Menu::Default => {
+                let palette = self.extended_palette();
+
+                menu::ItemAppearance {
+                    disabled_text_color: palette.background.strong.color,
+                    accelerator_color: palette.background.strong.text,
+                    separator_color: palette.background.strong.color,
+                }
+            }
+            Menu::Custom(custom) => custom.item(self),
+        }
+    }
+
+    fn bar(&self, style: &Self::Style) -> menu::BarAppearance {
+        match style {
+            Menu::Default => {
+                let palette = self.extended_palette();
+
+                menu::BarAppearance {
+                    background: Some(palette.background.weak.color.into()),
+                    text_color: palette.background.weak.text,
+                    selected_text_color: palette.primary.strong.text,
+                    selected_background: palette.primary.strong.color.into(),
+                }
+            }
+            Menu::Custom(custom) => custom.bar(self),
+        }
+    }
 }
 
 impl From<PickList> for Menu {
@@ -510,6 +599,33 @@ impl From<PickList> for Menu {
     }
 }
 
+/// The style of a modal.
+#[derive(Default)]
+pub enum Modal {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn modal::StyleSheet<Style = Theme>>),
+}
+
+impl modal::StyleSheet for Theme {
+    type Style = Modal;
+
+    fn appearance(&self, style: &Self::Style) -> modal::Appearance {
+        match style {
+            Modal::Default => modal::Appearance {
+                backdrop: Color {
+                    a: 0.8,
+                    ..Color::BLACK
+                }
+                .into(),
+            },
+            Modal::Custom(custom) => custom.appearance(self),
+        }
+    }
+}
+
 /// The style of a pick list.
 #[derive(Clone, Default)]
 pub enum PickList {
@@ -563,6 +679,17 @@ impl pick_list::StyleSheet for Theme {
             PickList::Custom(custom, _) => custom.hovered(self),
         }
     }
+
+    fn focused(&self, style: &Self::Style) -> pick_list::Appearance {
+        match style {
+            PickList::Default => pick_list::Appearance {
+                border_width: FOCUS_RING_WIDTH,
+                border_color: focus_ring(self.extended_palette()),
+                ..self.hovered(style)
+            },
+            PickList::Custom(custom, _) => custom.focused(self),
+        }
+    }
 }
 
 /// The style of a radio button.
@@ -618,6 +745,21 @@ impl radio::StyleSheet for Theme {
             Radio::Custom(custom) => custom.hovered(self, is_selected),
         }
     }
+
+    fn focused(
+        &self,
+        style: &Self::Style,
+        is_selected: bool,
+    ) -> radio::Appearance {
+        match style {
+            Radio::Default => radio::Appearance {
+                border_width: FOCUS_RING_WIDTH,
+                border_color: focus_ring(self.extended_palette()),
+                ..self.hovered(style, is_selected)
+            },
+            Radio::Custom(custom) => custom.focused(self, is_selected),
+        }
+    }
 }
 
 /// The style of a toggler.
@@ -685,6 +827,20 @@ impl toggler::StyleSheet for Theme {
             Toggler::Custom(custom) => custom.hovered(self, is_active),
         }
     }
+
+    fn focused(
+        &self,
+        style: &Self::Style,
+        is_active: bool,
+    ) -> toggler::Appearance {
+        match style {
+            Toggler::Default => toggler::Appearance {
+                background_border: Some(focus_ring(self.extended_palette())),
+                ..self.hovered(style, is_active)
+            },
+            Toggler::Custom(custom) => custom.focused(self, is_active),
+        }
+    }
 }
 
 /// The style of a pane grid.
@@ -729,6 +885,295 @@ impl pane_grid::StyleSheet for Theme {
     }
 }
 
+/// The style of a table.
+#[derive(Default)]
+pub enum Table {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn table::StyleSheet<Style = Theme>>),
+}
+
+impl table::StyleSheet for Theme {
+    type Style = Table;
+
+    fn appearance(&self, style: &Self::Style) -> table::Appearance {
+        match style {
+            Table::Default => {
+                let palette = self.extended_palette();
+
+                table::Appearance {
+                    background: None,
+                    border_radius: 0.0,
+                    border_width: 1.0,
+                    border_color: palette.background.strong.color,
+                    header_background: Some(
+                        palette.background.weak.color.into(),
+                    ),
+                    header_text_color: Some(palette.background.weak.text),
+                    divider_color: palette.background.weak.color,
+                    divider_width: 1.0,
+                    sort_indicator_color: palette.primary.strong.color,
+                }
+            }
+            Table::Custom(custom) => custom.appearance(self),
+        }
+    }
+
+    fn row(
+        &self,
+        style: &Self::Style,
+        index: usize,
+        is_selected: bool,
+    ) -> table::RowAppearance {
+        match style {
+            Table::Default => {
+                let palette = self.extended_palette();
+
+                if is_selected {
+                    table::RowAppearance {
+                        background: Some(palette.primary.weak.color.into()),
+                        text_color: Some(palette.primary.weak.text),
+                    }
+                } else {
+                    table::RowAppearance::default()
+                }
+            }
+            Table::Custom(custom) => custom.row(self, index, is_selected),
+        }
+    }
+
+    fn hovered_row(
+        &self,
+        style: &Self::Style,
+        index: usize,
+        is_selected: bool,
+    ) -> table::RowAppearance {
+        match style {
+            Table::Default => {
+                let palette = self.extended_palette();
+
+                if is_selected {
+                    table::RowAppearance {
+                        background: Some(palette.primary.base.color.into()),
+                        text_color: Some(palette.primary.base.text),
+                    }
+                } else {
+                    table::RowAppearance {
+                        background: Some(palette.background.weak.color.into()),
+                        text_color: Some(palette.background.weak.text),
+                    }
+                }
+            }
+            Table::Custom(custom) => {
+                custom.hovered_row(self, index, is_selected)
+            }
+        }
+    }
+
+    fn picked_divider(&self, style: &Self::Style) -> Option<table::Line> {
+        match style {
+            Table::Default => {
+                let palette = self.extended_palette();
+
+                Some(table::Line {
+                    color: palette.primary.strong.color,
+                    width: 2.0,
+                })
+            }
+            Table::Custom(custom) => custom.picked_divider(self),
+        }
+    }
+
+    fn hovered_divider(&self, style: &Self::Style) -> Option<table::Line> {
+        match style {
+            Table::Default => {
+                let palette = self.extended_palette();
+
+                Some(table::Line {
+                    color: palette.primary.base.color,
+                    width: 2.0,
+                })
+            }
+            Table::Custom(custom) => custom.hovered_divider(self),
+        }
+    }
+}
+
+/// The style of tabs.
+#[derive(Default)]
+pub enum Tabs {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn tabs::StyleSheet<Style = Theme>>),
+}
+
+impl tabs::StyleSheet for Theme {
+    type Style = Tabs;
+
+    fn appearance(&self, style: &Self::Style) -> tabs::Appearance {
+        match style {
+            Tabs::Default => {
+                let palette = self.extended_palette();
+
+                tabs::Appearance {
+                    background: Some(palette.background.weak.color.into()),
+                    divider_color: palette.background.strong.color,
+                    divider_width: 1.0,
+                }
+            }
+            Tabs::Custom(custom) => custom.appearance(self),
+        }
+    }
+
+    fn active(&self, style: &Self::Style) -> tabs::TabAppearance {
+        match style {
+            Tabs::Default => {
+                let palette = self.extended_palette();
+
+                tabs::TabAppearance {
+                    background: Some(palette.background.base.color.into()),
+                    border_radius: 2.0,
+                    border_width: 1.0,
+                    border_color: palette.background.strong.color,
+                    text_color: palette.background.base.text,
+                    close_color: palette.background.strong.text,
+                }
+            }
+            Tabs::Custom(custom) => custom.active(self),
+        }
+    }
+
+    fn hovered(
+        &self,
+        style: &Self::Style,
+        is_active: bool,
+    ) -> tabs::TabAppearance {
+        match style {
+            Tabs::Default => {
+                if is_active {
+                    self.active(style)
+                } else {
+                    let palette = self.extended_palette();
+
+                    tabs::TabAppearance {
+                        background: Some(
+                            palette.background.strong.color.into(),
+                        ),
+                        text_color: palette.background.strong.text,
+                        ..self.inactive(style)
+                    }
+                }
+            }
+            Tabs::Custom(custom) => custom.hovered(self, is_active),
+        }
+    }
+
+    fn inactive(&self, style: &Self::Style) -> tabs::TabAppearance {
+        match style {
+            Tabs::Default => {
+                let palette = self.extended_palette();
+
+                tabs::TabAppearance {
+                    background: None,
+                    border_radius: 2.0,
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                    text_color: palette.background.weak.text,
+                    close_color: palette.background.strong.color,
+                }
+            }
+            Tabs::Custom(custom) => custom.inactive(self),
+        }
+    }
+}
+
+/// The style of a tree view.
+#[derive(Default)]
+pub enum TreeView {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn tree_view::StyleSheet<Style = Theme>>),
+}
+
+impl tree_view::StyleSheet for Theme {
+    type Style = TreeView;
+
+    fn appearance(&self, style: &Self::Style) -> tree_view::Appearance {
+        match style {
+            TreeView::Default => {
+                let palette = self.extended_palette();
+
+                tree_view::Appearance {
+                    background: None,
+                    toggle_color: palette.background.strong.text,
+                    guide_color: palette.background.strong.color,
+                    guide_width: 1.0,
+                }
+            }
+            TreeView::Custom(custom) => custom.appearance(self),
+        }
+    }
+
+    fn row(
+        &self,
+        style: &Self::Style,
+        is_selected: bool,
+        is_focused: bool,
+    ) -> tree_view::RowAppearance {
+        match style {
+            TreeView::Default => {
+                let palette = self.extended_palette();
+
+                match (is_selected, is_focused) {
+                    (true, true) => tree_view::RowAppearance {
+                        background: Some(palette.primary.strong.color.into()),
+                        text_color: Some(palette.primary.strong.text),
+                    },
+                    (true, false) => tree_view::RowAppearance {
+                        background: Some(palette.primary.weak.color.into()),
+                        text_color: Some(palette.primary.weak.text),
+                    },
+                    (false, _) => tree_view::RowAppearance::default(),
+                }
+            }
+            TreeView::Custom(custom) => {
+                custom.row(self, is_selected, is_focused)
+            }
+        }
+    }
+
+    fn hovered_row(
+        &self,
+        style: &Self::Style,
+        is_selected: bool,
+        is_focused: bool,
+    ) -> tree_view::RowAppearance {
+        match style {
+            TreeView::Default => {
+                if is_selected {
+                    self.row(style, is_selected, is_focused)
+                } else {
+                    let palette = self.extended_palette();
+
+                    tree_view::RowAppearance {
+                        background: Some(palette.background.weak.color.into()),
+                        text_color: Some(palette.background.weak.text),
+                    }
+                }
+            }
+            TreeView::Custom(custom) => {
+                custom.hovered_row(self, is_selected, is_focused)
+            }
+        }
+    }
+}
+
 /// The style of a progress bar.
 #[derive(Default)]
 pub enum ProgressBar {
@@ -1,4 +1,5 @@
 //! Change the appearance of a toggler.
+use iced_core::animation::{Interpolate, Transition};
 use iced_core::Color;
 
 /// The appearance of a toggler.
@@ -14,6 +15,21 @@ pub struct Appearance {
     pub foreground_border: Option<Color>,
 }
 
+impl Interpolate for Appearance {
+    fn interpolate(&self, other: &Self, factor: f32) -> Self {
+        Self {
+            background: self.background.interpolate(&other.background, factor),
+            background_border: self
+                .background_border
+                .interpolate(&other.background_border, factor),
+            foreground: self.foreground.interpolate(&other.foreground, factor),
+            foreground_border: self
+                .foreground_border
+                .interpolate(&other.foreground_border, factor),
+        }
+    }
+}
+
 /// A set of rules that dictate the style of a toggler.
 pub trait StyleSheet {
     /// The supported style of the [`StyleSheet`].
@@ -28,4 +44,24 @@ pub trait StyleSheet {
     ///
     /// [`Style`]: Self::Style
     fn hovered(&self, style: &Self::Style, is_active: bool) -> Appearance;
+
+    /// Returns the [`Appearance`] of the toggler focused with the keyboard
+    /// for the provided [`Style`].
+    ///
+    /// By default, it is the hovered [`Appearance`].
+    ///
+    /// [`Style`]: Self::Style
+    fn focused(&self, style: &Self::Style, is_active: bool) -> Appearance {
+        self.hovered(style, is_active)
+    }
+
+    /// Returns the [`Transition`] between the appearances of the toggler,
+    /// including the movement of its foreground, for the provided [`Style`].
+    ///
+    /// By default, appearances change instantly.
+    ///
+    /// [`Style`]: Self::Style
+    fn transition(&self, _style: &Self::Style) -> Transition {
+        Transition::NONE
+    }
 }
@@ -0,0 +1,53 @@
+//! Change the appearance of a tree view.
+use iced_core::{Background, Color};
+
+/// The appearance of a tree view.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] of the tree view, if any.
+    pub background: Option<Background>,
+    /// The [`Color`] of the expand/collapse toggles.
+    pub toggle_color: Color,
+    /// The [`Color`] of the indentation guides.
+    pub guide_color: Color,
+    /// The width of the indentation guides.
+    pub guide_width: f32,
+}
+
+/// The appearance of a row of a tree view.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RowAppearance {
+    /// The [`Background`] of the row, if any.
+    pub background: Option<Background>,
+    /// The text [`Color`] of the row, if any.
+    pub text_color: Option<Color>,
+}
+
+/// A set of rules that dictate the style of a tree view.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of a tree view.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the [`RowAppearance`] of a row.
+    ///
+    /// `is_focused` tells whether the tree view has keyboard focus.
+    fn row(
+        &self,
+        style: &Self::Style,
+        is_selected: bool,
+        is_focused: bool,
+    ) -> RowAppearance;
+
+    /// Produces the [`RowAppearance`] of a hovered row.
+    ///
+    /// `is_focused` tells whether the tree view has keyboard focus.
+    fn hovered_row(
+        &self,
+        style: &Self::Style,
+        is_selected: bool,
+        is_focused: bool,
+    ) -> RowAppearance;
+}
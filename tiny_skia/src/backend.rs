@@ -0,0 +1,249 @@
+#[cfg(any(feature = "image", feature = "svg"))]
+use crate::image;
+use crate::quad;
+use crate::text;
+use crate::triangle;
+use crate::{Settings, Viewport};
+
+use iced_graphics::backend;
+use iced_graphics::font;
+use iced_graphics::layer;
+use iced_graphics::{Layer, Primitive};
+use iced_native::{Color, Font, Rectangle, Size};
+
+/// A [`tiny-skia`] graphics backend for [`iced`].
+///
+/// [`tiny-skia`]: https://github.com/RazrFalcon/tiny-skia
+/// [`iced`]: https://github.com/iced-rs/iced
+#[derive(Debug)]
+pub struct Backend {
+    #[cfg(any(feature = "image", feature = "svg"))]
+    image_pipeline: image::Pipeline,
+    text_pipeline: text::Pipeline,
+    default_text_size: u16,
+}
+
+impl Backend {
+    /// Creates a new [`Backend`].
+    pub fn new(settings: Settings) -> Self {
+        Self {
+            #[cfg(any(feature = "image", feature = "svg"))]
+            image_pipeline: image::Pipeline::new(),
+            text_pipeline: text::Pipeline::new(settings.default_font),
+            default_text_size: settings.default_text_size,
+        }
+    }
+
+    /// Draws the provided primitives in the given pixels, after filling them
+    /// with the background color.
+    ///
+    /// The text provided as overlay will be rendered on top of the primitives.
+    /// This is useful for rendering debug information.
+    pub fn draw<T: AsRef<str>>(
+        &mut self,
+        pixels: &mut tiny_skia::PixmapMut<'_>,
+        primitives: &[Primitive],
+        viewport: &Viewport,
+        background_color: Color,
+        overlay_text: &[T],
+    ) {
+        let scale_factor = viewport.scale_factor() as f32;
+        let [r, g, b, a] = background_color.into_rgba8();
+
+        pixels.fill(tiny_skia::Color::from_rgba8(r, g, b, a));
+
+        let mut layers = Layer::generate(primitives, viewport);
+        layers.push(Layer::overlay(overlay_text, viewport));
+
+        for layer in layers {
+            self.flush(pixels, scale_factor, &layer);
+        }
+
+        self.text_pipeline.trim_cache();
+
+        #[cfg(any(feature = "image", feature = "svg"))]
+        self.image_pipeline.trim_cache();
+    }
+
+    fn flush(
+        &mut self,
+        pixels: &mut tiny_skia::PixmapMut<'_>,
+        scale_factor: f32,
+        layer: &Layer<'_>,
+    ) {
+        let bounds = match physical_bounds(pixels, layer.bounds * scale_factor)
+        {
+            Some(bounds) => bounds,
+            None => return,
+        };
+
+        let clip_mask = clip_mask_of(pixels, bounds);
+
+        for quad in &layer.quads {
+            quad::draw(pixels, quad, scale_factor, clip_mask.as_ref());
+        }
+
+        // Custom shaders cannot run on the CPU, so their quads are filled
+        // with their background color instead.
+        for custom_shader_quad in &layer.custom_shader_quads {
+            let quad = layer::Quad {
+                position: custom_shader_quad.position,
+                size: custom_shader_quad.size,
+                color: custom_shader_quad.color,
+                border_color: [0.0; 4],
+                border_radius: [0.0; 4],
+                border_width: 0.0,
+            };
+
+            quad::draw(pixels, &quad, scale_factor, clip_mask.as_ref());
+        }
+
+        for mesh in &layer.meshes {
+            let bounds = match physical_bounds(
+                pixels,
+                mesh.clip_bounds() * scale_factor,
+            ) {
+                Some(bounds) => bounds,
+                None => continue,
+            };
+
+            let mesh_clip_mask = clip_mask_of(pixels, bounds);
+
+            triangle::draw(pixels, mesh, scale_factor, mesh_clip_mask.as_ref());
+        }
+
+        #[cfg(any(feature = "image", feature = "svg"))]
+        for image in &layer.images {
+            self.image_pipeline.draw(
+                pixels,
+                image,
+                scale_factor,
+                clip_mask.as_ref(),
+            );
+        }
+
+        for text in &layer.text {
+            self.text_pipeline.draw(pixels, text, scale_factor, bounds);
+        }
+    }
+}
+
+/// Snaps the given bounds to the pixel grid and clamps them to the pixels.
+fn physical_bounds(
+    pixels: &tiny_skia::PixmapMut<'_>,
+    bounds: Rectangle,
+) -> Option<Rectangle<u32>> {
+    let bounds = bounds.snap();
+
+    let x = bounds.x.min(pixels.width());
+    let y = bounds.y.min(pixels.height());
+    let width = bounds.width.min(pixels.width() - x);
+    let height = bounds.height.min(pixels.height() - y);
+
+    if width < 1 || height < 1 {
+        return None;
+    }
+
+    Some(Rectangle {
+        x,
+        y,
+        width,
+        height,
+    })
+}
+
+/// Creates a mask clipping the pixels to the given bounds, if they do not
+/// cover all of them.
+fn clip_mask_of(
+    pixels: &tiny_skia::PixmapMut<'_>,
+    bounds: Rectangle<u32>,
+) -> Option<tiny_skia::ClipMask> {
+    if bounds.x == 0
+        && bounds.y == 0
+        && bounds.width == pixels.width()
+        && bounds.height == pixels.height()
+    {
+        return None;
+    }
+
+    let rect = tiny_skia::Rect::from_xywh(
+        bounds.x as f32,
+        bounds.y as f32,
+        bounds.width as f32,
+        bounds.height as f32,
+    )?;
+
+    let mut clip_mask = tiny_skia::ClipMask::new();
+
+    clip_mask.set_path(
+        pixels.width(),
+        pixels.height(),
+        &tiny_skia::PathBuilder::from_rect(rect),
+        tiny_skia::FillRule::Winding,
+        false,
+    )?;
+
+    Some(clip_mask)
+}
+
+impl iced_graphics::Backend for Backend {
+    fn trim_measurements(&mut self) {
+        self.text_pipeline.trim_cache()
+    }
+}
+
+impl backend::Text for Backend {
+    const ICON_FONT: Font = font::ICONS;
+    const CHECKMARK_ICON: char = font::CHECKMARK_ICON;
+    const ARROW_DOWN_ICON: char = font::ARROW_DOWN_ICON;
+
+    fn default_size(&self) -> u16 {
+        self.default_text_size
+    }
+
+    fn measure(
+        &self,
+        contents: &str,
+        size: f32,
+        font: Font,
+        bounds: Size,
+    ) -> (f32, f32) {
+        self.text_pipeline.measure(contents, size, font, bounds)
+    }
+
+    fn hit_test(
+        &self,
+        contents: &str,
+        size: f32,
+        font: Font,
+        bounds: Size,
+        point: iced_native::Point,
+        nearest_only: bool,
+    ) -> Option<text::Hit> {
+        self.text_pipeline.hit_test(
+            contents,
+            size,
+            font,
+            bounds,
+            point,
+            nearest_only,
+        )
+    }
+}
+
+#[cfg(feature = "image")]
+impl backend::Image for Backend {
+    fn dimensions(&self, handle: &iced_native::image::Handle) -> Size<u32> {
+        self.image_pipeline.dimensions(handle)
+    }
+}
+
+#[cfg(feature = "svg")]
+impl backend::Svg for Backend {
+    fn viewport_dimensions(
+        &self,
+        handle: &iced_native::svg::Handle,
+    ) -> Size<u32> {
+        self.image_pipeline.viewport_dimensions(handle)
+    }
+}
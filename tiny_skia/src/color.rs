@@ -0,0 +1,18 @@
+/// Converts a color in __linear RGB__ into a [`tiny_skia::Color`] in sRGB.
+pub fn from_linear([r, g, b, a]: [f32; 4]) -> tiny_skia::Color {
+    fn gamma(linear: f32) -> f32 {
+        if linear <= 0.003_130_8 {
+            linear * 12.92
+        } else {
+            1.055 * linear.powf(1.0 / 2.4) - 0.055
+        }
+    }
+
+    tiny_skia::Color::from_rgba(
+        gamma(r).clamp(0.0, 1.0),
+        gamma(g).clamp(0.0, 1.0),
+        gamma(b).clamp(0.0, 1.0),
+        a.clamp(0.0, 1.0),
+    )
+    .unwrap_or(tiny_skia::Color::TRANSPARENT)
+}
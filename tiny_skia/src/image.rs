@@ -0,0 +1,259 @@
+use iced_graphics::image::storage;
+use iced_graphics::layer;
+use iced_native::Size;
+
+#[cfg(feature = "image")]
+use iced_graphics::image::raster;
+
+#[cfg(feature = "svg")]
+use iced_graphics::image::vector;
+
+use std::cell::RefCell;
+
+#[derive(Debug, Default)]
+pub struct Pipeline {
+    #[cfg(feature = "image")]
+    raster_cache: RefCell<raster::Cache<Storage>>,
+    #[cfg(feature = "svg")]
+    vector_cache: RefCell<vector::Cache<Storage>>,
+    #[cfg(feature = "svg")]
+    rasterized: rasterized::Cache,
+    #[cfg(feature = "image")]
+    storage: Storage,
+}
+
+impl Pipeline {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[cfg(feature = "image")]
+    pub fn dimensions(&self, handle: &iced_native::image::Handle) -> Size<u32> {
+        let mut cache = self.raster_cache.borrow_mut();
+        let memory = cache.load(handle);
+
+        memory.dimensions()
+    }
+
+    #[cfg(feature = "svg")]
+    pub fn viewport_dimensions(
+        &self,
+        handle: &iced_native::svg::Handle,
+    ) -> Size<u32> {
+        let mut cache = self.vector_cache.borrow_mut();
+        let svg = cache.load(handle);
+
+        svg.viewport_dimensions()
+    }
+
+    pub fn draw(
+        &mut self,
+        pixels: &mut tiny_skia::PixmapMut<'_>,
+        image: &layer::Image,
+        scale_factor: f32,
+        clip_mask: Option<&tiny_skia::ClipMask>,
+    ) {
+        match image {
+            #[cfg(feature = "image")]
+            layer::Image::Raster { handle, bounds } => {
+                let entry = self.raster_cache.get_mut().upload(
+                    handle,
+                    &mut (),
+                    &mut self.storage,
+                );
+
+                if let Some(Entry(pixmap)) = entry {
+                    let transform = tiny_skia::Transform::from_row(
+                        bounds.width * scale_factor / pixmap.width() as f32,
+                        0.0,
+                        0.0,
+                        bounds.height * scale_factor / pixmap.height() as f32,
+                        bounds.x * scale_factor,
+                        bounds.y * scale_factor,
+                    );
+
+                    let _ = pixels.draw_pixmap(
+                        0,
+                        0,
+                        pixmap.as_ref(),
+                        &tiny_skia::PixmapPaint {
+                            quality: tiny_skia::FilterQuality::Bilinear,
+                            ..tiny_skia::PixmapPaint::default()
+                        },
+                        transform,
+                        clip_mask,
+                    );
+                }
+            }
+            #[cfg(not(feature = "image"))]
+            layer::Image::Raster { .. } => {}
+
+            #[cfg(feature = "svg")]
+            layer::Image::Vector {
+                handle,
+                color,
+                bounds,
+            } => {
+                let pixmap = self.rasterized.upload(
+                    self.vector_cache.get_mut(),
+                    handle,
+                    *color,
+                    Size::new(
+                        (bounds.width * scale_factor).ceil() as u32,
+                        (bounds.height * scale_factor).ceil() as u32,
+                    ),
+                );
+
+                if let Some(pixmap) = pixmap {
+                    let _ = pixels.draw_pixmap(
+                        0,
+                        0,
+                        pixmap.as_ref(),
+                        &tiny_skia::PixmapPaint::default(),
+                        tiny_skia::Transform::from_translate(
+                            bounds.x * scale_factor,
+                            bounds.y * scale_factor,
+                        ),
+                        clip_mask,
+                    );
+                }
+            }
+            #[cfg(not(feature = "svg"))]
+            layer::Image::Vector { .. } => {}
+        }
+    }
+
+    pub fn trim_cache(&mut self) {
+        #[cfg(feature = "image")]
+        self.raster_cache.get_mut().trim(&mut self.storage, &mut ());
+
+        // The parsed vector images are kept, since the vector cache only
+        // tracks the hits of its own rasterizations.
+        #[cfg(feature = "svg")]
+        self.rasterized.trim();
+    }
+}
+
+/// Stores images in memory as premultiplied pixmaps.
+#[derive(Debug, Default)]
+pub struct Storage;
+
+impl storage::Storage for Storage {
+    type Entry = Entry;
+    type State<'a> = ();
+
+    fn upload(
+        &mut self,
+        width: u32,
+        height: u32,
+        data: &[u8],
+        _state: &mut (),
+    ) -> Option<Entry> {
+        let mut pixmap = tiny_skia::Pixmap::new(width, height)?;
+
+        for (pixel, rgba) in
+            pixmap.pixels_mut().iter_mut().zip(data.chunks_exact(4))
+        {
+            *pixel = tiny_skia::ColorU8::from_rgba(
+                rgba[0], rgba[1], rgba[2], rgba[3],
+            )
+            .premultiply();
+        }
+
+        Some(Entry(pixmap))
+    }
+
+    fn remove(&mut self, _entry: &Entry, _state: &mut ()) {}
+}
+
+/// An image stored in memory.
+pub struct Entry(tiny_skia::Pixmap);
+
+impl storage::Entry for Entry {
+    fn size(&self) -> Size<u32> {
+        Size::new(self.0.width(), self.0.height())
+    }
+}
+
+impl std::fmt::Debug for Entry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Entry({} * {})", self.0.width(), self.0.height())
+    }
+}
+
+#[cfg(feature = "svg")]
+mod rasterized {
+    use super::Storage;
+
+    use iced_graphics::image::vector;
+    use iced_native::{svg, Color, Size};
+
+    use std::collections::{HashMap, HashSet};
+
+    type Key = (u64, u32, u32, Option<[u8; 4]>);
+
+    /// Caches the vector images rasterized at a given size and color.
+    ///
+    /// Unlike [`vector::Cache::upload`], the color filter is applied to the
+    /// premultiplied pixels produced by `resvg`.
+    #[derive(Debug, Default)]
+    pub struct Cache {
+        pixmaps: HashMap<Key, tiny_skia::Pixmap>,
+        hits: HashSet<Key>,
+    }
+
+    impl Cache {
+        pub fn upload(
+            &mut self,
+            vector_cache: &mut vector::Cache<Storage>,
+            handle: &svg::Handle,
+            color: Option<Color>,
+            size: Size<u32>,
+        ) -> Option<&tiny_skia::Pixmap> {
+            let color = color.map(Color::into_rgba8);
+            let key = (handle.id(), size.width, size.height, color);
+
+            let _ = self.hits.insert(key);
+
+            if !self.pixmaps.contains_key(&key) {
+                let tree = match vector_cache.load(handle) {
+                    vector::Svg::Loaded(tree) => tree,
+                    vector::Svg::NotFound => return None,
+                };
+
+                let mut pixmap =
+                    tiny_skia::Pixmap::new(size.width, size.height)?;
+
+                resvg::render(
+                    tree,
+                    if size.width > size.height {
+                        usvg::FitTo::Width(size.width)
+                    } else {
+                        usvg::FitTo::Height(size.height)
+                    },
+                    pixmap.as_mut(),
+                )?;
+
+                if let Some([r, g, b, _]) = color {
+                    pixmap.pixels_mut().iter_mut().for_each(|pixel| {
+                        let a = pixel.alpha();
+
+                        *pixel = tiny_skia::ColorU8::from_rgba(r, g, b, a)
+                            .premultiply();
+                    });
+                }
+
+                let _ = self.pixmaps.insert(key, pixmap);
+            }
+
+            self.pixmaps.get(&key)
+        }
+
+        pub fn trim(&mut self) {
+            let hits = &self.hits;
+
+            self.pixmaps.retain(|key, _| hits.contains(key));
+            self.hits.clear();
+        }
+    }
+}
@@ -0,0 +1,97 @@
+//! A software renderer for [`iced_native`], built on top of [`tiny-skia`].
+//!
+//! It does not need a GPU, which makes it useful to take screenshots of a user
+//! interface in headless environments, like CI machines. Since it consumes the
+//! same layers as the GPU renderers, the screenshots can be saved as PNG and
+//! compared against golden images.
+//!
+//! ```
+//! use iced_native::renderer;
+//! use iced_native::user_interface::{self, UserInterface};
+//! use iced_native::widget::{button, text};
+//! use iced_native::{column, Color, Element, Point, Size, Theme};
+//! use iced_tiny_skia::{Backend, Renderer, Settings, Viewport};
+//!
+//! let mut renderer = Renderer::new(Backend::new(Settings::default()));
+//!
+//! let view: Element<'_, (), Renderer> =
+//!     column![text("Hello, world!"), button("Click me")].into();
+//!
+//! let viewport = Viewport::with_physical_size(Size::new(320, 240), 1.0);
+//!
+//! let mut user_interface = UserInterface::build(
+//!     view,
+//!     viewport.logical_size(),
+//!     user_interface::Cache::new(),
+//!     &mut renderer,
+//! );
+//!
+//! let _ = user_interface.draw(
+//!     &mut renderer,
+//!     &Theme::Light,
+//!     &renderer::Style::default(),
+//!     Point::ORIGIN,
+//! );
+//!
+//! let mut pixels = iced_tiny_skia::tiny_skia::Pixmap::new(320, 240).unwrap();
+//!
+//! renderer.with_primitives(|backend, primitives| {
+//!     backend.draw(
+//!         &mut pixels.as_mut(),
+//!         primitives,
+//!         &viewport,
+//!         Color::WHITE,
+//!         &[] as &[&str],
+//!     );
+//! });
+//!
+//! let png = pixels.encode_png().expect("Encode PNG");
+//! # assert!(!png.is_empty());
+//! ```
+//!
+//! [`iced_native`]: https://github.com/iced-rs/iced/tree/0.6/native
+//! [`tiny-skia`]: https://github.com/RazrFalcon/tiny-skia
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/iced-rs/iced/9ab6923e943f784985e9ef9ca28b10278297225d/docs/logo.svg"
+)]
+#![deny(
+    missing_debug_implementations,
+    missing_docs,
+    unused_results,
+    clippy::extra_unused_lifetimes,
+    clippy::from_over_into,
+    clippy::needless_borrow,
+    clippy::new_without_default,
+    clippy::useless_conversion
+)]
+#![forbid(rust_2018_idioms)]
+#![allow(clippy::inherent_to_string, clippy::type_complexity)]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+pub use tiny_skia;
+
+mod backend;
+mod color;
+#[cfg(any(feature = "image", feature = "svg"))]
+mod image;
+mod quad;
+mod text;
+mod triangle;
+
+pub mod settings;
+
+pub use backend::Backend;
+pub use settings::Settings;
+
+pub use iced_graphics::{Error, Viewport};
+pub use iced_native::Theme;
+
+pub use iced_native::alignment;
+pub use iced_native::{Alignment, Background, Color, Command, Length, Vector};
+
+/// A [`tiny-skia`] graphics renderer for [`iced`].
+///
+/// [`tiny-skia`]: https://github.com/RazrFalcon/tiny-skia
+/// [`iced`]: https://github.com/iced-rs/iced
+pub type Renderer<Theme = iced_native::Theme> =
+    iced_graphics::Renderer<Backend, Theme>;
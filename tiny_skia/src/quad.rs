@@ -0,0 +1,147 @@
+use crate::color;
+
+use iced_graphics::layer;
+
+pub fn draw(
+    pixels: &mut tiny_skia::PixmapMut<'_>,
+    quad: &layer::Quad,
+    scale_factor: f32,
+    clip_mask: Option<&tiny_skia::ClipMask>,
+) {
+    let [x, y] = quad.position;
+    let [width, height] = quad.size;
+
+    if width <= 0.0 || height <= 0.0 {
+        return;
+    }
+
+    let transform =
+        tiny_skia::Transform::from_scale(scale_factor, scale_factor);
+
+    // Like the GPU renderers, the radii are clamped to half of the smallest
+    // side and the border is drawn inside the bounds of the quad.
+    let max_radius = width.min(height) / 2.0;
+    let radii = quad.border_radius.map(|radius| radius.min(max_radius));
+
+    if let Some(path) = rounded_rectangle(x, y, width, height, radii) {
+        let paint = tiny_skia::Paint {
+            shader: tiny_skia::Shader::SolidColor(color::from_linear(
+                quad.color,
+            )),
+            anti_alias: true,
+            ..tiny_skia::Paint::default()
+        };
+
+        let _ = pixels.fill_path(
+            &path,
+            &paint,
+            tiny_skia::FillRule::EvenOdd,
+            transform,
+            clip_mask,
+        );
+    }
+
+    let border_width = quad.border_width.min(max_radius.max(0.0) * 2.0);
+
+    if border_width > 0.0 {
+        let inset = border_width / 2.0;
+
+        let path = rounded_rectangle(
+            x + inset,
+            y + inset,
+            width - border_width,
+            height - border_width,
+            radii.map(|radius| (radius - inset).max(0.0)),
+        );
+
+        if let Some(path) = path {
+            let paint = tiny_skia::Paint {
+                shader: tiny_skia::Shader::SolidColor(color::from_linear(
+                    quad.border_color,
+                )),
+                anti_alias: true,
+                ..tiny_skia::Paint::default()
+            };
+
+            let _ = pixels.stroke_path(
+                &path,
+                &paint,
+                &tiny_skia::Stroke {
+                    width: border_width,
+                    ..tiny_skia::Stroke::default()
+                },
+                transform,
+                clip_mask,
+            );
+        }
+    }
+}
+
+/// Builds the path of a rectangle with the given radii, in the order:
+/// top-left, top-right, bottom-right, bottom-left.
+fn rounded_rectangle(
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    [top_left, top_right, bottom_right, bottom_left]: [f32; 4],
+) -> Option<tiny_skia::Path> {
+    if top_left == 0.0
+        && top_right == 0.0
+        && bottom_right == 0.0
+        && bottom_left == 0.0
+    {
+        return tiny_skia::Rect::from_xywh(x, y, width, height)
+            .map(tiny_skia::PathBuilder::from_rect);
+    }
+
+    // The distance of the control points of a cubic Bézier curve
+    // approximating a quarter of a circle, relative to its radius.
+    const KAPPA: f32 = 0.552_284_8;
+
+    let right = x + width;
+    let bottom = y + height;
+
+    let mut builder = tiny_skia::PathBuilder::new();
+
+    builder.move_to(x + top_left, y);
+    builder.line_to(right - top_right, y);
+    builder.cubic_to(
+        right - top_right * (1.0 - KAPPA),
+        y,
+        right,
+        y + top_right * (1.0 - KAPPA),
+        right,
+        y + top_right,
+    );
+    builder.line_to(right, bottom - bottom_right);
+    builder.cubic_to(
+        right,
+        bottom - bottom_right * (1.0 - KAPPA),
+        right - bottom_right * (1.0 - KAPPA),
+        bottom,
+        right - bottom_right,
+        bottom,
+    );
+    builder.line_to(x + bottom_left, bottom);
+    builder.cubic_to(
+        x + bottom_left * (1.0 - KAPPA),
+        bottom,
+        x,
+        bottom - bottom_left * (1.0 - KAPPA),
+        x,
+        bottom - bottom_left,
+    );
+    builder.line_to(x, y + top_left);
+    builder.cubic_to(
+        x,
+        y + top_left * (1.0 - KAPPA),
+        x + top_left * (1.0 - KAPPA),
+        y,
+        x + top_left,
+        y,
+    );
+    builder.close();
+
+    builder.finish()
+}
@@ -0,0 +1,320 @@
+use crate::color;
+
+use iced_graphics::font;
+use iced_graphics::layer;
+use iced_native::alignment;
+
+use glyph_brush::ab_glyph;
+use glyph_brush::GlyphCruncher;
+use std::{cell::RefCell, collections::HashMap};
+
+pub use iced_native::text::Hit;
+
+#[derive(Debug)]
+pub struct Pipeline {
+    brush: RefCell<glyph_brush::GlyphBrush<()>>,
+    font_map: RefCell<HashMap<String, glyph_brush::FontId>>,
+}
+
+impl Pipeline {
+    pub fn new(default_font: Option<&[u8]>) -> Self {
+        let default_font = default_font.map(|slice| slice.to_vec());
+
+        // TODO: Font customization
+        #[cfg(feature = "default_system_font")]
+        let default_font = {
+            default_font.or_else(|| {
+                font::Source::new()
+                    .load(&[font::Family::SansSerif, font::Family::Serif])
+                    .ok()
+            })
+        };
+
+        let default_font =
+            default_font.unwrap_or_else(|| font::FALLBACK.to_vec());
+
+        let font = ab_glyph::FontArc::try_from_vec(default_font)
+            .unwrap_or_else(|_| {
+                log::warn!(
+                    "System font failed to load. Falling back to \
+                    embedded font..."
+                );
+
+                ab_glyph::FontArc::try_from_slice(font::FALLBACK)
+                    .expect("Load fallback font")
+            });
+
+        let brush = glyph_brush::GlyphBrushBuilder::using_font(font).build();
+
+        Pipeline {
+            brush: RefCell::new(brush),
+            font_map: RefCell::new(HashMap::new()),
+        }
+    }
+
+    pub fn draw(
+        &mut self,
+        pixels: &mut tiny_skia::PixmapMut<'_>,
+        text: &layer::Text<'_>,
+        scale_factor: f32,
+        clip_bounds: iced_native::Rectangle<u32>,
+    ) {
+        // Target physical coordinates directly to avoid blurry text, like the
+        // GPU renderers do.
+        let section = glyph_brush::Section {
+            screen_position: (
+                (text.bounds.x * scale_factor).round(),
+                (text.bounds.y * scale_factor).round(),
+            ),
+            bounds: (
+                (text.bounds.width * scale_factor).ceil(),
+                (text.bounds.height * scale_factor).ceil(),
+            ),
+            text: vec![glyph_brush::Text {
+                text: text.content,
+                scale: ab_glyph::PxScale {
+                    x: text.size * scale_factor,
+                    y: text.size * scale_factor,
+                },
+                font_id: self.find_font(text.font),
+                extra: glyph_brush::Extra::default(),
+            }],
+            layout: glyph_brush::Layout::default()
+                .h_align(match text.horizontal_alignment {
+                    alignment::Horizontal::Left => {
+                        glyph_brush::HorizontalAlign::Left
+                    }
+                    alignment::Horizontal::Center => {
+                        glyph_brush::HorizontalAlign::Center
+                    }
+                    alignment::Horizontal::Right => {
+                        glyph_brush::HorizontalAlign::Right
+                    }
+                })
+                .v_align(match text.vertical_alignment {
+                    alignment::Vertical::Top => glyph_brush::VerticalAlign::Top,
+                    alignment::Vertical::Center => {
+                        glyph_brush::VerticalAlign::Center
+                    }
+                    alignment::Vertical::Bottom => {
+                        glyph_brush::VerticalAlign::Bottom
+                    }
+                }),
+        };
+
+        let color = color::from_linear(text.color).premultiply();
+
+        let mut brush = self.brush.borrow_mut();
+        let glyphs: Vec<_> = brush.glyphs(section).cloned().collect();
+
+        let width = pixels.width();
+        let data = pixels.data_mut();
+
+        for glyph in glyphs {
+            use ab_glyph::Font;
+
+            let outline = match brush.fonts()[glyph.font_id.0]
+                .outline_glyph(glyph.glyph)
+            {
+                Some(outline) => outline,
+                None => continue,
+            };
+
+            let bounds = outline.px_bounds();
+
+            outline.draw(|x, y, coverage| {
+                let x = bounds.min.x as i64 + i64::from(x);
+                let y = bounds.min.y as i64 + i64::from(y);
+
+                if x < i64::from(clip_bounds.x)
+                    || y < i64::from(clip_bounds.y)
+                    || x >= i64::from(clip_bounds.x + clip_bounds.width)
+                    || y >= i64::from(clip_bounds.y + clip_bounds.height)
+                {
+                    return;
+                }
+
+                let index = (y as usize * width as usize + x as usize) * 4;
+
+                blend(&mut data[index..index + 4], color, coverage);
+            });
+        }
+    }
+
+    pub fn measure(
+        &self,
+        content: &str,
+        size: f32,
+        font: iced_native::Font,
+        bounds: iced_native::Size,
+    ) -> (f32, f32) {
+        let section = glyph_brush::Section {
+            bounds: (bounds.width, bounds.height),
+            text: vec![glyph_brush::Text {
+                text: content,
+                scale: size.into(),
+                font_id: self.find_font(font),
+                extra: glyph_brush::Extra::default(),
+            }],
+            ..Default::default()
+        };
+
+        if let Some(bounds) = self.brush.borrow_mut().glyph_bounds(section) {
+            (bounds.width().ceil(), bounds.height().ceil())
+        } else {
+            (0.0, 0.0)
+        }
+    }
+
+    pub fn hit_test(
+        &self,
+        content: &str,
+        size: f32,
+        font: iced_native::Font,
+        bounds: iced_native::Size,
+        point: iced_native::Point,
+        nearest_only: bool,
+    ) -> Option<Hit> {
+        let font_id = self.find_font(font);
+
+        let section = glyph_brush::Section {
+            bounds: (bounds.width, bounds.height),
+            text: vec![glyph_brush::Text {
+                text: content,
+                scale: size.into(),
+                font_id,
+                extra: glyph_brush::Extra::default(),
+            }],
+            ..Default::default()
+        };
+
+        let mut brush = self.brush.borrow_mut();
+
+        // The underlying type is FontArc, so clones are cheap.
+        use ab_glyph::{Font, ScaleFont};
+        let font = brush.fonts()[font_id.0].clone().into_scaled(size);
+
+        // Implements an iterator over the glyph bounding boxes.
+        let bounds = brush.glyphs(section).map(
+            |glyph_brush::SectionGlyph {
+                 byte_index, glyph, ..
+             }| {
+                (
+                    *byte_index,
+                    iced_native::Rectangle::new(
+                        iced_native::Point::new(
+                            glyph.position.x - font.h_side_bearing(glyph.id),
+                            glyph.position.y - font.ascent(),
+                        ),
+                        iced_native::Size::new(
+                            font.h_advance(glyph.id),
+                            font.ascent() - font.descent(),
+                        ),
+                    ),
+                )
+            },
+        );
+
+        // Implements computation of the character index based on the byte index
+        // within the input string.
+        let char_index = |byte_index| {
+            let mut b_count = 0;
+            for (i, utf8_len) in
+                content.chars().map(|c| c.len_utf8()).enumerate()
+            {
+                if byte_index < (b_count + utf8_len) {
+                    return i;
+                }
+                b_count += utf8_len;
+            }
+
+            byte_index
+        };
+
+        if !nearest_only {
+            for (idx, bounds) in bounds.clone() {
+                if bounds.contains(point) {
+                    return Some(Hit::CharOffset(char_index(idx)));
+                }
+            }
+        }
+
+        let nearest = bounds
+            .map(|(index, bounds)| (index, bounds.center()))
+            .min_by(|(_, center_a), (_, center_b)| {
+                center_a
+                    .distance(point)
+                    .partial_cmp(&center_b.distance(point))
+                    .unwrap_or(std::cmp::Ordering::Greater)
+            });
+
+        nearest.map(|(idx, center)| {
+            Hit::NearestCharOffset(char_index(idx), point - center)
+        })
+    }
+
+    pub fn trim_cache(&mut self) {
+        loop {
+            let action =
+                self.brush.borrow_mut().process_queued(|_, _| {}, |_| {});
+
+            match action {
+                Ok(_) => break,
+                Err(glyph_brush::BrushError::TextureTooSmall { suggested }) => {
+                    let (width, height) = suggested;
+
+                    self.brush.borrow_mut().resize_texture(width, height);
+                }
+            }
+        }
+    }
+
+    pub fn find_font(&self, font: iced_native::Font) -> glyph_brush::FontId {
+        match font {
+            iced_native::Font::Default => glyph_brush::FontId(0),
+            iced_native::Font::External { name, bytes } => {
+                if let Some(font_id) = self.font_map.borrow().get(name) {
+                    return *font_id;
+                }
+
+                let font = ab_glyph::FontArc::try_from_slice(bytes)
+                    .expect("Load font");
+
+                let font_id = self.brush.borrow_mut().add_font(font);
+
+                let _ = self
+                    .font_map
+                    .borrow_mut()
+                    .insert(String::from(name), font_id);
+
+                font_id
+            }
+        }
+    }
+}
+
+/// Blends a premultiplied color with the given coverage over a premultiplied
+/// RGBA pixel.
+fn blend(
+    pixel: &mut [u8],
+    color: tiny_skia::PremultipliedColor,
+    coverage: f32,
+) {
+    let coverage = coverage.clamp(0.0, 1.0);
+    let alpha = color.alpha() * coverage;
+
+    let source = [
+        color.red() * coverage,
+        color.green() * coverage,
+        color.blue() * coverage,
+        alpha,
+    ];
+
+    for (channel, source) in pixel.iter_mut().zip(source) {
+        let destination = f32::from(*channel) / 255.0;
+
+        *channel = ((source + destination * (1.0 - alpha)) * 255.0)
+            .round()
+            .clamp(0.0, 255.0) as u8;
+    }
+}
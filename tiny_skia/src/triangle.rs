@@ -0,0 +1,141 @@
+use crate::color;
+
+use iced_graphics::gradient::Gradient;
+use iced_graphics::layer::Mesh;
+
+pub fn draw(
+    pixels: &mut tiny_skia::PixmapMut<'_>,
+    mesh: &Mesh<'_>,
+    scale_factor: f32,
+    clip_mask: Option<&tiny_skia::ClipMask>,
+) {
+    let origin = mesh.origin();
+    let transform = tiny_skia::Transform::from_row(
+        scale_factor,
+        0.0,
+        0.0,
+        scale_factor,
+        origin.x * scale_factor,
+        origin.y * scale_factor,
+    );
+
+    // Antialiasing is disabled to avoid seams between adjacent triangles,
+    // matching the GPU renderers when multisampling is off.
+    match mesh {
+        Mesh::Solid { buffers, .. } => {
+            for triangle in buffers.indices.chunks_exact(3) {
+                let vertices = [
+                    buffers.vertices[triangle[0] as usize],
+                    buffers.vertices[triangle[1] as usize],
+                    buffers.vertices[triangle[2] as usize],
+                ];
+
+                // Colors are only interpolated across a triangle on the GPU.
+                // Here, each triangle is filled with the average color of its
+                // vertices instead.
+                let color = vertices.iter().fold([0.0; 4], |sum, vertex| {
+                    [
+                        sum[0] + vertex.color[0] / 3.0,
+                        sum[1] + vertex.color[1] / 3.0,
+                        sum[2] + vertex.color[2] / 3.0,
+                        sum[3] + vertex.color[3] / 3.0,
+                    ]
+                });
+
+                let path = match path(vertices.iter().map(|v| v.position)) {
+                    Some(path) => path,
+                    None => continue,
+                };
+
+                let paint = tiny_skia::Paint {
+                    shader: tiny_skia::Shader::SolidColor(color::from_linear(
+                        color,
+                    )),
+                    anti_alias: false,
+                    ..tiny_skia::Paint::default()
+                };
+
+                let _ = pixels.fill_path(
+                    &path,
+                    &paint,
+                    tiny_skia::FillRule::Winding,
+                    transform,
+                    clip_mask,
+                );
+            }
+        }
+        Mesh::Gradient {
+            buffers, gradient, ..
+        } => {
+            let shader = match gradient {
+                Gradient::Linear(linear) => tiny_skia::LinearGradient::new(
+                    tiny_skia::Point::from_xy(linear.start.x, linear.start.y),
+                    tiny_skia::Point::from_xy(linear.end.x, linear.end.y),
+                    linear
+                        .color_stops
+                        .iter()
+                        .map(|stop| {
+                            let [r, g, b, a] = stop.color.into_rgba8();
+
+                            tiny_skia::GradientStop::new(
+                                stop.offset,
+                                tiny_skia::Color::from_rgba8(r, g, b, a),
+                            )
+                        })
+                        .collect(),
+                    tiny_skia::SpreadMode::Pad,
+                    tiny_skia::Transform::identity(),
+                ),
+            };
+
+            let shader = match shader {
+                Some(shader) => shader,
+                None => return,
+            };
+
+            let path = path(
+                buffers
+                    .indices
+                    .iter()
+                    .map(|index| buffers.vertices[*index as usize].position),
+            );
+
+            let path = match path {
+                Some(path) => path,
+                None => return,
+            };
+
+            let paint = tiny_skia::Paint {
+                shader,
+                anti_alias: false,
+                ..tiny_skia::Paint::default()
+            };
+
+            let _ = pixels.fill_path(
+                &path,
+                &paint,
+                tiny_skia::FillRule::Winding,
+                transform,
+                clip_mask,
+            );
+        }
+    }
+}
+
+/// Builds a path out of a list of triangles.
+fn path(
+    mut positions: impl Iterator<Item = [f32; 2]>,
+) -> Option<tiny_skia::Path> {
+    let mut builder = tiny_skia::PathBuilder::new();
+
+    while let (Some(a), Some(b), Some(c)) =
+        (positions.next(), positions.next(), positions.next())
+    {
+        builder.move_to(a[0], a[1]);
+        builder.line_to(b[0], b[1]);
+        builder.line_to(c[0], c[1]);
+        builder.close();
+    }
+
+    builder.finish()
+}
@@ -181,6 +181,10 @@ impl Pipeline {
         device: &wgpu::Device,
         shader_module: &wgpu::ShaderModule,
     ) -> wgpu::RenderPipeline {
+        #[cfg(feature = "trace")]
+        let _span = tracing::info_span!("compile_custom_shader_pipeline")
+            .entered();
+
         let pipeline =
             device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
                 label: Some("iced_wgpu::custom shader quad pipeline"),
@@ -296,6 +300,13 @@ impl Pipeline {
             return false;
         }
 
+        #[cfg(feature = "trace")]
+        let _span = tracing::info_span!(
+            "compile_custom_shader_module",
+            shader = shader_handle.id
+        )
+        .entered();
+
         let shader_code = self.read_shader(&shader_handle);
 
         let shader_module =
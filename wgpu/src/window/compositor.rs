@@ -2,11 +2,12 @@ use crate::{Backend, Color, Error, Renderer, Settings, Viewport};
 
 use futures::stream::{self, StreamExt};
 
-use iced_graphics::compositor;
+use iced_graphics::{compositor, Size};
 use iced_native::futures;
 use raw_window_handle::{HasRawDisplayHandle, HasRawWindowHandle};
 
 use std::marker::PhantomData;
+use std::num::NonZeroU32;
 
 /// A window graphics backend for iced powered by `wgpu`.
 #[allow(missing_debug_implementations)]
@@ -196,33 +197,7 @@ impl<Theme> iced_graphics::window::Compositor for Compositor<Theme> {
                     .texture
                     .create_view(&wgpu::TextureViewDescriptor::default());
 
-                let _ =
-                    encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-                        label: Some(
-                            "iced_wgpu::window::Compositor render pass",
-                        ),
-                        color_attachments: &[Some(
-                            wgpu::RenderPassColorAttachment {
-                                view,
-                                resolve_target: None,
-                                ops: wgpu::Operations {
-                                    load: wgpu::LoadOp::Clear({
-                                        let [r, g, b, a] =
-                                            background_color.into_linear();
-
-                                        wgpu::Color {
-                                            r: f64::from(r),
-                                            g: f64::from(g),
-                                            b: f64::from(b),
-                                            a: f64::from(a),
-                                        }
-                                    }),
-                                    store: true,
-                                },
-                            },
-                        )],
-                        depth_stencil_attachment: None,
-                    });
+                clear(&mut encoder, view, background_color);
 
                 renderer.with_primitives(|backend, primitives| {
                     backend.present(
@@ -260,4 +235,162 @@ impl<Theme> iced_graphics::window::Compositor for Compositor<Theme> {
             },
         }
     }
+
+    /// Renders the primitives of the [`Renderer`] again into an offscreen
+    /// texture and reads it back, instead of reading back the presented
+    /// frame, since surface textures cannot be copied.
+    ///
+    /// The result matches the presented frame as long as the primitives do
+    /// not change in between.
+    ///
+    /// [`Renderer`]: Self::Renderer
+    fn screenshot<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        _surface: &mut Self::Surface,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+    ) -> Vec<u8> {
+        let Size { width, height } = viewport.physical_size();
+
+        let extent = wgpu::Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        };
+
+        let texture = self.device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("iced_wgpu::window::Compositor screenshot texture"),
+            size: extent,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: self.format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+                | wgpu::TextureUsages::COPY_SRC,
+        });
+
+        let view =
+            &texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        // Rows copied into a buffer must be aligned.
+        let row_length = width * 4;
+        let padded_row_length = {
+            let alignment = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+
+            (row_length + alignment - 1) / alignment * alignment
+        };
+
+        let buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("iced_wgpu::window::Compositor screenshot buffer"),
+            size: u64::from(padded_row_length * height),
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        let mut encoder = self.device.create_command_encoder(
+            &wgpu::CommandEncoderDescriptor {
+                label: Some("iced_wgpu screenshot encoder"),
+            },
+        );
+
+        clear(&mut encoder, view, background_color);
+
+        renderer.with_primitives(|backend, primitives| {
+            backend.present(
+                &self.device,
+                &mut self.staging_belt,
+                &mut encoder,
+                view,
+                primitives,
+                viewport,
+                overlay,
+            );
+        });
+
+        encoder.copy_texture_to_buffer(
+            wgpu::ImageCopyTexture {
+                texture: &texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            wgpu::ImageCopyBuffer {
+                buffer: &buffer,
+                layout: wgpu::ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: NonZeroU32::new(padded_row_length),
+                    rows_per_image: None,
+                },
+            },
+            extent,
+        );
+
+        self.staging_belt.finish();
+        let _submission = self.queue.submit(Some(encoder.finish()));
+        self.staging_belt.recall();
+
+        let slice = buffer.slice(..);
+        slice.map_async(wgpu::MapMode::Read, |result| {
+            if let Err(error) = result {
+                log::error!("Failed to map screenshot buffer: {:?}", error);
+            }
+        });
+
+        let _ = self.device.poll(wgpu::Maintain::Wait);
+
+        let is_bgra = matches!(
+            self.format,
+            wgpu::TextureFormat::Bgra8Unorm
+                | wgpu::TextureFormat::Bgra8UnormSrgb
+        );
+
+        let mut pixels = Vec::with_capacity((row_length * height) as usize);
+
+        for row in slice
+            .get_mapped_range()
+            .chunks_exact(padded_row_length as usize)
+        {
+            pixels.extend_from_slice(&row[..row_length as usize]);
+        }
+
+        buffer.unmap();
+
+        if is_bgra {
+            pixels
+                .chunks_exact_mut(4)
+                .for_each(|pixel| pixel.swap(0, 2));
+        }
+
+        pixels
+    }
+}
+
+fn clear(
+    encoder: &mut wgpu::CommandEncoder,
+    view: &wgpu::TextureView,
+    background_color: Color,
+) {
+    let _ = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+        label: Some("iced_wgpu::window::Compositor render pass"),
+        color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+            view,
+            resolve_target: None,
+            ops: wgpu::Operations {
+                load: wgpu::LoadOp::Clear({
+                    let [r, g, b, a] = background_color.into_linear();
+
+                    wgpu::Color {
+                        r: f64::from(r),
+                        g: f64::from(g),
+                        b: f64::from(b),
+                        a: f64::from(a),
+                    }
+                }),
+                store: true,
+            },
+        })],
+        depth_stencil_attachment: None,
+    });
 }
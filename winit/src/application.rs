@@ -3,14 +3,19 @@ mod state;
 
 pub use state::State;
 
-use crate::clipboard::{self, Clipboard};
+use crate::clipboard::Clipboard;
 use crate::conversion;
+use crate::geometry::Geometry;
 use crate::mouse;
 use crate::renderer;
+use crate::screenshot;
+use crate::session::Session;
+use crate::settings;
+use crate::trace::Trace;
 use crate::widget::operation;
 use crate::{
-    Command, Debug, Error, Executor, Proxy, Runtime, Settings, Size,
-    Subscription,
+    Color, Command, Debug, Error, Executor, Point, Proxy, Runtime, Settings,
+    Size, Subscription, Viewport,
 };
 
 use iced_futures::futures;
@@ -34,7 +39,9 @@ use std::mem::ManuallyDrop;
 /// [`Command`] in some of its methods.
 ///
 /// When using an [`Application`] with the `debug` feature enabled, a debug view
-/// can be toggled by pressing `F12`.
+/// can be toggled by pressing `F12`. Pressing `Shift+F12` toggles a widget
+/// inspector that highlights the widget under the cursor and shows the
+/// widget tree.
 pub trait Application: Program
 where
     <Self::Renderer as crate::Renderer>::Theme: StyleSheet,
@@ -118,6 +125,8 @@ where
     use futures::Future;
     use winit::event_loop::EventLoopBuilder;
 
+    let mut trace = Trace::start(&settings);
+
     let mut debug = Debug::new();
     debug.startup_started();
 
@@ -131,6 +140,9 @@ where
         Runtime::new(executor, proxy)
     };
 
+    let geometry_id = persisted_geometry_id(&settings);
+    let session = Session::open(&settings).map_err(Error::SessionOpenFailed)?;
+
     let (application, init_command) = {
         let flags = settings.flags;
 
@@ -140,17 +152,13 @@ where
     #[cfg(target_arch = "wasm32")]
     let target = settings.window.platform_specific.target.clone();
 
-    let builder = settings.window.into_builder(
+    let window = build_window(
+        settings.window,
         &application.title(),
-        event_loop.primary_monitor(),
         settings.id,
-    );
-
-    log::info!("Window builder: {:#?}", builder);
-
-    let window = builder
-        .build(&event_loop)
-        .map_err(Error::WindowCreationFailed)?;
+        geometry_id.as_deref(),
+        &event_loop,
+    )?;
 
     #[cfg(target_arch = "wasm32")]
     {
@@ -181,6 +189,7 @@ where
     let (compositor, renderer) = C::new(compositor_settings, Some(&window))?;
 
     let (mut sender, receiver) = mpsc::unbounded();
+    let (control_sender, mut control_receiver) = mpsc::unbounded();
 
     let mut instance = Box::pin(run_instance::<A, E, C>(
         application,
@@ -190,9 +199,13 @@ where
         proxy,
         debug,
         receiver,
+        control_sender,
         init_command,
         window,
         settings.exit_on_close_request,
+        settings.focus_traversal,
+        geometry_id,
+        session,
     ));
 
     let mut context = task::Context::from_waker(task::noop_waker_ref());
@@ -204,30 +217,27 @@ where
             return;
         }
 
-        let event = match event {
-            winit::event::Event::WindowEvent {
-                event:
-                    winit::event::WindowEvent::ScaleFactorChanged {
-                        new_inner_size,
-                        ..
-                    },
-                window_id,
-            } => Some(winit::event::Event::WindowEvent {
-                event: winit::event::WindowEvent::Resized(*new_inner_size),
-                window_id,
-            }),
-            _ => event.to_static(),
-        };
-
-        if let Some(event) = event {
+        if let Some(event) = static_event(event) {
             sender.start_send(event).expect("Send event");
 
             let poll = instance.as_mut().poll(&mut context);
 
-            *control_flow = match poll {
-                task::Poll::Pending => ControlFlow::Wait,
-                task::Poll::Ready(_) => ControlFlow::Exit,
-            };
+            match poll {
+                task::Poll::Pending => {
+                    if let Ok(Some(flow)) = control_receiver.try_next() {
+                        *control_flow = flow;
+                    } else if !matches!(control_flow, ControlFlow::WaitUntil(_))
+                    {
+                        *control_flow = ControlFlow::Wait;
+                    }
+                }
+                task::Poll::Ready(_) => {
+                    // Flush the performance trace, if any, before exiting
+                    drop(trace.take());
+
+                    *control_flow = ControlFlow::Exit;
+                }
+            }
         }
     })
 }
@@ -240,9 +250,13 @@ async fn run_instance<A, E, C>(
     mut proxy: winit::event_loop::EventLoopProxy<A::Message>,
     mut debug: Debug,
     mut receiver: mpsc::UnboundedReceiver<winit::event::Event<'_, A::Message>>,
+    mut control_sender: mpsc::UnboundedSender<winit::event_loop::ControlFlow>,
     init_command: Command<A::Message>,
     window: winit::window::Window,
     exit_on_close_request: bool,
+    focus_traversal: bool,
+    geometry_id: Option<String>,
+    mut session: Session,
 ) where
     A: Application + 'static,
     E: Executor + 'static,
@@ -256,8 +270,15 @@ async fn run_instance<A, E, C>(
     let mut cache = user_interface::Cache::default();
     let mut surface = compositor.create_surface(&window);
 
-    let mut state = State::new(&application, &window);
+    let mut state = State::new(
+        &window,
+        application.title(),
+        application.scale_factor(),
+        application.theme(),
+        &application.style(),
+    );
     let mut viewport_version = state.viewport_version();
+    let mut screenshots = screenshot::Queue::new();
 
     let physical_size = state.physical_size();
 
@@ -270,7 +291,7 @@ async fn run_instance<A, E, C>(
     run_command(
         &application,
         &mut cache,
-        &state,
+        &mut state,
         &mut renderer,
         init_command,
         &mut runtime,
@@ -278,6 +299,7 @@ async fn run_instance<A, E, C>(
         &mut proxy,
         &mut debug,
         &window,
+        &mut screenshots,
         || compositor.fetch_information(),
     );
     runtime.track(application.subscription());
@@ -290,6 +312,7 @@ async fn run_instance<A, E, C>(
         &mut debug,
     ));
 
+    let mut geometry = geometry_id.as_ref().map(|_| Geometry::of(&window));
     let mut mouse_interaction = mouse::Interaction::default();
     let mut events = Vec::new();
     let mut messages = Vec::new();
@@ -298,31 +321,90 @@ async fn run_instance<A, E, C>(
 
     while let Some(event) = receiver.next().await {
         match event {
+            event::Event::NewEvents(event::StartCause::ResumeTimeReached {
+                ..
+            }) => {
+                if !session.is_replaying() {
+                    events.push(iced_native::Event::Window(
+                        iced_native::window::Id::MAIN,
+                        iced_native::window::Event::RedrawRequested(
+                            iced_native::time::Instant::now(),
+                        ),
+                    ));
+                }
+            }
             event::Event::MainEventsCleared => {
+                let mut cursor_position = state.cursor_position();
+
+                if let Some(step) = session.next_step(&window) {
+                    events = step.events;
+                    cursor_position = step.cursor_position;
+                }
+
                 if events.is_empty() && messages.is_empty() {
+                    if let Some(at) = session.next_wake_up(None) {
+                        let _ = control_sender.start_send(
+                            winit::event_loop::ControlFlow::WaitUntil(at),
+                        );
+                    }
+
                     continue;
                 }
 
+                session.record_update(
+                    state.viewport(),
+                    state.viewport_version(),
+                    &events,
+                    cursor_position,
+                );
+
                 debug.event_processing_started();
 
-                let (interface_state, statuses) = user_interface.update(
+                let (interface_state, mut statuses) = user_interface.update(
                     &events,
-                    state.cursor_position(),
+                    cursor_position,
                     &mut renderer,
                     &mut clipboard,
                     &mut messages,
                 );
 
+                if focus_traversal {
+                    user_interface.traverse_focus(
+                        &events,
+                        &mut statuses,
+                        &renderer,
+                    );
+                }
+
                 debug.event_processing_finished();
 
-                for event in events.drain(..).zip(statuses.into_iter()) {
-                    runtime.broadcast(event);
+                session.record_messages(&messages);
+
+                for (event, status) in
+                    events.drain(..).zip(statuses.into_iter())
+                {
+                    // Subscriptions are notified of redraws once the frame
+                    // is presented
+                    if !is_redraw_request(&event) {
+                        runtime.broadcast((event, status));
+                    }
                 }
 
+                let redraw_request = interface_state.redraw_request();
+
+                let _ = control_sender.start_send(
+                    match session.next_wake_up(redraw_request) {
+                        Some(at) => {
+                            winit::event_loop::ControlFlow::WaitUntil(at)
+                        }
+                        None => winit::event_loop::ControlFlow::Wait,
+                    },
+                );
+
                 if !messages.is_empty()
                     || matches!(
                         interface_state,
-                        user_interface::State::Outdated,
+                        user_interface::State::Outdated { .. },
                     )
                 {
                     let mut cache =
@@ -332,7 +414,7 @@ async fn run_instance<A, E, C>(
                     update(
                         &mut application,
                         &mut cache,
-                        &state,
+                        &mut state,
                         &mut renderer,
                         &mut runtime,
                         &mut clipboard,
@@ -340,11 +422,18 @@ async fn run_instance<A, E, C>(
                         &mut debug,
                         &mut messages,
                         &window,
+                        &mut screenshots,
                         || compositor.fetch_information(),
                     );
 
                     // Update window
-                    state.synchronize(&application, &window);
+                    state.synchronize(
+                        &window,
+                        application.title(),
+                        application.scale_factor(),
+                        application.theme(),
+                        &application.style(),
+                    );
 
                     let should_exit = application.should_exit();
 
@@ -361,24 +450,19 @@ async fn run_instance<A, E, C>(
                     }
                 }
 
-                debug.draw_started();
-                let new_mouse_interaction = user_interface.draw(
+                draw(
+                    &mut user_interface,
                     &mut renderer,
                     state.theme(),
                     &renderer::Style {
                         text_color: state.text_color(),
                     },
                     state.cursor_position(),
+                    state.cursor_icon(),
+                    &mut debug,
+                    &window,
+                    &mut mouse_interaction,
                 );
-                debug.draw_finished();
-
-                if new_mouse_interaction != mouse_interaction {
-                    window.set_cursor_icon(conversion::mouse_interaction(
-                        new_mouse_interaction,
-                    ));
-
-                    mouse_interaction = new_mouse_interaction;
-                }
 
                 window.request_redraw();
             }
@@ -387,11 +471,13 @@ async fn run_instance<A, E, C>(
             )) => {
                 use iced_native::event;
 
-                events.push(iced_native::Event::PlatformSpecific(
-                    event::PlatformSpecific::MacOS(event::MacOS::ReceivedUrl(
-                        url,
-                    )),
-                ));
+                if !session.is_replaying() {
+                    events.push(iced_native::Event::PlatformSpecific(
+                        event::PlatformSpecific::MacOS(
+                            event::MacOS::ReceivedUrl(url),
+                        ),
+                    ));
+                }
             }
             event::Event::UserEvent(message) => {
                 messages.push(message);
@@ -416,25 +502,20 @@ async fn run_instance<A, E, C>(
                     );
                     debug.layout_finished();
 
-                    debug.draw_started();
-                    let new_mouse_interaction = user_interface.draw(
+                    draw(
+                        &mut user_interface,
                         &mut renderer,
                         state.theme(),
                         &renderer::Style {
                             text_color: state.text_color(),
                         },
                         state.cursor_position(),
+                        state.cursor_icon(),
+                        &mut debug,
+                        &window,
+                        &mut mouse_interaction,
                     );
 
-                    if new_mouse_interaction != mouse_interaction {
-                        window.set_cursor_icon(conversion::mouse_interaction(
-                            new_mouse_interaction,
-                        ));
-
-                        mouse_interaction = new_mouse_interaction;
-                    }
-                    debug.draw_finished();
-
                     compositor.configure_surface(
                         &mut surface,
                         physical_size.width,
@@ -444,37 +525,35 @@ async fn run_instance<A, E, C>(
                     viewport_version = current_viewport_version;
                 }
 
-                match compositor.present(
+                present(
+                    &mut compositor,
                     &mut renderer,
                     &mut surface,
                     state.viewport(),
                     state.background_color(),
-                    &debug.overlay(),
-                ) {
-                    Ok(()) => {
-                        debug.render_finished();
-
-                        // TODO: Handle animations!
-                        // Maybe we can use `ControlFlow::WaitUntil` for this.
-                    }
-                    Err(error) => match error {
-                        // This is an unrecoverable error.
-                        compositor::SurfaceError::OutOfMemory => {
-                            panic!("{:?}", error);
-                        }
-                        _ => {
-                            debug.render_finished();
-
-                            // Try rendering again next frame.
-                            window.request_redraw();
-                        }
-                    },
-                }
+                    &mut debug,
+                    &mut screenshots,
+                    &mut runtime,
+                    &mut proxy,
+                    iced_native::window::Id::MAIN,
+                    &window,
+                );
             }
             event::Event::WindowEvent {
                 event: window_event,
                 ..
             } => {
+                // The application may be terminated right after a close
+                // request, so its geometry is stored right away.
+                if let (
+                    winit::event::WindowEvent::CloseRequested,
+                    Some(id),
+                    Some(geometry),
+                ) = (&window_event, &geometry_id, geometry)
+                {
+                    save_geometry(id, geometry);
+                }
+
                 if requests_exit(&window_event, state.modifiers())
                     && exit_on_close_request
                 {
@@ -483,7 +562,18 @@ async fn run_instance<A, E, C>(
 
                 state.update(&window, &window_event, &mut debug);
 
+                if let Some(geometry) = &mut geometry {
+                    geometry.track(&window, &window_event);
+                }
+
+                // When replaying a session, the recorded events are used
+                // instead.
+                if session.is_replaying() {
+                    continue;
+                }
+
                 if let Some(event) = conversion::window_event(
+                    iced_native::window::Id::MAIN,
                     &window_event,
                     state.scale_factor(),
                     state.modifiers(),
@@ -495,10 +585,210 @@ async fn run_instance<A, E, C>(
         }
     }
 
+    if let (Some(id), Some(geometry)) = (geometry_id, geometry) {
+        save_geometry(&id, geometry);
+    }
+
     // Manually drop the user interface
     drop(ManuallyDrop::into_inner(user_interface));
 }
 
+/// Builds a window with the given [`settings::Window`] and title, restoring
+/// the [`Geometry`] persisted with the given identifier, if any.
+pub fn build_window<T>(
+    mut settings: settings::Window,
+    title: &str,
+    id: Option<String>,
+    geometry_id: Option<&str>,
+    window_target: &winit::event_loop::EventLoopWindowTarget<T>,
+) -> Result<winit::window::Window, Error> {
+    let restored_geometry = geometry_id
+        .and_then(Geometry::load)
+        .map(|geometry| geometry.clamp(window_target.available_monitors()));
+
+    if let Some(geometry) = &restored_geometry {
+        geometry.apply(&mut settings);
+    }
+
+    let builder =
+        settings.into_builder(title, window_target.primary_monitor(), id);
+
+    log::info!("Window builder: {:#?}", builder);
+
+    let window = builder
+        .build(window_target)
+        .map_err(Error::WindowCreationFailed)?;
+
+    if restored_geometry.is_some_and(|geometry| geometry.maximized) {
+        window.set_maximized(true);
+    }
+
+    Ok(window)
+}
+
+/// Converts a [`winit`] event into a `'static` one, turning any scale factor
+/// change into a resize of the window.
+pub fn static_event<T>(
+    event: winit::event::Event<'_, T>,
+) -> Option<winit::event::Event<'static, T>> {
+    match event {
+        winit::event::Event::WindowEvent {
+            event:
+                winit::event::WindowEvent::ScaleFactorChanged {
+                    new_inner_size, ..
+                },
+            window_id,
+        } => Some(winit::event::Event::WindowEvent {
+            event: winit::event::WindowEvent::Resized(*new_inner_size),
+            window_id,
+        }),
+        _ => event.to_static(),
+    }
+}
+
+/// Draws the given [`UserInterface`], updating the cursor icon of its window
+/// if the resulting [`mouse::Interaction`] changed.
+///
+/// The `cursor_icon`, if any, overrides the [`mouse::Interaction`] of the
+/// [`UserInterface`].
+pub fn draw<Message, Renderer>(
+    user_interface: &mut UserInterface<'_, Message, Renderer>,
+    renderer: &mut Renderer,
+    theme: &Renderer::Theme,
+    style: &renderer::Style,
+    cursor_position: Point,
+    cursor_icon: Option<mouse::Interaction>,
+    debug: &mut Debug,
+    window: &winit::window::Window,
+    mouse_interaction: &mut mouse::Interaction,
+) where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    debug.draw_started();
+    let new_mouse_interaction =
+        user_interface.draw(renderer, theme, style, cursor_position);
+    debug.inspect(user_interface, renderer, cursor_position);
+    debug.draw_finished();
+
+    let new_mouse_interaction = cursor_icon.unwrap_or(new_mouse_interaction);
+
+    if new_mouse_interaction != *mouse_interaction {
+        window.set_cursor_icon(conversion::mouse_interaction(
+            new_mouse_interaction,
+        ));
+
+        *mouse_interaction = new_mouse_interaction;
+    }
+}
+
+/// Presents the last frame drawn by the renderer on the surface of the window
+/// with the given [`Id`].
+///
+/// Once the frame is presented, the pending screenshots of the window are
+/// fulfilled and subscriptions are notified of the redraw.
+///
+/// [`Id`]: iced_native::window::Id
+pub fn present<C, E, Message>(
+    compositor: &mut C,
+    renderer: &mut C::Renderer,
+    surface: &mut C::Surface,
+    viewport: &Viewport,
+    background_color: Color,
+    debug: &mut Debug,
+    screenshots: &mut screenshot::Queue<Message>,
+    runtime: &mut Runtime<E, Proxy<Message>, Message>,
+    proxy: &mut winit::event_loop::EventLoopProxy<Message>,
+    id: iced_native::window::Id,
+    window: &winit::window::Window,
+) where
+    C: window::Compositor,
+    E: Executor,
+    Message: Send + 'static,
+{
+    match compositor.present(
+        renderer,
+        surface,
+        viewport,
+        background_color,
+        &debug.overlay(),
+    ) {
+        Ok(()) => {
+            if !screenshots.is_empty() {
+                let bytes = compositor.screenshot(
+                    renderer,
+                    surface,
+                    viewport,
+                    background_color,
+                    &debug.overlay(),
+                );
+
+                for message in screenshots.fulfill(
+                    bytes,
+                    viewport.physical_size(),
+                    viewport.scale_factor(),
+                ) {
+                    proxy
+                        .send_event(message)
+                        .expect("Send message to event loop");
+                }
+            }
+
+            debug.render_finished();
+
+            // Notify subscriptions of the presented frame
+            runtime.broadcast((
+                iced_native::Event::Window(
+                    id,
+                    iced_native::window::Event::RedrawRequested(
+                        iced_native::time::Instant::now(),
+                    ),
+                ),
+                iced_native::event::Status::Ignored,
+            ));
+        }
+        Err(error) => match error {
+            // This is an unrecoverable error.
+            compositor::SurfaceError::OutOfMemory => {
+                panic!("{:?}", error);
+            }
+            _ => {
+                debug.render_finished();
+
+                // Try rendering again next frame.
+                window.request_redraw();
+            }
+        },
+    }
+}
+
+/// Returns the identifier used to persist the window geometry of an
+/// [`Application`], if enabled in the given [`Settings`].
+pub fn persisted_geometry_id<Flags>(
+    settings: &Settings<Flags>,
+) -> Option<String> {
+    if !settings.persist_window_geometry {
+        return None;
+    }
+
+    if settings.id.is_none() {
+        log::warn!(
+            "The window geometry can only be persisted if the application \
+            has an identifier"
+        );
+    }
+
+    settings.id.clone()
+}
+
+/// Stores the given window [`Geometry`] for the application with the given
+/// identifier, logging any error.
+pub fn save_geometry(id: &str, geometry: Geometry) {
+    if let Err(error) = geometry.save(id) {
+        log::warn!("Failed to store the window geometry: {}", error);
+    }
+}
+
 /// Returns true if the provided event should cause an [`Application`] to
 /// exit.
 pub fn requests_exit(
@@ -523,6 +813,20 @@ pub fn requests_exit(
     }
 }
 
+/// Returns true if the provided event is a redraw request of a window.
+///
+/// Subscriptions are not notified of these events until the requested frame
+/// is presented.
+pub fn is_redraw_request(event: &iced_native::Event) -> bool {
+    matches!(
+        event,
+        iced_native::Event::Window(
+            _,
+            iced_native::window::Event::RedrawRequested(_)
+        )
+    )
+}
+
 /// Builds a [`UserInterface`] for the provided [`Application`], logging
 /// [`struct@Debug`] information accordingly.
 pub fn build_user_interface<'a, A: Application>(
@@ -551,7 +855,7 @@ where
 pub fn update<A: Application, E: Executor>(
     application: &mut A,
     cache: &mut user_interface::Cache,
-    state: &State<A>,
+    state: &mut State<<A::Renderer as crate::Renderer>::Theme>,
     renderer: &mut A::Renderer,
     runtime: &mut Runtime<E, Proxy<A::Message>, A::Message>,
     clipboard: &mut Clipboard,
@@ -559,6 +863,7 @@ pub fn update<A: Application, E: Executor>(
     debug: &mut Debug,
     messages: &mut Vec<A::Message>,
     window: &winit::window::Window,
+    screenshots: &mut screenshot::Queue<A::Message>,
     graphics_info: impl FnOnce() -> compositor::Information + Copy,
 ) where
     <A::Renderer as crate::Renderer>::Theme: StyleSheet,
@@ -581,6 +886,7 @@ pub fn update<A: Application, E: Executor>(
             proxy,
             debug,
             window,
+            screenshots,
             graphics_info,
         );
     }
@@ -593,7 +899,7 @@ pub fn update<A: Application, E: Executor>(
 pub fn run_command<A, E>(
     application: &A,
     cache: &mut user_interface::Cache,
-    state: &State<A>,
+    state: &mut State<<A::Renderer as crate::Renderer>::Theme>,
     renderer: &mut A::Renderer,
     command: Command<A::Message>,
     runtime: &mut Runtime<E, Proxy<A::Message>, A::Message>,
@@ -601,14 +907,14 @@ pub fn run_command<A, E>(
     proxy: &mut winit::event_loop::EventLoopProxy<A::Message>,
     debug: &mut Debug,
     window: &winit::window::Window,
-    _graphics_info: impl FnOnce() -> compositor::Information + Copy,
+    screenshots: &mut screenshot::Queue<A::Message>,
+    graphics_info: impl FnOnce() -> compositor::Information + Copy,
 ) where
     A: Application,
     E: Executor,
     <A::Renderer as crate::Renderer>::Theme: StyleSheet,
 {
     use iced_native::command;
-    use iced_native::system;
     use iced_native::window;
 
     for action in command.actions() {
@@ -616,85 +922,40 @@ pub fn run_command<A, E>(
             command::Action::Future(future) => {
                 runtime.spawn(future);
             }
-            command::Action::Clipboard(action) => match action {
-                clipboard::Action::Read(tag) => {
-                    let message = tag(clipboard.read());
-
+            command::Action::Clipboard(action) => {
+                if let Some(message) = clipboard.perform(action) {
                     proxy
                         .send_event(message)
                         .expect("Send message to event loop");
                 }
-                clipboard::Action::Write(contents) => {
-                    clipboard.write(contents);
-                }
-            },
-            command::Action::Window(action) => match action {
-                window::Action::Drag => {
-                    let _res = window.drag_window();
-                }
-                window::Action::Resize { width, height } => {
-                    window.set_inner_size(winit::dpi::LogicalSize {
-                        width,
-                        height,
-                    });
-                }
-                window::Action::Maximize(value) => {
-                    window.set_maximized(value);
-                }
-                window::Action::Minimize(value) => {
-                    window.set_minimized(value);
-                }
-                window::Action::Move { x, y } => {
-                    window.set_outer_position(winit::dpi::LogicalPosition {
-                        x,
-                        y,
-                    });
-                }
-                window::Action::SetMode(mode) => {
-                    window.set_visible(conversion::visible(mode));
-                    window.set_fullscreen(conversion::fullscreen(
-                        window.primary_monitor(),
-                        mode,
-                    ));
-                }
-                window::Action::ToggleMaximize => {
-                    window.set_maximized(!window.is_maximized())
+            }
+            command::Action::Window(_id, action) => match action {
+                window::Action::Spawn(_) | window::Action::Close => {
+                    log::warn!(
+                        "Spawning and closing windows is only supported by \
+                        multi-window applications"
+                    );
                 }
-                window::Action::FetchMode(tag) => {
-                    let mode = if window.is_visible().unwrap_or(true) {
-                        conversion::mode(window.fullscreen())
-                    } else {
-                        window::Mode::Hidden
-                    };
-
+                window::Action::FetchFocused(tag) => {
                     proxy
-                        .send_event(tag(mode))
+                        .send_event(tag(state.is_focused()))
                         .expect("Send message to event loop");
                 }
-            },
-            command::Action::System(action) => match action {
-                system::Action::QueryInformation(_tag) => {
-                    #[cfg(feature = "system")]
-                    {
-                        let graphics_info = _graphics_info();
-                        let proxy = proxy.clone();
-
-                        let _ = std::thread::spawn(move || {
-                            let information =
-                                crate::system::information(graphics_info);
-
-                            let message = _tag(information);
-
-                            proxy
-                                .send_event(message)
-                                .expect("Send message to event loop")
-                        });
-                    }
+                window::Action::Screenshot(region, tag) => {
+                    screenshots.push(region, tag);
+                    window.request_redraw();
                 }
+                window::Action::SetCursorIcon(cursor_icon) => {
+                    state.set_cursor_icon(cursor_icon);
+                    window.request_redraw();
+                }
+                action => run_window_action(action, window, proxy),
             },
+            command::Action::System(action) => {
+                run_system_action(action, proxy, graphics_info);
+            }
             command::Action::Widget(action) => {
                 let mut current_cache = std::mem::take(cache);
-                let mut current_operation = Some(action.into_operation());
 
                 let mut user_interface = build_user_interface(
                     application,
@@ -704,21 +965,9 @@ pub fn run_command<A, E>(
                     debug,
                 );
 
-                while let Some(mut operation) = current_operation.take() {
-                    user_interface.operate(renderer, operation.as_mut());
-
-                    match operation.finish() {
-                        operation::Outcome::None => {}
-                        operation::Outcome::Some(message) => {
-                            proxy
-                                .send_event(message)
-                                .expect("Send message to event loop");
-                        }
-                        operation::Outcome::Chain(next) => {
-                            current_operation = Some(next);
-                        }
-                    }
-                }
+                run_operation(action.into_operation(), proxy, |operation| {
+                    user_interface.operate(renderer, operation);
+                });
 
                 current_cache = user_interface.into_cache();
                 *cache = current_cache;
@@ -727,8 +976,210 @@ pub fn run_command<A, E>(
     }
 }
 
+/// Runs a system [`Action`], sending its output to the event loop.
+///
+/// [`Action`]: iced_native::system::Action
+pub fn run_system_action<Message>(
+    action: iced_native::system::Action<Message>,
+    _proxy: &mut winit::event_loop::EventLoopProxy<Message>,
+    _graphics_info: impl FnOnce() -> compositor::Information,
+) where
+    Message: Send + 'static,
+{
+    use iced_native::system;
+
+    match action {
+        system::Action::QueryInformation(_tag) => {
+            #[cfg(feature = "system")]
+            {
+                let graphics_info = _graphics_info();
+                let proxy = _proxy.clone();
+
+                let _ = std::thread::spawn(move || {
+                    let information = crate::system::information(graphics_info);
+
+                    let message = _tag(information);
+
+                    proxy
+                        .send_event(message)
+                        .expect("Send message to event loop")
+                });
+            }
+        }
+    }
+}
+
+/// Runs a widget [`Operation`] and the operations chained to it, sending
+/// their output, if any, to the event loop.
+///
+/// The `operate` closure applies each operation to the user interfaces of
+/// the application.
+///
+/// [`Operation`]: operation::Operation
+pub fn run_operation<Message>(
+    operation: Box<dyn operation::Operation<Message>>,
+    proxy: &mut winit::event_loop::EventLoopProxy<Message>,
+    mut operate: impl FnMut(&mut dyn operation::Operation<Message>),
+) where
+    Message: 'static,
+{
+    let mut current_operation = Some(operation);
+
+    while let Some(mut operation) = current_operation.take() {
+        operate(operation.as_mut());
+
+        match operation.finish() {
+            operation::Outcome::None => {}
+            operation::Outcome::Some(message) => {
+                proxy
+                    .send_event(message)
+                    .expect("Send message to event loop");
+            }
+            operation::Outcome::Chain(next) => {
+                current_operation = Some(next);
+            }
+        }
+    }
+}
+
+/// Runs a window [`Action`] on the given window.
+///
+/// Spawning and closing windows is left to the caller, since it depends on
+/// how the windows of the application are managed. Fetching the focus state
+/// and overriding the cursor icon are left to the caller as well, since they
+/// are tracked by its [`State`], and so are screenshots, since they need
+/// access to the compositor.
+///
+/// [`Action`]: iced_native::window::Action
+pub fn run_window_action<Message>(
+    action: iced_native::window::Action<Message>,
+    window: &winit::window::Window,
+    proxy: &mut winit::event_loop::EventLoopProxy<Message>,
+) where
+    Message: 'static,
+{
+    use iced_native::window;
+
+    match action {
+        window::Action::Spawn(_)
+        | window::Action::Close
+        | window::Action::FetchFocused(_)
+        | window::Action::SetCursorIcon(_)
+        | window::Action::Screenshot(..) => {}
+        window::Action::Drag => {
+            let _res = window.drag_window();
+        }
+        window::Action::Resize { width, height } => {
+            window.set_inner_size(winit::dpi::LogicalSize { width, height });
+        }
+        window::Action::Maximize(value) => {
+            window.set_maximized(value);
+        }
+        window::Action::Minimize(value) => {
+            window.set_minimized(value);
+        }
+        window::Action::Move { x, y } => {
+            window.set_outer_position(winit::dpi::LogicalPosition { x, y });
+        }
+        window::Action::SetMode(mode) => {
+            window.set_visible(conversion::visible(mode));
+            window.set_fullscreen(conversion::fullscreen(
+                window.primary_monitor(),
+                mode,
+            ));
+        }
+        window::Action::ToggleMaximize => {
+            window.set_maximized(!window.is_maximized())
+        }
+        window::Action::FetchMode(tag) => {
+            let mode = if window.is_visible().unwrap_or(true) {
+                conversion::mode(window.fullscreen())
+            } else {
+                window::Mode::Hidden
+            };
+
+            proxy
+                .send_event(tag(mode))
+                .expect("Send message to event loop");
+        }
+        window::Action::SetTitle(title) => {
+            window.set_title(&title);
+        }
+        window::Action::SetIcon(icon) => {
+            window.set_window_icon(conversion::icon(icon));
+        }
+        window::Action::SetDecorations(value) => {
+            window.set_decorations(value);
+        }
+        window::Action::SetResizable(value) => {
+            window.set_resizable(value);
+        }
+        window::Action::SetAlwaysOnTop(value) => {
+            window.set_always_on_top(value);
+        }
+        window::Action::RequestUserAttention(attention) => {
+            window.request_user_attention(
+                attention.map(conversion::user_attention),
+            );
+        }
+        window::Action::SetCursorVisible(value) => {
+            window.set_cursor_visible(value);
+        }
+        window::Action::SetCursorGrab(grab) => {
+            if let Err(error) =
+                window.set_cursor_grab(conversion::cursor_grab(grab))
+            {
+                log::warn!("Failed to grab the cursor: {}", error);
+            }
+        }
+        window::Action::FetchInnerSize(tag) => {
+            let size =
+                window.inner_size().to_logical::<u32>(window.scale_factor());
+
+            proxy
+                .send_event(tag(Size::new(size.width, size.height)))
+                .expect("Send message to event loop");
+        }
+        window::Action::FetchOuterSize(tag) => {
+            let size =
+                window.outer_size().to_logical::<u32>(window.scale_factor());
+
+            proxy
+                .send_event(tag(Size::new(size.width, size.height)))
+                .expect("Send message to event loop");
+        }
+        window::Action::FetchPosition(tag) => {
+            let position = window.outer_position().ok().map(|position| {
+                let position =
+                    position.to_logical::<i32>(window.scale_factor());
+
+                (position.x, position.y)
+            });
+
+            proxy
+                .send_event(tag(position))
+                .expect("Send message to event loop");
+        }
+        window::Action::FetchScaleFactor(tag) => {
+            proxy
+                .send_event(tag(window.scale_factor()))
+                .expect("Send message to event loop");
+        }
+        window::Action::FetchMonitors(tag) => {
+            let monitors = window
+                .available_monitors()
+                .map(conversion::monitor)
+                .collect();
+
+            proxy
+                .send_event(tag(monitors))
+                .expect("Send message to event loop");
+        }
+    }
+}
+
 #[cfg(not(target_arch = "wasm32"))]
-mod platform {
+pub(crate) mod platform {
     pub fn run<T, F>(
         mut event_loop: winit::event_loop::EventLoop<T>,
         event_handler: F,
@@ -750,7 +1201,7 @@ mod platform {
 }
 
 #[cfg(target_arch = "wasm32")]
-mod platform {
+pub(crate) mod platform {
     pub fn run<T, F>(
         event_loop: winit::event_loop::EventLoop<T>,
         event_handler: F,
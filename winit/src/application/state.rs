@@ -1,16 +1,21 @@
-use crate::application::{self, StyleSheet as _};
+use crate::application::{self, StyleSheet};
 use crate::conversion;
-use crate::{Application, Color, Debug, Point, Size, Viewport};
+use crate::mouse;
+use crate::{Color, Debug, Point, Size, Viewport};
 
-use std::marker::PhantomData;
 use winit::event::{Touch, WindowEvent};
 use winit::window::Window;
 
-/// The state of a windowed [`Application`].
+/// The state of a window of an [`Application`].
+///
+/// It is shared by single-window and multi-window applications, which pass
+/// in the title, the scale factor, and the theme of every window.
+///
+/// [`Application`]: crate::Application
 #[allow(missing_debug_implementations)]
-pub struct State<A: Application>
+pub struct State<Theme>
 where
-    <A::Renderer as crate::Renderer>::Theme: application::StyleSheet,
+    Theme: StyleSheet,
 {
     title: String,
     scale_factor: f64,
@@ -18,21 +23,26 @@ where
     viewport_version: usize,
     cursor_position: winit::dpi::PhysicalPosition<f64>,
     modifiers: winit::event::ModifiersState,
-    theme: <A::Renderer as crate::Renderer>::Theme,
+    focused: bool,
+    cursor_icon: Option<mouse::Interaction>,
+    theme: Theme,
     appearance: application::Appearance,
-    application: PhantomData<A>,
 }
 
-impl<A: Application> State<A>
+impl<Theme> State<Theme>
 where
-    <A::Renderer as crate::Renderer>::Theme: application::StyleSheet,
+    Theme: StyleSheet,
 {
-    /// Creates a new [`State`] for the provided [`Application`] and window.
-    pub fn new(application: &A, window: &Window) -> Self {
-        let title = application.title();
-        let scale_factor = application.scale_factor();
-        let theme = application.theme();
-        let appearance = theme.appearance(&application.style());
+    /// Creates a new [`State`] for the given window, with the provided title,
+    /// scale factor, theme, and style.
+    pub fn new(
+        window: &Window,
+        title: String,
+        scale_factor: f64,
+        theme: Theme,
+        style: &Theme::Style,
+    ) -> Self {
+        let appearance = theme.appearance(style);
 
         let viewport = {
             let physical_size = window.inner_size();
@@ -51,9 +61,10 @@ where
             // TODO: Encode cursor availability in the type-system
             cursor_position: winit::dpi::PhysicalPosition::new(-1.0, -1.0),
             modifiers: winit::event::ModifiersState::default(),
+            focused: false,
+            cursor_icon: None,
             theme,
             appearance,
-            application: PhantomData,
         }
     }
 
@@ -97,8 +108,25 @@ where
         self.modifiers
     }
 
+    /// Returns whether the window of the [`State`] is currently focused.
+    pub fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    /// Returns the cursor icon overriding the one of the user interface, if
+    /// any.
+    pub fn cursor_icon(&self) -> Option<mouse::Interaction> {
+        self.cursor_icon
+    }
+
+    /// Overrides the cursor icon of the user interface until it is cleared
+    /// with `None`.
+    pub fn set_cursor_icon(&mut self, cursor_icon: Option<mouse::Interaction>) {
+        self.cursor_icon = cursor_icon;
+    }
+
     /// Returns the current theme of the [`State`].
-    pub fn theme(&self) -> &<A::Renderer as crate::Renderer>::Theme {
+    pub fn theme(&self) -> &Theme {
         &self.theme
     }
 
@@ -132,7 +160,7 @@ where
                 self.viewport_version = self.viewport_version.wrapping_add(1);
             }
             WindowEvent::ScaleFactorChanged {
-                scale_factor: new_scale_factor,
+                scale_factor: scale_factor,
                 new_inner_size,
             } => {
                 let size =
@@ -140,7 +168,7 @@ where
 
                 self.viewport = Viewport::with_physical_size(
                     size,
-                    new_scale_factor * self.scale_factor,
+                    scale_factor * self.scale_factor,
                 );
 
                 self.viewport_version = self.viewport_version.wrapping_add(1);
@@ -159,6 +187,9 @@ where
             WindowEvent::ModifiersChanged(new_modifiers) => {
                 self.modifiers = *new_modifiers;
             }
+            WindowEvent::Focused(focused) => {
+                self.focused = *focused;
+            }
             #[cfg(feature = "debug")]
             WindowEvent::KeyboardInput {
                 input:
@@ -168,48 +199,59 @@ where
                         ..
                     },
                 ..
-            } => _debug.toggle(),
+            } => {
+                if self.modifiers.shift() {
+                    _debug.toggle_inspector();
+                } else {
+                    _debug.toggle();
+                }
+            }
             _ => {}
         }
     }
 
-    /// Synchronizes the [`State`] with its [`Application`] and its respective
-    /// window.
+    /// Synchronizes the [`State`] with the given window and the provided
+    /// title, scale factor, theme, and style.
     ///
-    /// Normally an [`Application`] should be synchronized with its [`State`]
+    /// Normally a [`State`] should be synchronized with its [`Application`]
     /// and window after calling [`Application::update`].
     ///
+    /// [`Application`]: crate::Application
     /// [`Application::update`]: crate::Program::update
-    pub fn synchronize(&mut self, application: &A, window: &Window) {
+    pub fn synchronize(
+        &mut self,
+        window: &Window,
+        title: String,
+        scale_factor: f64,
+        theme: Theme,
+        style: &Theme::Style,
+    ) {
         // Update window title
-        let new_title = application.title();
-
-        if self.title != new_title {
-            window.set_title(&new_title);
+        if self.title != title {
+            window.set_title(&title);
 
-            self.title = new_title;
+            self.title = title;
         }
 
         // Update scale factor and size
-        let new_scale_factor = application.scale_factor();
         let new_size = window.inner_size();
         let current_size = self.viewport.physical_size();
 
-        if self.scale_factor != new_scale_factor
+        if self.scale_factor != scale_factor
             || (current_size.width, current_size.height)
                 != (new_size.width, new_size.height)
         {
             self.viewport = Viewport::with_physical_size(
                 Size::new(new_size.width, new_size.height),
-                window.scale_factor() * new_scale_factor,
+                window.scale_factor() * scale_factor,
             );
             self.viewport_version = self.viewport_version.wrapping_add(1);
 
-            self.scale_factor = new_scale_factor;
+            self.scale_factor = scale_factor;
         }
 
         // Update theme and appearance
-        self.theme = application.theme();
-        self.appearance = self.theme.appearance(&application.style());
+        self.appearance = theme.appearance(style);
+        self.theme = theme;
     }
 }
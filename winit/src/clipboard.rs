@@ -1,8 +1,40 @@
 //! Access the clipboard.
-pub use iced_native::clipboard::Action;
+pub use iced_native::clipboard::{Action, Content, Format};
 
 use crate::command::{self, Command};
 
+#[cfg(not(target_arch = "wasm32"))]
+mod rich;
+
+#[cfg(target_arch = "wasm32")]
+mod rich {
+    use iced_native::clipboard::{Content, Format};
+
+    pub enum Clipboard {}
+
+    impl Clipboard {
+        pub fn connect() -> Option<Self> {
+            None
+        }
+
+        pub fn read(&self, _format: Format) -> Option<Content> {
+            match *self {}
+        }
+
+        pub fn write(&mut self, _content: Content) {
+            match *self {}
+        }
+
+        pub fn read_primary(&self) -> Option<String> {
+            match *self {}
+        }
+
+        pub fn write_primary(&mut self, _contents: String) {
+            match *self {}
+        }
+    }
+}
+
 /// A buffer for short-term storage and transfer within and between
 /// applications.
 #[allow(missing_debug_implementations)]
@@ -11,7 +43,10 @@ pub struct Clipboard {
 }
 
 enum State {
-    Connected(window_clipboard::Clipboard),
+    Connected {
+        raw: window_clipboard::Clipboard,
+        rich: Option<rich::Clipboard>,
+    },
     Unavailable,
 }
 
@@ -20,7 +55,10 @@ impl Clipboard {
     pub fn connect(window: &winit::window::Window) -> Clipboard {
         let state = window_clipboard::Clipboard::connect(window)
             .ok()
-            .map(State::Connected)
+            .map(|raw| State::Connected {
+                raw,
+                rich: rich::Clipboard::connect(),
+            })
             .unwrap_or(State::Unavailable);
 
         Clipboard { state }
@@ -37,7 +75,7 @@ impl Clipboard {
     /// Reads the current content of the [`Clipboard`] as text.
     pub fn read(&self) -> Option<String> {
         match &self.state {
-            State::Connected(clipboard) => clipboard.read().ok(),
+            State::Connected { raw, .. } => raw.read().ok(),
             State::Unavailable => None,
         }
     }
@@ -45,7 +83,7 @@ impl Clipboard {
     /// Writes the given text contents to the [`Clipboard`].
     pub fn write(&mut self, contents: String) {
         match &mut self.state {
-            State::Connected(clipboard) => match clipboard.write(contents) {
+            State::Connected { raw, .. } => match raw.write(contents) {
                 Ok(()) => {}
                 Err(error) => {
                     log::warn!("error writing to clipboard: {}", error)
@@ -54,6 +92,94 @@ impl Clipboard {
             State::Unavailable => {}
         }
     }
+
+    /// Reads the current content of the primary selection as text.
+    ///
+    /// The primary selection is only available on X11 and Wayland.
+    pub fn read_primary(&self) -> Option<String> {
+        match &self.state {
+            State::Connected {
+                rich: Some(rich), ..
+            } => rich.read_primary(),
+            _ => None,
+        }
+    }
+
+    /// Writes the given text contents to the primary selection.
+    ///
+    /// The primary selection is only available on X11 and Wayland.
+    pub fn write_primary(&mut self, contents: String) {
+        if let State::Connected {
+            rich: Some(rich), ..
+        } = &mut self.state
+        {
+            rich.write_primary(contents);
+        }
+    }
+
+    /// Reads the current content of the [`Clipboard`] in the given
+    /// [`Format`].
+    pub fn read_content(&self, format: Format) -> Option<Content> {
+        if format == Format::Text {
+            return self.read().map(Content::Text);
+        }
+
+        match &self.state {
+            State::Connected {
+                rich: Some(rich), ..
+            } => rich.read(format),
+            _ => None,
+        }
+    }
+
+    /// Writes the given [`Content`] to the [`Clipboard`].
+    pub fn write_content(&mut self, content: Content) {
+        if let Content::Text(contents) = content {
+            self.write(contents);
+
+            return;
+        }
+
+        match &mut self.state {
+            State::Connected {
+                rich: Some(rich), ..
+            } => rich.write(content),
+            State::Connected { rich: None, .. } => {
+                log::warn!(
+                    "writing {:?} content to the clipboard is not supported",
+                    content.format()
+                );
+            }
+            State::Unavailable => {}
+        }
+    }
+
+    /// Performs the given clipboard [`Action`], producing a message if the
+    /// [`Action`] reads from the [`Clipboard`].
+    pub fn perform<T>(&mut self, action: Action<T>) -> Option<T> {
+        match action {
+            Action::Read(tag) => Some(tag(self.read())),
+            Action::Write(contents) => {
+                self.write(contents);
+
+                None
+            }
+            Action::ReadPrimary(tag) => Some(tag(self.read_primary())),
+            Action::WritePrimary(contents) => {
+                self.write_primary(contents);
+
+                None
+            }
+            Action::ReadContent(format, tag) => {
+                Some(tag(self.read_content(format)))
+            }
+            Action::WriteContent(content) => {
+                self.write_content(content);
+
+                None
+            }
+        }
+    }
 }
 
 impl iced_native::Clipboard for Clipboard {
@@ -64,6 +190,22 @@ impl iced_native::Clipboard for Clipboard {
     fn write(&mut self, contents: String) {
         self.write(contents)
     }
+
+    fn read_primary(&self) -> Option<String> {
+        self.read_primary()
+    }
+
+    fn write_primary(&mut self, contents: String) {
+        self.write_primary(contents)
+    }
+
+    fn read_content(&self, format: Format) -> Option<Content> {
+        self.read_content(format)
+    }
+
+    fn write_content(&mut self, content: Content) {
+        self.write_content(content)
+    }
 }
 
 /// Read the current contents of the clipboard.
@@ -77,3 +219,37 @@ pub fn read<Message>(
 pub fn write<Message>(contents: String) -> Command<Message> {
     Command::single(command::Action::Clipboard(Action::Write(contents)))
 }
+
+/// Read the current contents of the primary selection.
+///
+/// The primary selection is only available on X11 and Wayland.
+pub fn read_primary<Message>(
+    f: impl Fn(Option<String>) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Clipboard(Action::ReadPrimary(Box::new(
+        f,
+    ))))
+}
+
+/// Write the given contents to the primary selection.
+///
+/// The primary selection is only available on X11 and Wayland.
+pub fn write_primary<Message>(contents: String) -> Command<Message> {
+    Command::single(command::Action::Clipboard(Action::WritePrimary(contents)))
+}
+
+/// Read the current contents of the clipboard in the given [`Format`].
+pub fn read_content<Message>(
+    format: Format,
+    f: impl Fn(Option<Content>) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Clipboard(Action::ReadContent(
+        format,
+        Box::new(f),
+    )))
+}
+
+/// Write the given [`Content`] to the clipboard.
+pub fn write_content<Message>(content: Content) -> Command<Message> {
+    Command::single(command::Action::Clipboard(Action::WriteContent(content)))
+}
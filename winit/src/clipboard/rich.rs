@@ -0,0 +1,148 @@
+//! Access rich clipboard content and the primary selection.
+use iced_native::clipboard::{Content, Format};
+
+use std::borrow::Cow;
+use std::cell::RefCell;
+
+/// A connection to the system clipboard supporting rich content.
+pub struct Clipboard {
+    raw: RefCell<arboard::Clipboard>,
+}
+
+impl Clipboard {
+    /// Connects to the system clipboard, if available.
+    pub fn connect() -> Option<Self> {
+        arboard::Clipboard::new()
+            .map_err(|error| {
+                log::warn!("rich clipboard content is unavailable: {}", error)
+            })
+            .ok()
+            .map(|raw| Self {
+                raw: RefCell::new(raw),
+            })
+    }
+
+    /// Reads the content of the clipboard in the given [`Format`].
+    pub fn read(&self, format: Format) -> Option<Content> {
+        let mut raw = self.raw.borrow_mut();
+
+        match format {
+            Format::Text => raw.get_text().ok().map(Content::Text),
+            Format::Html => {
+                let html = raw.get().html().ok()?;
+                let alt_text = raw.get_text().ok();
+
+                Some(Content::Html { html, alt_text })
+            }
+            Format::Image => {
+                let image = raw.get_image().ok()?;
+
+                Some(Content::Image {
+                    width: image.width as u32,
+                    height: image.height as u32,
+                    pixels: image.bytes.into_owned(),
+                })
+            }
+            Format::Files => raw.get().file_list().ok().map(Content::Files),
+        }
+    }
+
+    /// Writes the given [`Content`] to the clipboard.
+    pub fn write(&mut self, content: Content) {
+        let raw = self.raw.get_mut();
+
+        let result = match content {
+            Content::Text(contents) => raw.set_text(contents),
+            Content::Html { html, alt_text } => raw.set_html(html, alt_text),
+            Content::Image {
+                width,
+                height,
+                pixels,
+            } => raw.set_image(arboard::ImageData {
+                width: width as usize,
+                height: height as usize,
+                bytes: Cow::Owned(pixels),
+            }),
+            Content::Files(_) => {
+                log::warn!("writing files to the clipboard is not supported");
+
+                Ok(())
+            }
+        };
+
+        if let Err(error) = result {
+            log::warn!("error writing to clipboard: {}", error);
+        }
+    }
+
+    /// Reads the text contents of the primary selection.
+    #[cfg(all(
+        unix,
+        not(any(
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "android",
+            target_os = "emscripten"
+        ))
+    ))]
+    pub fn read_primary(&self) -> Option<String> {
+        use arboard::{GetExtLinux, LinuxClipboardKind};
+
+        self.raw
+            .borrow_mut()
+            .get()
+            .clipboard(LinuxClipboardKind::Primary)
+            .text()
+            .ok()
+    }
+
+    /// Reads the text contents of the primary selection.
+    #[cfg(not(all(
+        unix,
+        not(any(
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "android",
+            target_os = "emscripten"
+        ))
+    )))]
+    pub fn read_primary(&self) -> Option<String> {
+        None
+    }
+
+    /// Writes the given text contents to the primary selection.
+    #[cfg(all(
+        unix,
+        not(any(
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "android",
+            target_os = "emscripten"
+        ))
+    ))]
+    pub fn write_primary(&mut self, contents: String) {
+        use arboard::{LinuxClipboardKind, SetExtLinux};
+
+        if let Err(error) = self
+            .raw
+            .get_mut()
+            .set()
+            .clipboard(LinuxClipboardKind::Primary)
+            .text(contents)
+        {
+            log::warn!("error writing to primary selection: {}", error);
+        }
+    }
+
+    /// Writes the given text contents to the primary selection.
+    #[cfg(not(all(
+        unix,
+        not(any(
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "android",
+            target_os = "emscripten"
+        ))
+    )))]
+    pub fn write_primary(&mut self, _contents: String) {}
+}
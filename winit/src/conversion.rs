@@ -6,10 +6,12 @@ use crate::keyboard;
 use crate::mouse;
 use crate::touch;
 use crate::window;
-use crate::{Event, Point, Position};
+use crate::{Event, Point, Position, Size};
 
-/// Converts a winit window event into an iced event.
+/// Converts a winit window event of the window with the given [`window::Id`]
+/// into an iced event.
 pub fn window_event(
+    id: window::Id,
     event: &winit::event::WindowEvent<'_>,
     scale_factor: f64,
     modifiers: winit::event::ModifiersState,
@@ -20,21 +22,27 @@ pub fn window_event(
         WindowEvent::Resized(new_size) => {
             let logical_size = new_size.to_logical(scale_factor);
 
-            Some(Event::Window(window::Event::Resized {
-                width: logical_size.width,
-                height: logical_size.height,
-            }))
+            Some(Event::Window(
+                id,
+                window::Event::Resized {
+                    width: logical_size.width,
+                    height: logical_size.height,
+                },
+            ))
         }
         WindowEvent::ScaleFactorChanged { new_inner_size, .. } => {
             let logical_size = new_inner_size.to_logical(scale_factor);
 
-            Some(Event::Window(window::Event::Resized {
-                width: logical_size.width,
-                height: logical_size.height,
-            }))
+            Some(Event::Window(
+                id,
+                window::Event::Resized {
+                    width: logical_size.width,
+                    height: logical_size.height,
+                },
+            ))
         }
         WindowEvent::CloseRequested => {
-            Some(Event::Window(window::Event::CloseRequested))
+            Some(Event::Window(id, window::Event::CloseRequested))
         }
         WindowEvent::CursorMoved { position, .. } => {
             let position = position.to_logical::<f64>(scale_factor);
@@ -112,19 +120,22 @@ pub fn window_event(
         WindowEvent::ModifiersChanged(new_modifiers) => Some(Event::Keyboard(
             keyboard::Event::ModifiersChanged(self::modifiers(*new_modifiers)),
         )),
-        WindowEvent::Focused(focused) => Some(Event::Window(if *focused {
-            window::Event::Focused
-        } else {
-            window::Event::Unfocused
-        })),
+        WindowEvent::Focused(focused) => Some(Event::Window(
+            id,
+            if *focused {
+                window::Event::Focused
+            } else {
+                window::Event::Unfocused
+            },
+        )),
         WindowEvent::HoveredFile(path) => {
-            Some(Event::Window(window::Event::FileHovered(path.clone())))
+            Some(Event::Window(id, window::Event::FileHovered(path.clone())))
         }
         WindowEvent::DroppedFile(path) => {
-            Some(Event::Window(window::Event::FileDropped(path.clone())))
+            Some(Event::Window(id, window::Event::FileDropped(path.clone())))
         }
         WindowEvent::HoveredFileCancelled => {
-            Some(Event::Window(window::Event::FilesHoveredLeft))
+            Some(Event::Window(id, window::Event::FilesHoveredLeft))
         }
         WindowEvent::Touch(touch) => {
             Some(Event::Touch(touch_event(*touch, scale_factor)))
@@ -133,7 +144,7 @@ pub fn window_event(
             let winit::dpi::LogicalPosition { x, y } =
                 position.to_logical(scale_factor);
 
-            Some(Event::Window(window::Event::Moved { x, y }))
+            Some(Event::Window(id, window::Event::Moved { x, y }))
         }
         _ => None,
     }
@@ -215,6 +226,61 @@ pub fn mode(mode: Option<winit::window::Fullscreen>) -> window::Mode {
     }
 }
 
+/// Converts a [`window::Icon`] to a [`winit`] icon.
+///
+/// Returns `None` if the underlying OS fails to create the icon.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+pub fn icon(icon: window::Icon) -> Option<winit::window::Icon> {
+    let (rgba, size) = icon.into_raw();
+
+    winit::window::Icon::from_rgba(rgba, size.width, size.height).ok()
+}
+
+/// Converts a [`window::UserAttention`] to a [`winit`] user attention type.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+pub fn user_attention(
+    user_attention: window::UserAttention,
+) -> winit::window::UserAttentionType {
+    match user_attention {
+        window::UserAttention::Critical => {
+            winit::window::UserAttentionType::Critical
+        }
+        window::UserAttention::Informational => {
+            winit::window::UserAttentionType::Informational
+        }
+    }
+}
+
+/// Converts a [`window::CursorGrab`] to a [`winit`] cursor grab mode.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+pub fn cursor_grab(
+    cursor_grab: window::CursorGrab,
+) -> winit::window::CursorGrabMode {
+    match cursor_grab {
+        window::CursorGrab::None => winit::window::CursorGrabMode::None,
+        window::CursorGrab::Confined => winit::window::CursorGrabMode::Confined,
+        window::CursorGrab::Locked => winit::window::CursorGrabMode::Locked,
+    }
+}
+
+/// Converts a [`winit`] monitor handle to a [`window::Monitor`].
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+pub fn monitor(monitor: winit::monitor::MonitorHandle) -> window::Monitor {
+    let size = monitor.size();
+    let position = monitor.position();
+
+    window::Monitor {
+        name: monitor.name(),
+        size: Size::new(size.width, size.height),
+        position: (position.x, position.y),
+        scale_factor: monitor.scale_factor(),
+    }
+}
+
 /// Converts a `MouseCursor` from [`iced_native`] to a [`winit`] cursor icon.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
@@ -14,6 +14,10 @@ pub enum Error {
     /// The application graphics context could not be created.
     #[error("the application graphics context could not be created")]
     GraphicsCreationFailed(iced_graphics::Error),
+
+    /// The event session could not be opened for recording or replaying.
+    #[error("the event session could not be opened")]
+    SessionOpenFailed(crate::session::Error),
 }
 
 impl From<iced_graphics::Error> for Error {
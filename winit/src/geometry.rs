@@ -0,0 +1,427 @@
+//! Persist and restore the geometry of a window across sessions.
+use crate::settings;
+use crate::Position;
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The geometry of a window, in logical coordinates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Geometry {
+    /// The position of the top-left corner of the window, including its
+    /// decorations, if known.
+    pub position: Option<(i32, i32)>,
+
+    /// The size of the client area of the window.
+    pub size: (u32, u32),
+
+    /// Whether the window is maximized.
+    pub maximized: bool,
+}
+
+impl Geometry {
+    /// Captures the current [`Geometry`] of the given window.
+    pub fn of(window: &winit::window::Window) -> Self {
+        let scale_factor = window.scale_factor();
+
+        let position = window.outer_position().ok().map(|position| {
+            let position = position.to_logical::<i32>(scale_factor);
+
+            (position.x, position.y)
+        });
+
+        let size = window.inner_size().to_logical::<u32>(scale_factor);
+
+        Self {
+            position,
+            size: (size.width, size.height),
+            maximized: window.is_maximized(),
+        }
+    }
+
+    /// Updates the [`Geometry`] after the given window has been moved or
+    /// resized.
+    ///
+    /// The size and position of a maximized window are ignored, so the
+    /// window can be restored to its previous geometry.
+    pub fn track(
+        &mut self,
+        window: &winit::window::Window,
+        event: &winit::event::WindowEvent<'_>,
+    ) {
+        use winit::event::WindowEvent;
+
+        if !matches!(event, WindowEvent::Moved(_) | WindowEvent::Resized(_)) {
+            return;
+        }
+
+        if window.is_maximized() {
+            self.maximized = true;
+        } else {
+            *self = Self::of(window);
+        }
+    }
+
+    /// Loads the [`Geometry`] stored for the application with the given
+    /// identifier, if any.
+    pub fn load(id: &str) -> Option<Self> {
+        Self::read(&path(id)?)
+    }
+
+    /// Stores the [`Geometry`] for the application with the given identifier
+    /// in the configuration directory of the user.
+    pub fn save(&self, id: &str) -> io::Result<()> {
+        let path = path(id).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                "the configuration directory of the user could not be found",
+            )
+        })?;
+
+        self.write(&path)
+    }
+
+    fn read(path: &Path) -> Option<Self> {
+        Self::parse(&fs::read_to_string(path).ok()?)
+    }
+
+    fn write(&self, path: &Path) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(path, self.serialize())
+    }
+
+    /// Parses a [`Geometry`] from the contents of a file produced by
+    /// [`Geometry::serialize`], returning `None` if they are corrupt.
+    fn parse(contents: &str) -> Option<Self> {
+        let mut position = None;
+        let mut size = None;
+        let mut maximized = false;
+
+        for line in contents.lines() {
+            let (key, value) = match line.split_once('=') {
+                Some((key, value)) => (key.trim(), value.trim()),
+                None => continue,
+            };
+
+            let mut values = value.split_whitespace();
+
+            match key {
+                "position" => {
+                    let x = values.next()?.parse().ok()?;
+                    let y = values.next()?.parse().ok()?;
+
+                    position = Some((x, y));
+                }
+                "size" => {
+                    let width = values.next()?.parse().ok()?;
+                    let height = values.next()?.parse().ok()?;
+
+                    size = Some((width, height));
+                }
+                "maximized" => {
+                    maximized = value.parse().ok()?;
+                }
+                _ => {}
+            }
+        }
+
+        Some(Self {
+            position,
+            size: size?,
+            maximized,
+        })
+    }
+
+    fn serialize(&self) -> String {
+        let mut contents = format!(
+            "size = {} {}\nmaximized = {}\n",
+            self.size.0, self.size.1, self.maximized
+        );
+
+        if let Some((x, y)) = self.position {
+            contents.push_str(&format!("position = {} {}\n", x, y));
+        }
+
+        contents
+    }
+
+    /// Clamps the [`Geometry`] to the given monitors.
+    ///
+    /// If the window would not be visible in any of the monitors, its
+    /// position is dropped so the platform can choose a new one. The size is
+    /// shrunk to fit the monitor the window ends up in.
+    pub fn clamp(
+        self,
+        monitors: impl IntoIterator<Item = winit::monitor::MonitorHandle>,
+    ) -> Self {
+        let bounds: Vec<_> = monitors
+            .into_iter()
+            .map(|monitor| {
+                let scale_factor = monitor.scale_factor();
+                let position =
+                    monitor.position().to_logical::<i32>(scale_factor);
+                let size = monitor.size().to_logical::<i32>(scale_factor);
+
+                (position.x, position.y, size.width, size.height)
+            })
+            .collect();
+
+        self.clamp_to(&bounds)
+    }
+
+    /// Clamps the [`Geometry`] to the given monitor bounds, as
+    /// `(x, y, width, height)` in logical coordinates.
+    fn clamp_to(self, bounds: &[(i32, i32, i32, i32)]) -> Self {
+        let (width, height) = (self.size.0 as i32, self.size.1 as i32);
+
+        let visible = self.position.and_then(|(x, y)| {
+            bounds
+                .iter()
+                .copied()
+                .find(|(mx, my, mw, mh)| {
+                    x < mx + mw
+                        && x + width > *mx
+                        && y < my + mh
+                        && y + height > *my
+                })
+                .map(|monitor| ((x, y), monitor))
+        });
+
+        match visible {
+            Some(((x, y), (mx, my, mw, mh))) => {
+                let width = width.min(mw).max(1);
+                let height = height.min(mh).max(1);
+
+                Self {
+                    position: Some((
+                        x.clamp(mx, mx + (mw - width).max(0)),
+                        y.clamp(my, my + (mh - height).max(0)),
+                    )),
+                    size: (width as u32, height as u32),
+                    maximized: self.maximized,
+                }
+            }
+            None => Self {
+                position: None,
+                size: match bounds.first() {
+                    Some((_, _, mw, mh)) => (
+                        width.min(*mw).max(1) as u32,
+                        height.min(*mh).max(1) as u32,
+                    ),
+                    None => self.size,
+                },
+                maximized: self.maximized,
+            },
+        }
+    }
+
+    /// Applies the [`Geometry`] to the given window settings.
+    ///
+    /// The maximized state cannot be set on creation and needs to be applied
+    /// to the window afterwards.
+    pub fn apply(&self, settings: &mut settings::Window) {
+        settings.size = self.size;
+
+        if let Some((x, y)) = self.position {
+            settings.position = Position::Specific(x, y);
+        }
+    }
+}
+
+/// Returns the path of the file that stores the window geometry of the
+/// application with the given identifier.
+///
+/// Only ASCII alphanumerics and `-` are kept in the identifier, so it cannot
+/// escape the configuration directory. Any other byte is escaped as `_`
+/// followed by its hexadecimal value, so different identifiers never share
+/// the same file.
+fn path(id: &str) -> Option<PathBuf> {
+    Some(config_dir()?.join(directory(id)?).join("window_geometry"))
+}
+
+fn directory(id: &str) -> Option<String> {
+    if id.is_empty() {
+        return None;
+    }
+
+    Some(
+        id.bytes()
+            .map(|byte| match byte {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' => {
+                    char::from(byte).to_string()
+                }
+                _ => format!("_{:02X}", byte),
+            })
+            .collect(),
+    )
+}
+
+#[cfg(target_os = "windows")]
+fn config_dir() -> Option<PathBuf> {
+    std::env::var_os("APPDATA").map(PathBuf::from)
+}
+
+#[cfg(target_os = "macos")]
+fn config_dir() -> Option<PathBuf> {
+    std::env::var_os("HOME")
+        .map(|home| PathBuf::from(home).join("Library/Application Support"))
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn config_dir() -> Option<PathBuf> {
+    std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .filter(|path| path.is_absolute())
+        .or_else(|| {
+            std::env::var_os("HOME")
+                .map(|home| PathBuf::from(home).join(".config"))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MONITOR: (i32, i32, i32, i32) = (0, 0, 1920, 1080);
+
+    fn geometry(position: Option<(i32, i32)>, size: (u32, u32)) -> Geometry {
+        Geometry {
+            position,
+            size,
+            maximized: false,
+        }
+    }
+
+    fn temporary_file(name: &str) -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("iced_winit_geometry_{}", std::process::id()))
+            .join(name)
+    }
+
+    #[test]
+    fn save_and_load_round_trip() {
+        let path = temporary_file("round_trip");
+
+        for geometry in [
+            geometry(Some((-20, 40)), (800, 600)),
+            Geometry {
+                maximized: true,
+                ..geometry(None, (1024, 768))
+            },
+        ] {
+            geometry.write(&path).unwrap();
+
+            assert_eq!(Geometry::read(&path), Some(geometry));
+        }
+
+        let _ = fs::remove_file(path);
+    }
+
+    #[test]
+    fn load_ignores_missing_and_corrupt_files() {
+        let path = temporary_file("corrupt");
+
+        assert_eq!(Geometry::read(&path), None);
+
+        for contents in [
+            "",
+            "maximized = true\n",
+            "size = 800\n",
+            "size = 800 tall\n",
+            "size = -800 600\n",
+            "size = 800 600\nposition = 10\n",
+            "size = 800 600\nmaximized = yes\n",
+        ] {
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(&path, contents).unwrap();
+
+            assert_eq!(Geometry::read(&path), None, "{:?}", contents);
+        }
+
+        let _ = fs::remove_file(path);
+    }
+
+    #[test]
+    fn parse_ignores_unknown_keys() {
+        assert_eq!(
+            Geometry::parse("size = 800 600\ntheme = dark\nposition = 1 2\n"),
+            Some(geometry(Some((1, 2)), (800, 600)))
+        );
+    }
+
+    #[test]
+    fn clamp_keeps_visible_geometry() {
+        let visible = geometry(Some((100, 100)), (800, 600));
+
+        assert_eq!(visible.clamp_to(&[MONITOR]), visible);
+    }
+
+    #[test]
+    fn clamp_moves_partially_visible_geometry_into_its_monitor() {
+        assert_eq!(
+            geometry(Some((1800, -50)), (800, 600)).clamp_to(&[MONITOR]),
+            geometry(Some((1120, 0)), (800, 600))
+        );
+    }
+
+    #[test]
+    fn clamp_drops_the_position_of_off_screen_geometry() {
+        assert_eq!(
+            geometry(Some((5000, 5000)), (800, 600))
+                .clamp_to(&[MONITOR, (1920, 0, 1280, 1024)]),
+            geometry(None, (800, 600))
+        );
+    }
+
+    #[test]
+    fn clamp_shrinks_geometry_bigger_than_its_monitor() {
+        assert_eq!(
+            geometry(Some((0, 0)), (4000, 3000)).clamp_to(&[MONITOR]),
+            geometry(Some((0, 0)), (1920, 1080))
+        );
+        assert_eq!(
+            geometry(None, (4000, 3000)).clamp_to(&[MONITOR]),
+            geometry(None, (1920, 1080))
+        );
+    }
+
+    #[test]
+    fn clamp_drops_the_position_without_monitors() {
+        let geometry = geometry(Some((5000, 5000)), (800, 600));
+
+        assert_eq!(
+            geometry.clamp_to(&[]),
+            Geometry {
+                position: None,
+                ..geometry
+            }
+        );
+    }
+
+    #[test]
+    fn path_stays_in_the_configuration_directory() {
+        assert_eq!(
+            directory("../../etc").as_deref(),
+            Some("_2E_2E_2F_2E_2E_2Fetc")
+        );
+        assert_eq!(directory("my-app2").as_deref(), Some("my-app2"));
+        assert_eq!(directory("café").as_deref(), Some("caf_C3_A9"));
+        assert_eq!(directory(""), None);
+    }
+
+    #[test]
+    fn path_is_unique_for_every_identifier() {
+        assert_eq!(
+            directory("com.example/app").as_deref(),
+            Some("com_2Eexample_2Fapp")
+        );
+        assert_eq!(
+            directory("com_example_app").as_deref(),
+            Some("com_5Fexample_5Fapp")
+        );
+    }
+}
@@ -39,21 +39,26 @@ pub use winit;
 pub mod application;
 pub mod clipboard;
 pub mod conversion;
+pub mod geometry;
+#[cfg(feature = "multi-window")]
+pub mod multi_window;
+pub mod screenshot;
+pub mod session;
 pub mod settings;
+pub mod trace;
 pub mod window;
 
 #[cfg(feature = "system")]
 pub mod system;
 
 mod error;
-mod position;
 mod proxy;
 
 #[cfg(feature = "application")]
 pub use application::Application;
 pub use clipboard::Clipboard;
 pub use error::Error;
-pub use position::Position;
+pub use iced_native::window::Position;
 pub use proxy::Proxy;
 pub use settings::Settings;
 
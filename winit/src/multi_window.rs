@@ -0,0 +1,1017 @@
+//! Create interactive, native cross-platform applications with multiple
+//! windows.
+pub use crate::application::State;
+
+use crate::application::{
+    build_window, draw, is_redraw_request, persisted_geometry_id, platform,
+    present, requests_exit, run_operation, run_system_action,
+    run_window_action, save_geometry, static_event,
+};
+use crate::clipboard::Clipboard;
+use crate::conversion;
+use crate::geometry::Geometry;
+use crate::mouse;
+use crate::renderer;
+use crate::screenshot;
+use crate::session;
+use crate::settings;
+use crate::time::Instant;
+use crate::trace::Trace;
+use crate::{
+    Command, Debug, Element, Error, Executor, Proxy, Runtime, Settings, Size,
+    Subscription,
+};
+
+use iced_futures::futures;
+use iced_futures::futures::channel::mpsc;
+use iced_graphics::compositor;
+use iced_graphics::window::Compositor;
+use iced_native::user_interface::{self, UserInterface};
+use iced_native::window;
+
+pub use iced_native::application::{Appearance, StyleSheet};
+
+use std::collections::{BTreeMap, VecDeque};
+use std::mem::ManuallyDrop;
+
+/// An interactive, native cross-platform application with multiple windows.
+///
+/// Every window of a multi-window [`Application`] is identified by a
+/// [`window::Id`]. The window created on startup uses [`window::Id::MAIN`],
+/// and additional windows can be opened and closed by returning the
+/// [`Command`]s produced by [`window::spawn`] and [`window::close`].
+///
+/// All the windows share the state and the messages of the [`Application`].
+///
+/// When using an [`Application`] with the `debug` feature enabled, a debug view
+/// can be toggled by pressing `F12`. Pressing `Shift+F12` toggles a widget
+/// inspector that highlights the widget under the cursor and shows the
+/// widget tree.
+///
+/// [`window::spawn`]: crate::window::spawn
+/// [`window::close`]: crate::window::close
+pub trait Application: Sized
+where
+    <Self::Renderer as crate::Renderer>::Theme: StyleSheet,
+{
+    /// The data needed to initialize your [`Application`].
+    type Flags;
+
+    /// The graphics backend to use to draw the [`Application`].
+    type Renderer: crate::Renderer;
+
+    /// The type of __messages__ your [`Application`] will produce.
+    type Message: std::fmt::Debug + Send;
+
+    /// Initializes the [`Application`] with the flags provided to
+    /// [`run`] as part of the [`Settings`].
+    ///
+    /// Here is where you should return the initial state of your app.
+    ///
+    /// Additionally, you can return a [`Command`] if you need to perform some
+    /// async action in the background on startup. This is useful if you want to
+    /// load state from a file, perform an initial HTTP request, etc.
+    fn new(flags: Self::Flags) -> (Self, Command<Self::Message>);
+
+    /// Returns the current title of the window with the given [`window::Id`].
+    ///
+    /// This title can be dynamic! The runtime will automatically update the
+    /// title of the window when necessary.
+    fn title(&self, window: window::Id) -> String;
+
+    /// Handles a __message__ and updates the state of the [`Application`].
+    ///
+    /// This is where you define your __update logic__. All the __messages__,
+    /// produced by either user interactions in any window or commands, will
+    /// be handled by this method.
+    ///
+    /// Any [`Command`] returned will be executed immediately in the
+    /// background.
+    fn update(&mut self, message: Self::Message) -> Command<Self::Message>;
+
+    /// Returns the widgets to display in the window with the given
+    /// [`window::Id`].
+    ///
+    /// These widgets can produce __messages__ based on user interaction.
+    fn view(
+        &self,
+        window: window::Id,
+    ) -> Element<'_, Self::Message, Self::Renderer>;
+
+    /// Returns the current `Theme` of the window with the given
+    /// [`window::Id`].
+    fn theme(
+        &self,
+        window: window::Id,
+    ) -> <Self::Renderer as crate::Renderer>::Theme;
+
+    /// Returns the `Style` variation of the `Theme` of the window with the
+    /// given [`window::Id`].
+    fn style(
+        &self,
+        _window: window::Id,
+    ) -> <<Self::Renderer as crate::Renderer>::Theme as StyleSheet>::Style {
+        Default::default()
+    }
+
+    /// Returns the event `Subscription` for the current state of the
+    /// application.
+    ///
+    /// The messages produced by the `Subscription` will be handled by
+    /// [`update`](#tymethod.update).
+    ///
+    /// A `Subscription` will be kept alive as long as you keep returning it!
+    ///
+    /// By default, it returns an empty subscription.
+    fn subscription(&self) -> Subscription<Self::Message> {
+        Subscription::none()
+    }
+
+    /// Returns the scale factor of the window with the given [`window::Id`].
+    ///
+    /// It can be used to dynamically control the size of the UI at runtime
+    /// (i.e. zooming).
+    ///
+    /// For instance, a scale factor of `2.0` will make widgets twice as big,
+    /// while a scale factor of `0.5` will shrink them to half their size.
+    ///
+    /// By default, it returns `1.0`.
+    fn scale_factor(&self, _window: window::Id) -> f64 {
+        1.0
+    }
+
+    /// Returns whether the [`Application`] should be terminated.
+    ///
+    /// By default, it returns `false`.
+    fn should_exit(&self) -> bool {
+        false
+    }
+}
+
+/// An event processed by the instance of a multi-window [`Application`].
+enum Event<Message: 'static> {
+    Winit(winit::event::Event<'static, Message>),
+    WindowCreated {
+        id: window::Id,
+        window: winit::window::Window,
+    },
+}
+
+/// A request from the instance of a multi-window [`Application`] to the
+/// event loop.
+enum Control {
+    ChangeFlow(winit::event_loop::ControlFlow),
+    CreateWindow {
+        id: window::Id,
+        settings: settings::Window,
+        title: String,
+    },
+}
+
+/// Runs a multi-window [`Application`] with an executor, compositor, and the
+/// provided settings.
+///
+/// The [`Settings::window`] are used to create the [`window::Id::MAIN`]
+/// window. If [`Settings::exit_on_close_request`] is enabled, a window is
+/// closed as soon as the user requests it, and the [`Application`] exits once
+/// all of its windows are closed.
+///
+/// Event sessions cannot be recorded nor replayed yet, so an
+/// [`Error::SessionOpenFailed`] is returned if [`Settings::record_session`]
+/// or [`Settings::replay_session`] is set.
+pub fn run<A, E, C>(
+    settings: Settings<A::Flags>,
+    compositor_settings: C::Settings,
+) -> Result<(), Error>
+where
+    A: Application + 'static,
+    E: Executor + 'static,
+    C: Compositor<Renderer = A::Renderer> + 'static,
+    <A::Renderer as crate::Renderer>::Theme: StyleSheet,
+{
+    use futures::task;
+    use futures::Future;
+    use winit::event_loop::EventLoopBuilder;
+
+    if settings.record_session.is_some() || settings.replay_session.is_some() {
+        return Err(Error::SessionOpenFailed(
+            session::Error::MultiWindowUnsupported,
+        ));
+    }
+
+    let mut trace = Trace::start(&settings);
+
+    let mut debug = Debug::new();
+    debug.startup_started();
+
+    let event_loop = EventLoopBuilder::with_user_event().build();
+    let proxy = event_loop.create_proxy();
+
+    let runtime = {
+        let proxy = Proxy::new(event_loop.create_proxy());
+        let executor = E::new().map_err(Error::ExecutorCreationFailed)?;
+
+        Runtime::new(executor, proxy)
+    };
+
+    let geometry_id = persisted_geometry_id(&settings);
+
+    let (application, init_command) = {
+        let flags = settings.flags;
+
+        runtime.enter(|| A::new(flags))
+    };
+
+    let main_window = build_window(
+        settings.window,
+        &application.title(window::Id::MAIN),
+        settings.id,
+        geometry_id.as_deref(),
+        &event_loop,
+    )?;
+
+    let (compositor, renderer) =
+        C::new(compositor_settings, Some(&main_window))?;
+
+    let (mut sender, receiver) = mpsc::unbounded();
+    let (control_sender, mut control_receiver) = mpsc::unbounded();
+
+    let mut instance = Box::pin(run_instance::<A, E, C>(
+        application,
+        compositor,
+        renderer,
+        runtime,
+        proxy,
+        debug,
+        receiver,
+        control_sender,
+        init_command,
+        main_window,
+        settings.exit_on_close_request,
+        settings.focus_traversal,
+        geometry_id,
+    ));
+
+    let mut context = task::Context::from_waker(task::noop_waker_ref());
+
+    platform::run(event_loop, move |event, window_target, control_flow| {
+        use winit::event_loop::ControlFlow;
+
+        if let ControlFlow::ExitWithCode(_) = control_flow {
+            return;
+        }
+
+        if let Some(event) = static_event(event) {
+            let mut events = VecDeque::from([Event::Winit(event)]);
+
+            while let Some(event) = events.pop_front() {
+                sender.start_send(event).expect("Send event");
+
+                let poll = instance.as_mut().poll(&mut context);
+
+                if let task::Poll::Ready(_) = poll {
+                    // Flush the performance trace, if any, before exiting
+                    drop(trace.take());
+
+                    *control_flow = ControlFlow::Exit;
+                    return;
+                }
+
+                while let Ok(Some(control)) = control_receiver.try_next() {
+                    match control {
+                        Control::ChangeFlow(flow) => {
+                            *control_flow = flow;
+                        }
+                        Control::CreateWindow {
+                            id,
+                            settings,
+                            title,
+                        } => {
+                            match build_window(
+                                settings,
+                                &title,
+                                None,
+                                None,
+                                window_target,
+                            ) {
+                                Ok(window) => {
+                                    events.push_back(Event::WindowCreated {
+                                        id,
+                                        window,
+                                    });
+                                }
+                                Err(error) => {
+                                    log::error!(
+                                        "Failed to create window {:?}: {}",
+                                        id,
+                                        error
+                                    );
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            if let ControlFlow::Poll = control_flow {
+                *control_flow = ControlFlow::Wait;
+            }
+        }
+    })
+}
+
+/// A window of a multi-window [`Application`], along with its runtime state.
+struct Window<A, C>
+where
+    A: Application,
+    C: Compositor<Renderer = A::Renderer>,
+    <A::Renderer as crate::Renderer>::Theme: StyleSheet,
+{
+    // The surface needs to be dropped before the window it presents to.
+    surface: C::Surface,
+    raw: winit::window::Window,
+    state: State<<A::Renderer as crate::Renderer>::Theme>,
+    viewport_version: usize,
+    mouse_interaction: mouse::Interaction,
+    screenshots: screenshot::Queue<A::Message>,
+    redraw_request: Option<Instant>,
+}
+
+impl<A, C> Window<A, C>
+where
+    A: Application,
+    C: Compositor<Renderer = A::Renderer>,
+    <A::Renderer as crate::Renderer>::Theme: StyleSheet,
+{
+    fn new(
+        application: &A,
+        id: window::Id,
+        raw: winit::window::Window,
+        compositor: &mut C,
+    ) -> Self {
+        let state = State::new(
+            &raw,
+            application.title(id),
+            application.scale_factor(id),
+            application.theme(id),
+            &application.style(id),
+        );
+        let physical_size = state.physical_size();
+
+        let mut surface = compositor.create_surface(&raw);
+
+        compositor.configure_surface(
+            &mut surface,
+            physical_size.width,
+            physical_size.height,
+        );
+
+        Self {
+            surface,
+            viewport_version: state.viewport_version(),
+            raw,
+            state,
+            mouse_interaction: mouse::Interaction::default(),
+            screenshots: screenshot::Queue::new(),
+            redraw_request: None,
+        }
+    }
+}
+
+async fn run_instance<A, E, C>(
+    mut application: A,
+    mut compositor: C,
+    mut renderer: A::Renderer,
+    mut runtime: Runtime<E, Proxy<A::Message>, A::Message>,
+    mut proxy: winit::event_loop::EventLoopProxy<A::Message>,
+    mut debug: Debug,
+    mut receiver: mpsc::UnboundedReceiver<Event<A::Message>>,
+    mut control_sender: mpsc::UnboundedSender<Control>,
+    init_command: Command<A::Message>,
+    main_window: winit::window::Window,
+    exit_on_close_request: bool,
+    focus_traversal: bool,
+    geometry_id: Option<String>,
+) where
+    A: Application + 'static,
+    E: Executor + 'static,
+    C: Compositor<Renderer = A::Renderer> + 'static,
+    <A::Renderer as crate::Renderer>::Theme: StyleSheet,
+{
+    use iced_futures::futures::stream::StreamExt;
+    use winit::event;
+
+    let mut clipboard = Clipboard::connect(&main_window);
+    let mut geometry = geometry_id.as_ref().map(|_| Geometry::of(&main_window));
+    let mut windows = BTreeMap::new();
+
+    let _ = windows.insert(
+        window::Id::MAIN,
+        Window::new(
+            &application,
+            window::Id::MAIN,
+            main_window,
+            &mut compositor,
+        ),
+    );
+
+    let mut caches =
+        BTreeMap::from([(window::Id::MAIN, user_interface::Cache::default())]);
+
+    run_command(
+        &application,
+        &mut caches,
+        &mut windows,
+        &mut renderer,
+        init_command,
+        &mut runtime,
+        &mut clipboard,
+        &mut proxy,
+        &mut control_sender,
+        &mut debug,
+        || compositor.fetch_information(),
+    );
+    runtime.track(application.subscription());
+
+    let mut user_interfaces = ManuallyDrop::new(build_user_interfaces(
+        &application,
+        caches,
+        &windows,
+        &mut renderer,
+        &mut debug,
+    ));
+
+    let mut events = Vec::new();
+    let mut messages = Vec::new();
+
+    debug.startup_finished();
+
+    while let Some(event) = receiver.next().await {
+        let event = match event {
+            Event::WindowCreated { id, window } => {
+                let window =
+                    Window::new(&application, id, window, &mut compositor);
+
+                let _ = user_interfaces.insert(
+                    id,
+                    build_user_interface(
+                        &application,
+                        id,
+                        user_interface::Cache::default(),
+                        &mut renderer,
+                        window.state.logical_size(),
+                        &mut debug,
+                    ),
+                );
+
+                window.raw.request_redraw();
+
+                let _ = windows.insert(id, window);
+
+                continue;
+            }
+            Event::Winit(event) => event,
+        };
+
+        match event {
+            event::Event::NewEvents(event::StartCause::ResumeTimeReached {
+                ..
+            }) => {
+                let now = Instant::now();
+
+                events.extend(windows.keys().map(|id| {
+                    (
+                        *id,
+                        iced_native::Event::Window(
+                            *id,
+                            window::Event::RedrawRequested(now),
+                        ),
+                    )
+                }));
+            }
+            event::Event::MainEventsCleared => {
+                if events.is_empty() && messages.is_empty() {
+                    continue;
+                }
+
+                debug.event_processing_started();
+
+                let mut outdated = false;
+
+                for (id, user_interface) in user_interfaces.iter_mut() {
+                    let window = match windows.get_mut(id) {
+                        Some(window) => window,
+                        None => continue,
+                    };
+
+                    let window_events: Vec<_> = events
+                        .iter()
+                        .filter(|(window, _)| window == id)
+                        .map(|(_, event)| event.clone())
+                        .collect();
+
+                    if window_events.is_empty() {
+                        continue;
+                    }
+
+                    let (interface_state, mut statuses) = user_interface
+                        .update(
+                            &window_events,
+                            window.state.cursor_position(),
+                            &mut renderer,
+                            &mut clipboard,
+                            &mut messages,
+                        );
+
+                    if focus_traversal {
+                        user_interface.traverse_focus(
+                            &window_events,
+                            &mut statuses,
+                            &renderer,
+                        );
+                    }
+
+                    for (event, status) in
+                        window_events.into_iter().zip(statuses)
+                    {
+                        // Subscriptions are notified of redraws once the
+                        // frame is presented
+                        if !is_redraw_request(&event) {
+                            runtime.broadcast((event, status));
+                        }
+                    }
+
+                    if let user_interface::State::Outdated { .. } =
+                        interface_state
+                    {
+                        outdated = true;
+                    }
+
+                    window.redraw_request = interface_state.redraw_request();
+                    window.raw.request_redraw();
+                }
+
+                events.clear();
+
+                debug.event_processing_finished();
+
+                // Windows that did not receive any events keep waiting for
+                // the redraw they requested last
+                let redraw_request = windows
+                    .values()
+                    .filter_map(|window| window.redraw_request)
+                    .min();
+
+                let _ = control_sender.start_send(Control::ChangeFlow(
+                    match redraw_request {
+                        Some(at) => {
+                            winit::event_loop::ControlFlow::WaitUntil(at)
+                        }
+                        None => winit::event_loop::ControlFlow::Wait,
+                    },
+                ));
+
+                if !messages.is_empty() || outdated {
+                    let mut caches: BTreeMap<_, _> =
+                        ManuallyDrop::into_inner(user_interfaces)
+                            .into_iter()
+                            .map(|(id, user_interface)| {
+                                (id, user_interface.into_cache())
+                            })
+                            .collect();
+
+                    // Update application
+                    update(
+                        &mut application,
+                        &mut caches,
+                        &mut windows,
+                        &mut renderer,
+                        &mut runtime,
+                        &mut clipboard,
+                        &mut proxy,
+                        &mut control_sender,
+                        &mut debug,
+                        &mut messages,
+                        || compositor.fetch_information(),
+                    );
+
+                    // Update windows
+                    for (id, window) in windows.iter_mut() {
+                        window.state.synchronize(
+                            &window.raw,
+                            application.title(*id),
+                            application.scale_factor(*id),
+                            application.theme(*id),
+                            &application.style(*id),
+                        );
+                        window.raw.request_redraw();
+                    }
+
+                    let should_exit =
+                        application.should_exit() || windows.is_empty();
+
+                    user_interfaces = ManuallyDrop::new(build_user_interfaces(
+                        &application,
+                        caches,
+                        &windows,
+                        &mut renderer,
+                        &mut debug,
+                    ));
+
+                    if should_exit {
+                        break;
+                    }
+                }
+            }
+            event::Event::PlatformSpecific(event::PlatformSpecific::MacOS(
+                event::MacOS::ReceivedUrl(url),
+            )) => {
+                use iced_native::event;
+
+                // Platform events are not tied to a window, so we deliver
+                // them to the oldest window that is still open.
+                if let Some(id) = windows.keys().next() {
+                    events.push((
+                        *id,
+                        iced_native::Event::PlatformSpecific(
+                            event::PlatformSpecific::MacOS(
+                                event::MacOS::ReceivedUrl(url),
+                            ),
+                        ),
+                    ));
+                }
+            }
+            event::Event::UserEvent(message) => {
+                messages.push(message);
+            }
+            event::Event::RedrawRequested(window_id) => {
+                let (id, window) = match windows
+                    .iter_mut()
+                    .find(|(_, window)| window.raw.id() == window_id)
+                {
+                    Some((id, window)) => (*id, window),
+                    None => continue,
+                };
+
+                let physical_size = window.state.physical_size();
+
+                if physical_size.width == 0 || physical_size.height == 0 {
+                    continue;
+                }
+
+                debug.render_started();
+                let current_viewport_version = window.state.viewport_version();
+
+                if window.viewport_version != current_viewport_version {
+                    let logical_size = window.state.logical_size();
+
+                    debug.layout_started();
+                    if let Some(user_interface) = user_interfaces.remove(&id) {
+                        let _ = user_interfaces.insert(
+                            id,
+                            user_interface
+                                .relayout(logical_size, &mut renderer),
+                        );
+                    }
+                    debug.layout_finished();
+
+                    compositor.configure_surface(
+                        &mut window.surface,
+                        physical_size.width,
+                        physical_size.height,
+                    );
+
+                    window.viewport_version = current_viewport_version;
+                }
+
+                let user_interface = match user_interfaces.get_mut(&id) {
+                    Some(user_interface) => user_interface,
+                    None => continue,
+                };
+
+                // The renderer is shared by all the windows, so the user
+                // interface needs to be drawn right before presenting it.
+                draw(
+                    user_interface,
+                    &mut renderer,
+                    window.state.theme(),
+                    &renderer::Style {
+                        text_color: window.state.text_color(),
+                    },
+                    window.state.cursor_position(),
+                    window.state.cursor_icon(),
+                    &mut debug,
+                    &window.raw,
+                    &mut window.mouse_interaction,
+                );
+
+                present(
+                    &mut compositor,
+                    &mut renderer,
+                    &mut window.surface,
+                    window.state.viewport(),
+                    window.state.background_color(),
+                    &mut debug,
+                    &mut window.screenshots,
+                    &mut runtime,
+                    &mut proxy,
+                    id,
+                    &window.raw,
+                );
+            }
+            event::Event::WindowEvent {
+                event: window_event,
+                window_id,
+            } => {
+                let (id, window) = match windows
+                    .iter_mut()
+                    .find(|(_, window)| window.raw.id() == window_id)
+                {
+                    Some((id, window)) => (*id, window),
+                    None => continue,
+                };
+
+                // The application may be terminated right after a close
+                // request, so its geometry is stored right away.
+                if let (
+                    window::Id::MAIN,
+                    winit::event::WindowEvent::CloseRequested,
+                    Some(geometry_id),
+                    Some(geometry),
+                ) = (id, &window_event, &geometry_id, geometry)
+                {
+                    save_geometry(geometry_id, geometry);
+                }
+
+                if exit_on_close_request
+                    && requests_exit(&window_event, window.state.modifiers())
+                {
+                    if let winit::event::WindowEvent::CloseRequested =
+                        window_event
+                    {
+                        let _ = user_interfaces.remove(&id);
+                        let _ = windows.remove(&id);
+
+                        if !windows.is_empty() {
+                            continue;
+                        }
+                    }
+
+                    break;
+                }
+
+                window.state.update(&window.raw, &window_event, &mut debug);
+
+                if let (window::Id::MAIN, Some(geometry)) = (id, &mut geometry)
+                {
+                    geometry.track(&window.raw, &window_event);
+                }
+
+                if let Some(event) = conversion::window_event(
+                    id,
+                    &window_event,
+                    window.state.scale_factor(),
+                    window.state.modifiers(),
+                ) {
+                    events.push((id, event));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if let (Some(id), Some(geometry)) = (geometry_id, geometry) {
+        save_geometry(&id, geometry);
+    }
+
+    // Manually drop the user interfaces
+    drop(ManuallyDrop::into_inner(user_interfaces));
+}
+
+/// Builds a [`UserInterface`] for the window with the given [`window::Id`]
+/// of the provided [`Application`], logging [`struct@Debug`] information
+/// accordingly.
+pub fn build_user_interface<'a, A: Application>(
+    application: &'a A,
+    id: window::Id,
+    cache: user_interface::Cache,
+    renderer: &mut A::Renderer,
+    size: Size,
+    debug: &mut Debug,
+) -> UserInterface<'a, A::Message, A::Renderer>
+where
+    <A::Renderer as crate::Renderer>::Theme: StyleSheet,
+{
+    debug.view_started();
+    let view = application.view(id);
+    debug.view_finished();
+
+    debug.layout_started();
+    let user_interface = UserInterface::build(view, size, cache, renderer);
+    debug.layout_finished();
+
+    user_interface
+}
+
+/// Builds the [`UserInterface`] of every window that is still open, reusing
+/// the given caches.
+fn build_user_interfaces<'a, A, C>(
+    application: &'a A,
+    caches: BTreeMap<window::Id, user_interface::Cache>,
+    windows: &BTreeMap<window::Id, Window<A, C>>,
+    renderer: &mut A::Renderer,
+    debug: &mut Debug,
+) -> BTreeMap<window::Id, UserInterface<'a, A::Message, A::Renderer>>
+where
+    A: Application,
+    C: Compositor<Renderer = A::Renderer>,
+    <A::Renderer as crate::Renderer>::Theme: StyleSheet,
+{
+    caches
+        .into_iter()
+        .filter_map(|(id, cache)| {
+            let window = windows.get(&id)?;
+
+            Some((
+                id,
+                build_user_interface(
+                    application,
+                    id,
+                    cache,
+                    renderer,
+                    window.state.logical_size(),
+                    debug,
+                ),
+            ))
+        })
+        .collect()
+}
+
+/// Updates a multi-window [`Application`] by feeding it the provided
+/// messages, spawning any resulting [`Command`], and tracking its
+/// [`Subscription`].
+fn update<A, C, E>(
+    application: &mut A,
+    caches: &mut BTreeMap<window::Id, user_interface::Cache>,
+    windows: &mut BTreeMap<window::Id, Window<A, C>>,
+    renderer: &mut A::Renderer,
+    runtime: &mut Runtime<E, Proxy<A::Message>, A::Message>,
+    clipboard: &mut Clipboard,
+    proxy: &mut winit::event_loop::EventLoopProxy<A::Message>,
+    control_sender: &mut mpsc::UnboundedSender<Control>,
+    debug: &mut Debug,
+    messages: &mut Vec<A::Message>,
+    graphics_info: impl FnOnce() -> compositor::Information + Copy,
+) where
+    A: Application,
+    C: Compositor<Renderer = A::Renderer>,
+    E: Executor,
+    <A::Renderer as crate::Renderer>::Theme: StyleSheet,
+{
+    for message in messages.drain(..) {
+        debug.log_message(&message);
+
+        debug.update_started();
+        let command = runtime.enter(|| application.update(message));
+        debug.update_finished();
+
+        run_command(
+            application,
+            caches,
+            windows,
+            renderer,
+            command,
+            runtime,
+            clipboard,
+            proxy,
+            control_sender,
+            debug,
+            graphics_info,
+        );
+    }
+
+    let subscription = application.subscription();
+    runtime.track(subscription);
+}
+
+/// Runs the actions of a [`Command`] for a multi-window [`Application`].
+fn run_command<A, C, E>(
+    application: &A,
+    caches: &mut BTreeMap<window::Id, user_interface::Cache>,
+    windows: &mut BTreeMap<window::Id, Window<A, C>>,
+    renderer: &mut A::Renderer,
+    command: Command<A::Message>,
+    runtime: &mut Runtime<E, Proxy<A::Message>, A::Message>,
+    clipboard: &mut Clipboard,
+    proxy: &mut winit::event_loop::EventLoopProxy<A::Message>,
+    control_sender: &mut mpsc::UnboundedSender<Control>,
+    debug: &mut Debug,
+    graphics_info: impl FnOnce() -> compositor::Information + Copy,
+) where
+    A: Application,
+    C: Compositor<Renderer = A::Renderer>,
+    E: Executor,
+    <A::Renderer as crate::Renderer>::Theme: StyleSheet,
+{
+    use iced_native::command;
+
+    for action in command.actions() {
+        match action {
+            command::Action::Future(future) => {
+                runtime.spawn(future);
+            }
+            command::Action::Clipboard(action) => {
+                if let Some(message) = clipboard.perform(action) {
+                    proxy
+                        .send_event(message)
+                        .expect("Send message to event loop");
+                }
+            }
+            command::Action::Window(id, action) => match action {
+                window::Action::Spawn(settings) => {
+                    if windows.contains_key(&id) {
+                        log::warn!("Window {:?} is already open", id);
+                        continue;
+                    }
+
+                    let _ = control_sender.start_send(Control::CreateWindow {
+                        id,
+                        settings: settings.into(),
+                        title: application.title(id),
+                    });
+                }
+                window::Action::Close => {
+                    let _ = caches.remove(&id);
+                    let _ = windows.remove(&id);
+                }
+                window::Action::FetchFocused(tag) => {
+                    let focused = windows
+                        .get(&id)
+                        .map(|window| window.state.is_focused())
+                        .unwrap_or(false);
+
+                    proxy
+                        .send_event(tag(focused))
+                        .expect("Send message to event loop");
+                }
+                window::Action::Screenshot(region, tag) => {
+                    match windows.get_mut(&id) {
+                        Some(window) => {
+                            window.screenshots.push(region, tag);
+                            window.raw.request_redraw();
+                        }
+                        None => {
+                            log::warn!("Window {:?} is not open", id);
+                        }
+                    }
+                }
+                window::Action::SetCursorIcon(cursor_icon) => {
+                    match windows.get_mut(&id) {
+                        Some(window) => {
+                            window.state.set_cursor_icon(cursor_icon);
+                            window.raw.request_redraw();
+                        }
+                        None => {
+                            log::warn!("Window {:?} is not open", id);
+                        }
+                    }
+                }
+                action => match windows.get(&id) {
+                    Some(window) => {
+                        run_window_action(action, &window.raw, proxy);
+                    }
+                    None => {
+                        log::warn!("Window {:?} is not open", id);
+                    }
+                },
+            },
+            command::Action::System(action) => {
+                run_system_action(action, proxy, graphics_info);
+            }
+            command::Action::Widget(action) => {
+                // Widget operations are not tied to a window, so they are
+                // run on the user interfaces of all the open windows.
+                let mut user_interfaces = build_user_interfaces(
+                    application,
+                    std::mem::take(caches),
+                    windows,
+                    renderer,
+                    debug,
+                );
+
+                run_operation(action.into_operation(), proxy, |operation| {
+                    for user_interface in user_interfaces.values_mut() {
+                        user_interface.operate(renderer, operation);
+                    }
+                });
+
+                *caches = user_interfaces
+                    .into_iter()
+                    .map(|(id, user_interface)| {
+                        (id, user_interface.into_cache())
+                    })
+                    .collect();
+            }
+        }
+    }
+}
@@ -0,0 +1,62 @@
+//! Fulfill the screenshot requests of an application.
+use crate::window::Screenshot;
+use crate::{Rectangle, Size};
+
+/// A list of screenshot requests waiting for the next frame of a window to
+/// be presented.
+#[allow(missing_debug_implementations)]
+pub struct Queue<Message> {
+    requests: Vec<Request<Message>>,
+}
+
+type Request<Message> =
+    (Option<Rectangle>, Box<dyn FnOnce(Screenshot) -> Message>);
+
+impl<Message> Queue<Message> {
+    /// Creates a new empty [`Queue`].
+    pub fn new() -> Self {
+        Self {
+            requests: Vec::new(),
+        }
+    }
+
+    /// Adds a screenshot request, optionally cropped to the given region in
+    /// logical coordinates.
+    pub fn push(
+        &mut self,
+        region: Option<Rectangle>,
+        f: Box<dyn FnOnce(Screenshot) -> Message>,
+    ) {
+        self.requests.push((region, f));
+    }
+
+    /// Returns true if there are no pending requests.
+    pub fn is_empty(&self) -> bool {
+        self.requests.is_empty()
+    }
+
+    /// Fulfills all the pending requests with the given RGBA bytes of the
+    /// presented frame, returning the resulting messages.
+    pub fn fulfill(
+        &mut self,
+        bytes: Vec<u8>,
+        size: Size<u32>,
+        scale_factor: f64,
+    ) -> Vec<Message> {
+        let screenshot = Screenshot::new(bytes, size, scale_factor);
+
+        self.requests
+            .drain(..)
+            .map(|(region, f)| match region {
+                Some(region) => f(screenshot.crop(region)),
+                None => f(screenshot.clone()),
+            })
+            .collect()
+    }
+}
+
+impl<Message> Default for Queue<Message> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
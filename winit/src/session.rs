@@ -0,0 +1,203 @@
+//! Record and replay the event sessions of an application.
+pub use iced_native::session::{Record, Recorder, Replay, Step};
+
+use crate::time::Instant;
+use crate::{Event, Point, Settings, Viewport};
+
+use std::fmt;
+use std::fs;
+use std::io;
+
+/// The event session of an application, either being recorded to a file or
+/// replayed from one.
+///
+/// See [`Settings::record_session`] and [`Settings::replay_session`].
+#[allow(missing_debug_implementations)]
+pub struct Session {
+    mode: Mode,
+}
+
+enum Mode {
+    Disabled,
+    Recording {
+        recorder: Recorder<fs::File>,
+        viewport_version: Option<usize>,
+    },
+    Replaying {
+        replay: Replay,
+        expected_messages: Vec<String>,
+    },
+}
+
+impl Session {
+    /// Opens the [`Session`] configured in the given [`Settings`].
+    ///
+    /// Replaying takes precedence over recording.
+    pub fn open<Flags>(settings: &Settings<Flags>) -> Result<Self, Error> {
+        let mode = if let Some(path) = &settings.replay_session {
+            let contents = fs::read_to_string(path)?;
+
+            Mode::Replaying {
+                replay: Replay::parse(&contents)?,
+                expected_messages: Vec::new(),
+            }
+        } else if let Some(path) = &settings.record_session {
+            Mode::Recording {
+                recorder: Recorder::new(fs::File::create(path)?),
+                viewport_version: None,
+            }
+        } else {
+            Mode::Disabled
+        };
+
+        Ok(Self { mode })
+    }
+
+    /// Returns true if the [`Session`] is being replayed, in which case the
+    /// input of the user should be ignored.
+    pub fn is_replaying(&self) -> bool {
+        matches!(self.mode, Mode::Replaying { .. })
+    }
+
+    /// Records the events about to be processed by the user interface, along
+    /// with the current [`Viewport`] if it changed since the last update.
+    pub fn record_update(
+        &mut self,
+        viewport: &Viewport,
+        viewport_version: usize,
+        events: &[Event],
+        cursor_position: Point,
+    ) {
+        if let Mode::Recording {
+            recorder,
+            viewport_version: recorded_version,
+        } = &mut self.mode
+        {
+            let viewport_changed = *recorded_version != Some(viewport_version);
+            *recorded_version = Some(viewport_version);
+
+            let viewport = viewport_changed.then(|| Record::Viewport {
+                size: viewport.physical_size(),
+                scale_factor: viewport.scale_factor(),
+            });
+
+            let records = viewport
+                .into_iter()
+                .chain(events.iter().cloned().map(Record::Event))
+                .chain(Some(Record::Update { cursor_position }));
+
+            for record in records {
+                if let Err(error) = recorder.record(&record) {
+                    log::warn!("Failed to record session: {}", error);
+                }
+            }
+        }
+    }
+
+    /// Records the messages produced by the last update.
+    ///
+    /// When replaying, they are compared with the recorded ones instead and
+    /// a warning is logged if the replay diverged.
+    pub fn record_messages<Message: fmt::Debug>(
+        &mut self,
+        messages: &[Message],
+    ) {
+        match &mut self.mode {
+            Mode::Disabled => {}
+            Mode::Recording { recorder, .. } => {
+                for message in messages {
+                    let record = Record::Message(format!("{:?}", message));
+
+                    if let Err(error) = recorder.record(&record) {
+                        log::warn!("Failed to record session: {}", error);
+                    }
+                }
+            }
+            Mode::Replaying {
+                expected_messages, ..
+            } => {
+                let messages: Vec<_> = messages
+                    .iter()
+                    .map(|message| format!("{:?}", message))
+                    .collect();
+
+                if messages != *expected_messages {
+                    log::warn!(
+                        "Replay diverged: expected messages {:?}, \
+                        produced {:?}",
+                        expected_messages,
+                        messages
+                    );
+                }
+
+                expected_messages.clear();
+            }
+        }
+    }
+
+    /// Returns the next [`Step`] of the replay, if it is due.
+    ///
+    /// The given window is resized to the recorded size, if it changed. Its
+    /// scale factor cannot be changed, so a warning is logged if it differs.
+    pub fn next_step(
+        &mut self,
+        window: &winit::window::Window,
+    ) -> Option<Step> {
+        match &mut self.mode {
+            Mode::Replaying {
+                replay,
+                expected_messages,
+            } => {
+                let mut step = replay.next_due(Instant::now())?;
+                *expected_messages = std::mem::take(&mut step.messages);
+
+                if let Some((size, scale_factor)) = step.viewport {
+                    window.set_inner_size(winit::dpi::PhysicalSize {
+                        width: size.width,
+                        height: size.height,
+                    });
+
+                    if scale_factor != window.scale_factor() {
+                        log::warn!(
+                            "Replaying a session recorded with a scale factor \
+                            of {}, but the window has a scale factor of {}",
+                            scale_factor,
+                            window.scale_factor()
+                        );
+                    }
+                }
+
+                Some(step)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the earliest of the given moment and the moment the next
+    /// [`Step`] of the replay is due.
+    pub fn next_wake_up(&self, at: Option<Instant>) -> Option<Instant> {
+        match &self.mode {
+            Mode::Replaying { replay, .. } => match (at, replay.next_at()) {
+                (Some(at), Some(next)) => Some(at.min(next)),
+                (at, next) => at.or(next),
+            },
+            _ => at,
+        }
+    }
+}
+
+/// An error produced while opening a [`Session`].
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The session file could not be read or created.
+    #[error("the session file could not be accessed: {0}")]
+    Io(#[from] io::Error),
+
+    /// The session file contains invalid records.
+    #[error("the session file is invalid: {0}")]
+    Invalid(#[from] iced_native::session::Error),
+
+    /// Sessions cannot be recorded or replayed by multi-window applications.
+    #[error("sessions are not supported by multi-window applications")]
+    MultiWindowUnsupported,
+}
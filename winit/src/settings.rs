@@ -22,10 +22,13 @@ mod platform;
 pub use platform::PlatformSpecific;
 
 use crate::conversion;
+use crate::window;
 use crate::Position;
 use winit::monitor::MonitorHandle;
 use winit::window::WindowBuilder;
 
+use std::path::PathBuf;
+
 /// The settings of an application.
 #[derive(Debug, Clone, Default)]
 pub struct Settings<Flags> {
@@ -49,6 +52,57 @@ pub struct Settings<Flags> {
     /// [`Application`]: crate::Application
     pub exit_on_close_request: bool,
 
+    /// Whether pressing Tab or Shift+Tab should move the focus to the next
+    /// or previous focusable widget, when no widget handles the key press.
+    pub focus_traversal: bool,
+
+    /// Whether the size, position, and maximized state of the window should
+    /// be stored when the [`Application`] exits and restored on startup.
+    ///
+    /// The geometry is stored under the [`id`](Self::id) of the application
+    /// in the configuration directory of the user, so it needs to be
+    /// provided. The restored geometry is clamped to the monitors that are
+    /// currently connected.
+    ///
+    /// [`Application`]: crate::Application
+    pub persist_window_geometry: bool,
+
+    /// The file where the events received by the [`Application`], along with
+    /// the size and scale factor of its window and the messages it produces,
+    /// should be recorded, if any.
+    ///
+    /// The recording can be replayed later with
+    /// [`replay_session`](Self::replay_session). Multi-window applications
+    /// do not support it and fail to start if it is set.
+    ///
+    /// [`Application`]: crate::Application
+    pub record_session: Option<PathBuf>,
+
+    /// The recorded session that should be replayed, if any.
+    ///
+    /// When replaying, the input of the user is ignored and the recorded
+    /// events are fed to the [`Application`] at their original pace instead.
+    /// A warning is logged if the produced messages differ from the recorded
+    /// ones. Multi-window applications do not support it and fail to start
+    /// if it is set.
+    ///
+    /// [`Application`]: crate::Application
+    pub replay_session: Option<PathBuf>,
+
+    /// The file where a performance trace of the [`Application`] should be
+    /// written, if any.
+    ///
+    /// The trace contains the update, view, layout, event processing, draw
+    /// and render spans of the [`Application`], along with the primitive
+    /// counts of every layer and the compilations of custom shaders. It uses
+    /// the Chrome trace format, so it can be opened with `chrome://tracing`
+    /// or [Perfetto](https://ui.perfetto.dev).
+    ///
+    /// Requires the `trace` feature.
+    ///
+    /// [`Application`]: crate::Application
+    pub trace_file: Option<PathBuf>,
+
     /// Whether the [`Application`] should try to build the context
     /// using OpenGL ES first then OpenGL.
     ///
@@ -197,3 +251,21 @@ impl Default for Window {
         }
     }
 }
+
+impl From<window::Settings> for Window {
+    fn from(settings: window::Settings) -> Self {
+        Self {
+            size: settings.size,
+            position: settings.position,
+            min_size: settings.min_size,
+            max_size: settings.max_size,
+            visible: settings.visible,
+            resizable: settings.resizable,
+            decorations: settings.decorations,
+            transparent: settings.transparent,
+            always_on_top: settings.always_on_top,
+            icon: settings.icon.and_then(conversion::icon),
+            platform_specific: Default::default(),
+        }
+    }
+}
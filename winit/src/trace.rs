@@ -0,0 +1,66 @@
+//! Export performance traces of an application.
+use crate::Settings;
+
+/// A performance trace of an application being written to a file.
+///
+/// The trace is flushed when the [`Trace`] is dropped.
+///
+/// See [`Settings::trace_file`].
+#[allow(missing_debug_implementations)]
+pub struct Trace {
+    #[cfg(feature = "trace")]
+    _guard: tracing_chrome::FlushGuard,
+}
+
+impl Trace {
+    /// Starts the [`Trace`] configured in the given [`Settings`], if any.
+    ///
+    /// Failing to start a [`Trace`] is not fatal; a warning is logged
+    /// instead.
+    pub fn start<Flags>(settings: &Settings<Flags>) -> Option<Self> {
+        let path = settings.trace_file.as_ref()?;
+
+        #[cfg(feature = "trace")]
+        {
+            use tracing_subscriber::prelude::*;
+
+            let file = match std::fs::File::create(path) {
+                Ok(file) => file,
+                Err(error) => {
+                    log::warn!(
+                        "Could not create trace file {}: {}",
+                        path.display(),
+                        error
+                    );
+
+                    return None;
+                }
+            };
+
+            let (layer, guard) = tracing_chrome::ChromeLayerBuilder::new()
+                .writer(file)
+                .include_args(true)
+                .build();
+
+            if let Err(error) =
+                tracing_subscriber::registry().with(layer).try_init()
+            {
+                log::warn!("Could not install trace subscriber: {}", error);
+
+                return None;
+            }
+
+            Some(Self { _guard: guard })
+        }
+
+        #[cfg(not(feature = "trace"))]
+        {
+            log::warn!(
+                "Cannot write trace to {}: the `trace` feature is disabled",
+                path.display()
+            );
+
+            None
+        }
+    }
+}
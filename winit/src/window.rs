@@ -1,52 +1,420 @@
-//! Interact with the window of your application.
+//! Interact with the windows of your application.
 use crate::command::{self, Command};
+use crate::mouse;
+use crate::{Rectangle, Size};
 use iced_native::window;
 
-pub use window::{Event, Mode};
+pub use window::{
+    frames, icon, CursorGrab, Event, Icon, Id, Mode, Monitor, Position,
+    Screenshot, Settings, UserAttention,
+};
+
+/// Opens a new window with the given [`Id`] and [`Settings`].
+///
+/// Only supported by multi-window applications.
+pub fn spawn<Message>(id: Id, settings: Settings) -> Command<Message> {
+    Command::single(command::Action::Window(
+        id,
+        window::Action::Spawn(settings),
+    ))
+}
+
+/// Closes the window with the given [`Id`].
+///
+/// Only supported by multi-window applications.
+pub fn close<Message>(id: Id) -> Command<Message> {
+    Command::single(command::Action::Window(id, window::Action::Close))
+}
 
 /// Begins dragging the window while the left mouse button is held.
 pub fn drag<Message>() -> Command<Message> {
-    Command::single(command::Action::Window(window::Action::Drag))
+    drag_for(Id::MAIN)
+}
+
+/// Same as [`drag`], but for the window with the given [`Id`].
+pub fn drag_for<Message>(id: Id) -> Command<Message> {
+    Command::single(command::Action::Window(id, window::Action::Drag))
 }
 
 /// Resizes the window to the given logical dimensions.
 pub fn resize<Message>(width: u32, height: u32) -> Command<Message> {
-    Command::single(command::Action::Window(window::Action::Resize {
-        width,
-        height,
-    }))
+    resize_for(Id::MAIN, width, height)
+}
+
+/// Same as [`resize`], but for the window with the given [`Id`].
+pub fn resize_for<Message>(
+    id: Id,
+    width: u32,
+    height: u32,
+) -> Command<Message> {
+    Command::single(command::Action::Window(
+        id,
+        window::Action::Resize { width, height },
+    ))
 }
 
 /// Sets the window to maximized or back.
 pub fn maximize<Message>(value: bool) -> Command<Message> {
-    Command::single(command::Action::Window(window::Action::Maximize(value)))
+    maximize_for(Id::MAIN, value)
+}
+
+/// Same as [`maximize`], but for the window with the given [`Id`].
+pub fn maximize_for<Message>(id: Id, value: bool) -> Command<Message> {
+    Command::single(command::Action::Window(
+        id,
+        window::Action::Maximize(value),
+    ))
 }
 
 /// Set the window to minimized or back.
 pub fn minimize<Message>(value: bool) -> Command<Message> {
-    Command::single(command::Action::Window(window::Action::Minimize(value)))
+    minimize_for(Id::MAIN, value)
+}
+
+/// Same as [`minimize`], but for the window with the given [`Id`].
+pub fn minimize_for<Message>(id: Id, value: bool) -> Command<Message> {
+    Command::single(command::Action::Window(
+        id,
+        window::Action::Minimize(value),
+    ))
 }
 
 /// Moves a window to the given logical coordinates.
 pub fn move_to<Message>(x: i32, y: i32) -> Command<Message> {
-    Command::single(command::Action::Window(window::Action::Move { x, y }))
+    move_to_for(Id::MAIN, x, y)
+}
+
+/// Same as [`move_to`], but for the window with the given [`Id`].
+pub fn move_to_for<Message>(id: Id, x: i32, y: i32) -> Command<Message> {
+    Command::single(command::Action::Window(id, window::Action::Move { x, y }))
 }
 
 /// Sets the [`Mode`] of the window.
 pub fn set_mode<Message>(mode: Mode) -> Command<Message> {
-    Command::single(command::Action::Window(window::Action::SetMode(mode)))
+    set_mode_for(Id::MAIN, mode)
+}
+
+/// Same as [`set_mode`], but for the window with the given [`Id`].
+pub fn set_mode_for<Message>(id: Id, mode: Mode) -> Command<Message> {
+    Command::single(command::Action::Window(id, window::Action::SetMode(mode)))
 }
 
 /// Sets the window to maximized or back.
 pub fn toggle_maximize<Message>() -> Command<Message> {
-    Command::single(command::Action::Window(window::Action::ToggleMaximize))
+    toggle_maximize_for(Id::MAIN)
+}
+
+/// Same as [`toggle_maximize`], but for the window with the given [`Id`].
+pub fn toggle_maximize_for<Message>(id: Id) -> Command<Message> {
+    Command::single(command::Action::Window(id, window::Action::ToggleMaximize))
 }
 
 /// Fetches the current [`Mode`] of the window.
 pub fn fetch_mode<Message>(
     f: impl FnOnce(Mode) -> Message + 'static,
 ) -> Command<Message> {
-    Command::single(command::Action::Window(window::Action::FetchMode(
-        Box::new(f),
-    )))
+    fetch_mode_for(Id::MAIN, f)
+}
+
+/// Same as [`fetch_mode`], but for the window with the given [`Id`].
+pub fn fetch_mode_for<Message>(
+    id: Id,
+    f: impl FnOnce(Mode) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Window(
+        id,
+        window::Action::FetchMode(Box::new(f)),
+    ))
+}
+
+/// Changes the title of the window.
+///
+/// The title will be replaced again once the title of the application
+/// changes.
+pub fn set_title<Message>(title: impl Into<String>) -> Command<Message> {
+    set_title_for(Id::MAIN, title)
+}
+
+/// Same as [`set_title`], but for the window with the given [`Id`].
+pub fn set_title_for<Message>(
+    id: Id,
+    title: impl Into<String>,
+) -> Command<Message> {
+    Command::single(command::Action::Window(
+        id,
+        window::Action::SetTitle(title.into()),
+    ))
+}
+
+/// Changes the [`Icon`] of the window.
+pub fn set_icon<Message>(icon: Icon) -> Command<Message> {
+    set_icon_for(Id::MAIN, icon)
+}
+
+/// Same as [`set_icon`], but for the window with the given [`Id`].
+pub fn set_icon_for<Message>(id: Id, icon: Icon) -> Command<Message> {
+    Command::single(command::Action::Window(id, window::Action::SetIcon(icon)))
+}
+
+/// Sets whether the window should have a border, a title bar, etc.
+pub fn set_decorations<Message>(value: bool) -> Command<Message> {
+    set_decorations_for(Id::MAIN, value)
+}
+
+/// Same as [`set_decorations`], but for the window with the given [`Id`].
+pub fn set_decorations_for<Message>(id: Id, value: bool) -> Command<Message> {
+    Command::single(command::Action::Window(
+        id,
+        window::Action::SetDecorations(value),
+    ))
+}
+
+/// Sets whether the window should be resizable or not.
+pub fn set_resizable<Message>(value: bool) -> Command<Message> {
+    set_resizable_for(Id::MAIN, value)
+}
+
+/// Same as [`set_resizable`], but for the window with the given [`Id`].
+pub fn set_resizable_for<Message>(id: Id, value: bool) -> Command<Message> {
+    Command::single(command::Action::Window(
+        id,
+        window::Action::SetResizable(value),
+    ))
+}
+
+/// Sets whether the window will always be on top of other windows.
+pub fn set_always_on_top<Message>(value: bool) -> Command<Message> {
+    set_always_on_top_for(Id::MAIN, value)
+}
+
+/// Same as [`set_always_on_top`], but for the window with the given [`Id`].
+pub fn set_always_on_top_for<Message>(id: Id, value: bool) -> Command<Message> {
+    Command::single(command::Action::Window(
+        id,
+        window::Action::SetAlwaysOnTop(value),
+    ))
+}
+
+/// Requests user attention to the window, or cancels a previous request with
+/// `None`.
+pub fn request_user_attention<Message>(
+    attention: Option<UserAttention>,
+) -> Command<Message> {
+    request_user_attention_for(Id::MAIN, attention)
+}
+
+/// Same as [`request_user_attention`], but for the window with the given [`Id`].
+pub fn request_user_attention_for<Message>(
+    id: Id,
+    attention: Option<UserAttention>,
+) -> Command<Message> {
+    Command::single(command::Action::Window(
+        id,
+        window::Action::RequestUserAttention(attention),
+    ))
+}
+
+/// Overrides the icon of the cursor while it is over the window.
+///
+/// The icon replaces the one of the [`mouse::Interaction`] of the user
+/// interface until it is cleared with `None`.
+pub fn set_cursor_icon<Message>(
+    interaction: Option<mouse::Interaction>,
+) -> Command<Message> {
+    set_cursor_icon_for(Id::MAIN, interaction)
+}
+
+/// Same as [`set_cursor_icon`], but for the window with the given [`Id`].
+pub fn set_cursor_icon_for<Message>(
+    id: Id,
+    interaction: Option<mouse::Interaction>,
+) -> Command<Message> {
+    Command::single(command::Action::Window(
+        id,
+        window::Action::SetCursorIcon(interaction),
+    ))
+}
+
+/// Sets whether the cursor is visible while it is over the window.
+pub fn set_cursor_visible<Message>(value: bool) -> Command<Message> {
+    set_cursor_visible_for(Id::MAIN, value)
+}
+
+/// Same as [`set_cursor_visible`], but for the window with the given [`Id`].
+pub fn set_cursor_visible_for<Message>(
+    id: Id,
+    value: bool,
+) -> Command<Message> {
+    Command::single(command::Action::Window(
+        id,
+        window::Action::SetCursorVisible(value),
+    ))
+}
+
+/// Grabs or releases the cursor with the given [`CursorGrab`] mode.
+pub fn set_cursor_grab<Message>(grab: CursorGrab) -> Command<Message> {
+    set_cursor_grab_for(Id::MAIN, grab)
+}
+
+/// Same as [`set_cursor_grab`], but for the window with the given [`Id`].
+pub fn set_cursor_grab_for<Message>(
+    id: Id,
+    grab: CursorGrab,
+) -> Command<Message> {
+    Command::single(command::Action::Window(
+        id,
+        window::Action::SetCursorGrab(grab),
+    ))
+}
+
+/// Fetches the current logical size of the client area of the window.
+pub fn fetch_inner_size<Message>(
+    f: impl FnOnce(Size<u32>) -> Message + 'static,
+) -> Command<Message> {
+    fetch_inner_size_for(Id::MAIN, f)
+}
+
+/// Same as [`fetch_inner_size`], but for the window with the given [`Id`].
+pub fn fetch_inner_size_for<Message>(
+    id: Id,
+    f: impl FnOnce(Size<u32>) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Window(
+        id,
+        window::Action::FetchInnerSize(Box::new(f)),
+    ))
+}
+
+/// Fetches the current logical size of the whole window, including its
+/// decorations.
+pub fn fetch_outer_size<Message>(
+    f: impl FnOnce(Size<u32>) -> Message + 'static,
+) -> Command<Message> {
+    fetch_outer_size_for(Id::MAIN, f)
+}
+
+/// Same as [`fetch_outer_size`], but for the window with the given [`Id`].
+pub fn fetch_outer_size_for<Message>(
+    id: Id,
+    f: impl FnOnce(Size<u32>) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Window(
+        id,
+        window::Action::FetchOuterSize(Box::new(f)),
+    ))
+}
+
+/// Fetches the current logical position of the window, if supported by the
+/// platform.
+pub fn fetch_position<Message>(
+    f: impl FnOnce(Option<(i32, i32)>) -> Message + 'static,
+) -> Command<Message> {
+    fetch_position_for(Id::MAIN, f)
+}
+
+/// Same as [`fetch_position`], but for the window with the given [`Id`].
+pub fn fetch_position_for<Message>(
+    id: Id,
+    f: impl FnOnce(Option<(i32, i32)>) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Window(
+        id,
+        window::Action::FetchPosition(Box::new(f)),
+    ))
+}
+
+/// Fetches the current scale factor of the window.
+pub fn fetch_scale_factor<Message>(
+    f: impl FnOnce(f64) -> Message + 'static,
+) -> Command<Message> {
+    fetch_scale_factor_for(Id::MAIN, f)
+}
+
+/// Same as [`fetch_scale_factor`], but for the window with the given [`Id`].
+pub fn fetch_scale_factor_for<Message>(
+    id: Id,
+    f: impl FnOnce(f64) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Window(
+        id,
+        window::Action::FetchScaleFactor(Box::new(f)),
+    ))
+}
+
+/// Fetches whether the window is currently focused.
+pub fn fetch_focused<Message>(
+    f: impl FnOnce(bool) -> Message + 'static,
+) -> Command<Message> {
+    fetch_focused_for(Id::MAIN, f)
+}
+
+/// Same as [`fetch_focused`], but for the window with the given [`Id`].
+pub fn fetch_focused_for<Message>(
+    id: Id,
+    f: impl FnOnce(bool) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Window(
+        id,
+        window::Action::FetchFocused(Box::new(f)),
+    ))
+}
+
+/// Fetches the list of [`Monitor`]s connected to the system.
+pub fn fetch_monitors<Message>(
+    f: impl FnOnce(Vec<Monitor>) -> Message + 'static,
+) -> Command<Message> {
+    fetch_monitors_for(Id::MAIN, f)
+}
+
+/// Same as [`fetch_monitors`], but for the window with the given [`Id`].
+pub fn fetch_monitors_for<Message>(
+    id: Id,
+    f: impl FnOnce(Vec<Monitor>) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Window(
+        id,
+        window::Action::FetchMonitors(Box::new(f)),
+    ))
+}
+
+/// Captures a [`Screenshot`] of the window once its next frame is presented.
+///
+/// The primitives of the frame are rendered again to capture it, since the
+/// presented frame cannot be read back with every backend.
+pub fn screenshot<Message>(
+    f: impl FnOnce(Screenshot) -> Message + 'static,
+) -> Command<Message> {
+    screenshot_for(Id::MAIN, f)
+}
+
+/// Same as [`screenshot`], but for the window with the given [`Id`].
+pub fn screenshot_for<Message>(
+    id: Id,
+    f: impl FnOnce(Screenshot) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Window(
+        id,
+        window::Action::Screenshot(None, Box::new(f)),
+    ))
+}
+
+/// Captures a [`Screenshot`] of the given region of the window, in logical
+/// coordinates, once its next frame is presented.
+pub fn screenshot_region<Message>(
+    region: Rectangle,
+    f: impl FnOnce(Screenshot) -> Message + 'static,
+) -> Command<Message> {
+    screenshot_region_for(Id::MAIN, region, f)
+}
+
+/// Same as [`screenshot_region`], but for the window with the given [`Id`].
+pub fn screenshot_region_for<Message>(
+    id: Id,
+    region: Rectangle,
+    f: impl FnOnce(Screenshot) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Window(
+        id,
+        window::Action::Screenshot(Some(region), Box::new(f)),
+    ))
 }